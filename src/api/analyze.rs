@@ -1,24 +1,137 @@
 use crate::types::*;
-use crate::providers::TokenProvider;
+use crate::providers::{image, trace, ProviderError, ProviderExecutor, TokenProvider, TraceCollector};
 use crate::checks::*;
-use crate::scoring::aggregate_score;
+use crate::scoring::{aggregate_score_with_profile, apply_partial_penalty, scoring_config};
+#[cfg(any(debug_assertions, test))]
+use crate::scoring::{ScoreComponent, FRAGILE_CUTOFF};
 use super::types::*;
+use super::chain::{normalize_address, normalize_chain};
+use super::config::AnalyzeConfig;
+use super::templates::ExplanationTemplates;
+use super::locale::NumberFormat;
+use super::baseline::compare_to_baseline;
+use super::facts_hash::facts_hash;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::Semaphore;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Reported instead of check results for ERC-721/1155 and non-token contracts
+pub const NOT_FUNGIBLE_MESSAGE: &str =
+    "Contract is not a fungible token (ERC-721/1155 or a non-token contract); \
+     this tool analyzes fungible tokens only";
+
+/// Reported instead of check results when the pre-flight ping fails hard
+pub const PROVIDER_DOWN_MESSAGE: &str =
+    "Provider unavailable (credentials rejected or endpoint unreachable); analysis skipped";
+
+/// Reported instead of check results for a chain `normalize_chain` does not
+/// recognize, unless `AnalyzeConfig::allow_unknown_chains` is set
+pub const UNSUPPORTED_CHAIN_MESSAGE: &str =
+    "Unsupported chain; no checks are defined for it, analysis skipped";
+
+/// Facts `gather_facts` fetches through provider calls. "identity" (name
+/// and symbol) comes with the metadata call.
+const FETCHED_FACTS: [&str; 12] = [
+    "metadata", "supply", "historical_supply", "authorities", "holders",
+    "creation", "tax", "authority_history", "bytecode", "mint_simulation", "rent",
+    "identity",
+];
+
+/// Facts that can be read as of a past slot or block. The rest only exist
+/// as current state and are not fetched for a point-in-time analysis.
+const POINT_IN_TIME_FACTS: [&str; 4] = ["metadata", "supply", "authorities", "identity"];
+
 /// Main API handler: orchestrates provider calls, checks, and scoring
-pub async fn analyze<P: TokenProvider>(
+pub async fn analyze<P: TokenProvider + ?Sized>(
     request: AnalyzeRequest,
     provider: &P,
 ) -> AnalyzeResponse {
+    analyze_with_config(request, provider, &AnalyzeConfig::default()).await
+}
+
+/// `analyze` with operator-supplied configuration (scoring profile, ...)
+pub async fn analyze_with_config<P: TokenProvider + ?Sized>(
+    mut request: AnalyzeRequest,
+    provider: &P,
+    config: &AnalyzeConfig,
+) -> AnalyzeResponse {
+    let chain = normalize_chain(&request.chain).unwrap_or_else(|| request.chain.clone());
+    request.address = normalize_address(&chain, &request.address);
     let analysis_id = generate_analysis_id();
     let requested_at = current_timestamp();
     let mut errors = Vec::new();
+    let at = request.options.point_in_time(&chain);
+    let (at_slot, at_block) = if chain == "solana" { (at, None) } else { (None, at) };
+
+    // A grade from the few chain-agnostic checks would look more confident
+    // than it is; unknown chains fail closed unless the operator opts in
+    let unsupported_chain = normalize_chain(&chain).is_none() && !config.allow_unknown_chains;
+
+    // Gather facts from provider, tracing every call made on its behalf
+    let collector = TraceCollector::new(request.options.include_raw_evidence);
+    let provider_down = if request.options.preflight_ping && !unsupported_chain {
+        trace::with_trace(collector.clone(), provider.ping()).await
+            .err()
+            .filter(ProviderError::is_fatal)
+    } else {
+        None
+    };
+    let mut facts = match provider_down.clone() {
+        _ if unsupported_chain => {
+            let mut facts = TokenFacts::default();
+            facts.error_details.push(ErrorDetail {
+                fact: "chain".to_string(),
+                code: "unsupported_chain".to_string(),
+                message: format!("Unsupported chain '{}'", chain),
+            });
+            errors.push(UNSUPPORTED_CHAIN_MESSAGE.to_string());
+            facts
+        }
+        // Every fetch would fail the same way; make none of them
+        Some(error) => {
+            let mut facts = TokenFacts::default();
+            facts.error_details.push(ErrorDetail {
+                fact: "provider".to_string(),
+                code: error.code().to_string(),
+                message: format!("Provider ping failed: {:?}", error),
+            });
+            errors.push(PROVIDER_DOWN_MESSAGE.to_string());
+            facts
+        }
+        None => trace::with_trace(
+            collector.clone(),
+            gather_facts(provider, &request.address, at, &request.options, config, None, &mut errors),
+        ).await,
+    };
+    prepare_facts(&mut facts, &chain, request.options.as_of, config);
+    let rpc_trace = if request.options.trace_rpc {
+        collector.entries()
+    } else {
+        Vec::new()
+    };
+    let rpc_calls = collector.call_count();
 
-    // Gather facts from provider
-    let facts = gather_facts(provider, &request.address, &request.options, &mut errors).await;
+    // Checks on a non-fungible contract would only report failed ERC20 calls
+    let non_fungible = is_non_fungible(&facts);
+    if non_fungible {
+        errors.push(NOT_FUNGIBLE_MESSAGE.to_string());
+    }
+    // Why no checks were run, when there is no point running them
+    let skipped = if unsupported_chain {
+        Some(UNSUPPORTED_CHAIN_MESSAGE)
+    } else if provider_down.is_some() {
+        Some(PROVIDER_DOWN_MESSAGE)
+    } else if non_fungible {
+        Some(NOT_FUNGIBLE_MESSAGE)
+    } else {
+        None
+    };
 
     // Determine analysis status
-    let status = if errors.is_empty() {
+    let status = if skipped.is_some() {
+        AnalysisStatus::Error
+    } else if errors.is_empty() {
         AnalysisStatus::Ok
     } else if facts.metadata.is_some() || facts.authorities.is_some() {
         AnalysisStatus::Partial
@@ -27,168 +140,781 @@ pub async fn analyze<P: TokenProvider>(
     };
 
     // Run checks based on chain
-    let checks = run_checks(&facts, &request.chain);
+    let mut checks = if skipped.is_some() { Vec::new() } else { run_checks(&facts, &chain, config) };
+    if request.options.stable_check_order {
+        sort_checks(&mut checks);
+    }
 
     // Aggregate score
-    let score = aggregate_score(&checks);
+    let mut score = aggregate_score_with_profile(&checks, &config.scoring);
+    if status == AnalysisStatus::Partial {
+        apply_partial_penalty(&mut score, config.scoring.partial_penalty);
+    }
+
+    let verdict = Verdict::from_score(&score, &checks);
+    let summary = CheckSummary::from_checks(&checks);
 
     // Build token metadata
-    let token = build_token_metadata(&facts);
+    let number_format = request.options.number_format();
+    let token = build_token_metadata(&facts, &number_format);
 
     // Generate explanation
-    let explain = generate_explanation(&checks, &score);
+    let mut explain = generate_explanation(
+        &checks,
+        &score,
+        request.options.explain_level,
+        &config.explanation_templates,
+        &config.check_registry,
+        &number_format,
+    );
+    if let (Some(message), Some(explain)) = (skipped, explain.as_mut()) {
+        explain.summary = message.to_string();
+    }
 
-    AnalyzeResponse {
+    let headline = headline_finding(&checks, &config.explanation_templates, &number_format);
+    let facts_hash = request.options.include_facts_hash.then(|| facts_hash(&facts));
+
+    let mut response = AnalyzeResponse {
         schema_version: "1.0.0".to_string(),
         analysis_id,
         requested_at,
-        chain: request.chain.clone(),
+        chain,
         address: request.address.clone(),
         status,
         token,
         checks,
         score,
+        verdict,
+        summary,
         explain,
         errors,
+        error_details: facts.error_details,
+        sources: facts.sources,
+        baseline_deltas: None,
+        scoring_config: None,
+        headline,
+        rpc_trace,
+        attestation: None,
+        changed_since_cache: None,
+        age_of_analysis_seconds: None,
+        facts_hash,
+        rpc_calls,
+        at_slot,
+        at_block,
+    };
+
+    if request.options.include_baseline {
+        response.baseline_deltas = Some(compare_to_baseline(&response));
+    }
+    if request.options.include_scoring_config {
+        response.scoring_config = Some(scoring_config(&response.checks, &config.scoring));
+    }
+
+    // Scoring/explanation drift shows up here before it reaches clients
+    #[cfg(debug_assertions)]
+    for violation in invariant_violations(&response) {
+        eprintln!("Analysis {} breaks an invariant: {}", response.analysis_id, violation);
+    }
+
+    response
+}
+
+/// Consistency rules between checks, grade and explanation:
+/// - `Compromised` needs a failed critical check, no known checks at all,
+///   or checks that score under the Fragile cutoff on their own. The
+///   reported score is not trusted for this: a partial penalty lowers it.
+/// - a full explanation of a failing analysis says what to do
+#[cfg(any(debug_assertions, test))]
+pub(super) fn invariant_violations(response: &AnalyzeResponse) -> Vec<String> {
+    let mut violations = Vec::new();
+    let failed = |c: &&CheckResult| c.status == CheckStatus::Fail;
+
+    if matches!(response.score.grade, Grade::Compromised) {
+        let critical_failure = response.checks.iter()
+            .filter(failed)
+            .any(|c| c.severity == Severity::Critical);
+        let known: Vec<&ScoreComponent> = response.score.components.iter()
+            .filter(|c| c.component_score.is_some())
+            .collect();
+        let known_weight: u32 = known.iter().map(|c| c.weight as u32).sum();
+        let checks_score = (known_weight > 0).then(|| {
+            let points: f64 = known.iter()
+                .map(|c| c.weight as f64 * c.component_score.unwrap_or(0) as f64)
+                .sum();
+            points / known_weight as f64
+        });
+        let low_score = checks_score.is_none_or(|s| s.round() < FRAGILE_CUTOFF as f64);
+        if !critical_failure && !low_score {
+            violations.push(format!(
+                "grade Compromised with score {:?} and no failed critical check",
+                response.score.fairness_score
+            ));
+        }
+    }
+
+    let full_explanation = response.explain.as_ref().filter(|e| !e.details.is_empty());
+    if let Some(explain) = full_explanation {
+        if response.checks.iter().any(|c| failed(&c)) && explain.interpretation.what_to_do.is_empty() {
+            violations.push("failed checks but explain.interpretation.what_to_do is empty".to_string());
+        }
     }
+
+    violations
 }
 
-async fn gather_facts<P: TokenProvider>(
+/// Fetch facts from the provider. `only` restricts fetching to the named
+/// facts (e.g. "holders"); everything is fetched when None.
+pub(super) async fn gather_facts<P: TokenProvider + ?Sized>(
     provider: &P,
     address: &str,
+    at: Option<u64>,
     options: &AnalyzeOptions,
+    config: &AnalyzeConfig,
+    only: Option<&HashSet<&str>>,
     errors: &mut Vec<String>,
 ) -> TokenFacts {
-    let mut facts = TokenFacts {
-        metadata: None,
-        supply: None,
-        authorities: None,
-        holders: None,
-        creation: None,
+    let executor = config.executor.as_deref();
+    let mut facts = TokenFacts::default();
+
+    // Facts the provider cannot serve (or not as of `at`) are noted, not
+    // requested
+    let capabilities = provider.capabilities();
+    let requested = |fact: &str| only.is_none_or(|only| only.contains(fact));
+    let servable = |fact: &str| {
+        capabilities.supports(fact)
+            && (at.is_none() || (capabilities.point_in_time && POINT_IN_TIME_FACTS.contains(&fact)))
+    };
+    facts.unsupported = FETCHED_FACTS.iter()
+        .filter(|fact| requested(fact) && !servable(fact))
+        .map(|fact| fact.to_string())
+        .collect();
+    let wants = |fact: &str| requested(fact) && servable(fact);
+
+    // Metadata, supply and authorities are read as of `at` when given
+    let (fetch_metadata, fetch_supply, fetch_authorities) = match at {
+        Some(_) => ("fetch_metadata_at", "fetch_supply_at", "fetch_authorities_at"),
+        None => ("fetch_metadata", "fetch_supply", "fetch_authorities"),
     };
 
     // Fetch metadata
-    match provider.fetch_metadata(address).await {
-        Ok(metadata) => facts.metadata = Some(metadata),
-        Err(e) => errors.push(format!("Failed to fetch metadata: {:?}", e)),
+    if wants("metadata") {
+        let metadata = match at {
+            Some(at) => provider.fetch_metadata_at(address, at),
+            None => provider.fetch_metadata(address),
+        };
+        match submit(executor, metadata).await {
+            Ok(metadata) => {
+                facts.metadata = Some(metadata);
+                record_source(&mut facts, provider.provider_name(), "metadata", fetch_metadata);
+            }
+            Err(e) => record_fetch_error(&mut facts, errors, "metadata", "metadata", e),
+        }
+    }
+
+    // Nothing else to learn about a contract that is not a fungible token
+    if is_non_fungible(&facts) {
+        return facts;
+    }
+
+    // Everything else is independent: fetch concurrently, at most
+    // `max_concurrent_fetches` at a time for this analysis
+    let limit = Semaphore::new(options.max_concurrent_fetches.max(1));
+    let fetch = |fact: &'static str, wanted: bool| wanted && wants(fact);
+    let image_uri = facts.metadata.as_ref()
+        .and_then(|m| m.image_uri.clone())
+        .filter(|_| options.check_image && wants("image"));
+    let baseline = options.supply_baseline.filter(|_| wants("historical_supply"));
+
+    let (image, supply, historical_supply, authorities, holders, creation, tax, authority_history, bytecode, mint_simulation, rent) = tokio::join!(
+        optional(&limit, image_uri.as_deref().map(|uri| image::probe_image(uri, image::IMAGE_PROBE_TIMEOUT))),
+        optional(&limit, fetch("supply", true).then(|| submit(executor, match at {
+            Some(at) => provider.fetch_supply_at(address, at),
+            None => provider.fetch_supply(address),
+        }))),
+        optional(&limit, baseline.map(|at| submit(executor, provider.fetch_supply_at(address, at)))),
+        optional(&limit, fetch("authorities", true).then(|| submit(executor, match at {
+            Some(at) => provider.fetch_authorities_at(address, at),
+            None => provider.fetch_authorities(address),
+        }))),
+        optional(&limit, fetch("holders", options.include_holders)
+            .then(|| submit(executor, provider.fetch_holders(address, options.max_holders, options.holder_strategy)))),
+        optional(&limit, fetch("creation", true).then(|| submit(executor, provider.fetch_creation_time(address)))),
+        optional(&limit, fetch("tax", true).then(|| submit(executor, provider.fetch_tax_info(address)))),
+        optional(&limit, fetch("authority_history", true)
+            .then(|| submit(executor, provider.fetch_authority_history(address)))),
+        // Bytecode only when there is a blocklist to compare it against
+        optional(&limit, fetch("bytecode", !config.known_malicious_bytecode.is_empty())
+            .then(|| submit(executor, provider.fetch_bytecode(address)))),
+        optional(&limit, fetch("mint_simulation", options.simulate_mint)
+            .then(|| submit(executor, provider.simulate_mint(address)))),
+        optional(&limit, fetch("rent", true).then(|| submit(executor, provider.fetch_rent_status(address)))),
+    );
+
+    // Results are recorded in a fixed order, whichever fetch finished first
+    let provider_name = provider.provider_name();
+
+    // Metadata image probe (informational, opt-in)
+    if let Some(image) = image {
+        facts.image = Some(image);
+        record_source(&mut facts, "image_probe", "image", "probe_image");
+    }
+
+    match supply {
+        Some(Ok(supply)) => {
+            facts.supply = Some(supply);
+            record_source(&mut facts, provider_name, "supply", fetch_supply);
+        }
+        Some(Err(e)) => record_fetch_error(&mut facts, errors, "supply", "supply", e),
+        None => {}
+    }
+
+    // Supply at the baseline (optional: needs archival state)
+    match (historical_supply, baseline) {
+        (Some(Ok(supply)), Some(at)) => {
+            facts.historical_supply = Some(HistoricalSupply { at, supply });
+            record_source(&mut facts, provider_name, "historical_supply", "fetch_supply_at");
+        }
+        (Some(Err(ProviderError::NotFound)), _) => {}
+        (Some(Err(e)), _) => record_fetch_error(&mut facts, errors, "historical_supply", "historical supply", e),
+        _ => {}
+    }
+
+    match authorities {
+        Some(Ok(authorities)) => {
+            facts.authorities = Some(authorities);
+            record_source(&mut facts, provider_name, "authorities", fetch_authorities);
+        }
+        Some(Err(e)) => record_fetch_error(&mut facts, errors, "authorities", "authorities", e),
+        None => {}
+    }
+
+    match holders {
+        Some(Ok(holders)) => {
+            facts.holders = Some(holders);
+            record_source(&mut facts, provider_name, "holders", "fetch_holders");
+            if options.holder_strategy == HolderStrategy::Aggregate {
+                facts.not_requested.push(HOLDER_LIST_FACT.to_string());
+            }
+        }
+        Some(Err(e)) => record_fetch_error(&mut facts, errors, "holders", "holders", e),
+        None => {}
+    }
+
+    match creation {
+        Some(Ok(creation)) => {
+            facts.creation = Some(creation);
+            record_source(&mut facts, provider_name, "creation", "fetch_creation_time");
+        }
+        Some(Err(e)) => record_fetch_error(&mut facts, errors, "creation", "creation time", e),
+        None => {}
     }
 
-    // Fetch supply
-    match provider.fetch_supply(address).await {
-        Ok(supply) => facts.supply = Some(supply),
-        Err(e) => errors.push(format!("Failed to fetch supply: {:?}", e)),
+    // Tax routing (optional: providers without tax detection return NotFound)
+    match tax {
+        Some(Ok(tax)) => {
+            facts.tax = Some(tax);
+            record_source(&mut facts, provider_name, "tax", "fetch_tax_info");
+        }
+        Some(Err(ProviderError::NotFound)) | None => {}
+        Some(Err(e)) => record_fetch_error(&mut facts, errors, "tax", "tax info", e),
+    }
+
+    // Authority history (optional: needs transaction history)
+    match authority_history {
+        Some(Ok(history)) => {
+            facts.authority_history = Some(history);
+            record_source(&mut facts, provider_name, "authority_history", "fetch_authority_history");
+        }
+        Some(Err(ProviderError::NotFound)) | None => {}
+        Some(Err(e)) => record_fetch_error(&mut facts, errors, "authority_history", "authority history", e),
     }
 
-    // Fetch authorities
-    match provider.fetch_authorities(address).await {
-        Ok(authorities) => facts.authorities = Some(authorities),
-        Err(e) => errors.push(format!("Failed to fetch authorities: {:?}", e)),
+    match bytecode {
+        Some(Ok(bytecode)) => {
+            facts.bytecode = Some(bytecode);
+            record_source(&mut facts, provider_name, "bytecode", "fetch_bytecode");
+        }
+        Some(Err(ProviderError::NotFound)) | None => {}
+        Some(Err(e)) => record_fetch_error(&mut facts, errors, "bytecode", "bytecode", e),
+    }
+
+    // Labels for the top holders (optional: empty when the provider has none)
+    if let Some(holders) = facts.holders.as_mut().filter(|h| !h.top_holders.is_empty()) {
+        let addresses: Vec<String> = holders.top_holders.iter().map(|h| h.address.clone()).collect();
+        let labels = submit(executor, provider.fetch_labels(&addresses)).await;
+        for holder in holders.top_holders.iter_mut() {
+            if let Some(label) = labels.get(&holder.address) {
+                holder.label = Some(label.clone());
+            }
+        }
     }
 
-    // Fetch holders (conditional)
-    if options.include_holders {
-        match provider.fetch_holders(address, options.max_holders).await {
-            Ok(holders) => facts.holders = Some(holders),
-            Err(e) => errors.push(format!("Failed to fetch holders: {:?}", e)),
+    // Mint simulation (optional, EVM only)
+    match mint_simulation {
+        Some(Ok(simulation)) => {
+            facts.mint_simulation = Some(simulation);
+            record_source(&mut facts, provider_name, "mint_simulation", "simulate_mint");
         }
+        Some(Err(ProviderError::NotFound)) | None => {}
+        Some(Err(e)) => record_fetch_error(&mut facts, errors, "mint_simulation", "mint simulation", e),
     }
 
-    // Fetch creation time
-    match provider.fetch_creation_time(address).await {
-        Ok(creation) => facts.creation = Some(creation),
-        Err(e) => errors.push(format!("Failed to fetch creation time: {:?}", e)),
+    // Mint account rent status (optional, Solana only)
+    match rent {
+        Some(Ok(rent)) => {
+            facts.rent = Some(rent);
+            record_source(&mut facts, provider_name, "rent", "fetch_rent_status");
+        }
+        Some(Err(ProviderError::NotFound)) | None => {}
+        Some(Err(e)) => record_fetch_error(&mut facts, errors, "rent", "rent status", e),
     }
 
     facts
 }
 
-fn run_checks(facts: &TokenFacts, chain: &str) -> Vec<CheckResult> {
+/// Run `fetch`, if any, once a permit of this analysis' fetch limit is free
+async fn optional<F: std::future::Future>(limit: &Semaphore, fetch: Option<F>) -> Option<F::Output> {
+    let fetch = fetch?;
+    let _permit = limit.acquire().await.expect("fetch limit semaphore is never closed");
+    Some(fetch.await)
+}
+
+fn is_non_fungible(facts: &TokenFacts) -> bool {
+    facts.metadata.as_ref()
+        .is_some_and(|m| m.standard == TokenStandard::NonFungibleOrUnknown)
+}
+
+/// Run a provider fetch through the shared executor, when one is configured
+async fn submit<F: std::future::Future>(executor: Option<&ProviderExecutor>, fetch: F) -> F::Output {
+    match executor {
+        Some(executor) => executor.run(fetch).await,
+        None => fetch.await,
+    }
+}
+
+/// Note where a just-fetched fact came from
+fn record_source(facts: &mut TokenFacts, provider: &str, fact: &str, method: &str) {
+    facts.sources.push(FactSource {
+        fact: fact.to_string(),
+        provider: provider.to_string(),
+        method: method.to_string(),
+        fetched_at: current_timestamp(),
+    });
+}
+
+/// Log a failed fact fetch, as text and as an `ErrorDetail`; real provider
+/// failures (not plain absence) are kept on the facts so checks can report
+/// `provider_error`
+fn record_fetch_error(
+    facts: &mut TokenFacts,
+    errors: &mut Vec<String>,
+    fact: &str,
+    label: &str,
+    error: ProviderError,
+) {
+    let message = format!("Failed to fetch {}: {:?}", label, error);
+    facts.error_details.push(ErrorDetail {
+        fact: fact.to_string(),
+        code: error.code().to_string(),
+        message: message.clone(),
+    });
+    errors.push(message);
+    if !matches!(error, ProviderError::NotFound) {
+        facts.fetch_errors.push(fact.to_string());
+    }
+}
+
+/// Derived-fact fixups shared by full and incremental analyses
+pub(super) fn prepare_facts(facts: &mut TokenFacts, chain: &str, as_of: Option<u64>, config: &AnalyzeConfig) {
+    normalize_authorities(facts, chain);
+    fill_total_supply(facts);
+
+    // Holdings at burn addresses are destroyed supply, whatever the provider said
+    if let Some(holders) = facts.holders.as_mut() {
+        for holder in holders.top_holders.iter_mut() {
+            if holder.label.is_none() {
+                holder.label = config.address_labels.get(chain, &holder.address).cloned();
+            }
+            match holder.label.as_ref().map(|l| &l.kind) {
+                Some(LabelKind::DexPool) => holder.kind = HolderKind::Lp,
+                Some(LabelKind::Burn) => holder.kind = HolderKind::Burn,
+                _ => {}
+            }
+            if config.burn_addresses.is_burn(chain, &holder.address) {
+                holder.kind = HolderKind::Burn;
+            }
+        }
+    }
+    fill_circulating_supply(facts);
+
+    // Age is measured against `as_of` when backtesting, otherwise now
+    let reference_time = as_of.unwrap_or_else(|| {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+    });
+    if let Some(creation) = facts.creation.as_mut() {
+        rebase_creation(creation, reference_time);
+    }
+    // Exact creation time wins; otherwise estimate from authority history
+    if facts.creation.as_ref().is_none_or(|c| c.age_seconds.is_none()) {
+        if let Some(estimate) = facts.authority_history.as_deref()
+            .and_then(|history| estimate_creation(history, reference_time))
+        {
+            facts.creation = Some(estimate);
+        }
+    }
+
+    // Band with the configured policy so `token.age_band` agrees with the check
+    if let (Some(policy), Some(creation)) = (config.age_band_policy.as_deref(), facts.creation.as_mut()) {
+        if let Some(age_seconds) = creation.age_seconds {
+            creation.age_band = policy.band_for(age_seconds);
+        }
+    }
+}
+
+/// Some providers report only raw units; ratio checks need the human supply
+fn fill_total_supply(facts: &mut TokenFacts) {
+    let decimals = facts.metadata.as_ref().and_then(|m| m.decimals);
+    if let (Some(supply), Some(decimals)) = (facts.supply.as_mut(), decimals) {
+        if supply.total_supply.is_none() {
+            supply.total_supply = supply.raw_units().map(|raw| raw / 10f64.powi(decimals as i32));
+        }
+    }
+}
+
+/// No provider reports circulating supply; derive it as total supply less
+/// the listed holdings of labeled contracts (vesting, treasury, ...). Left
+/// unset when no such holding is listed.
+fn fill_circulating_supply(facts: &mut TokenFacts) {
+    let (Some(supply), Some(holders)) = (facts.supply.as_mut(), facts.holders.as_ref()) else {
+        return;
+    };
+    let Some(total) = supply.total_supply.filter(|_| supply.circulating_supply.is_none()) else {
+        return;
+    };
+    let locked_pct: Option<f64> = holders.top_holders.iter()
+        .filter(|h| h.label.as_ref().is_some_and(|l| l.kind == LabelKind::Contract))
+        .map(|h| h.pct_of_supply)
+        .sum();
+    if let Some(locked_pct) = locked_pct.filter(|pct| *pct > 0.0) {
+        supply.circulating_supply = Some(total * (1.0 - locked_pct.min(100.0) / 100.0));
+    }
+}
+
+/// On Solana `mint_mutable` is exactly "a mint authority is set"; providers
+/// and fixtures fill it independently, so re-derive it when they disagree.
+/// EVM has no mint authority (`mint_mutable` comes from a bytecode scan there).
+fn normalize_authorities(facts: &mut TokenFacts, chain: &str) {
+    if chain != "solana" {
+        return;
+    }
+
+    if let Some(authorities) = facts.authorities.as_mut() {
+        let derived = authorities.mint_authority.is_some();
+        if authorities.mint_mutable != Some(derived) {
+            eprintln!(
+                "Inconsistent authority facts: mint_authority={:?} but mint_mutable={:?}; using {}",
+                authorities.mint_authority, authorities.mint_mutable, derived
+            );
+            authorities.mint_mutable = Some(derived);
+        }
+    }
+}
+
+pub(super) fn run_checks(facts: &TokenFacts, chain: &str, config: &AnalyzeConfig) -> Vec<CheckResult> {
+    let profile = &config.scoring;
+    let age_policy = config.age_band_policy.as_deref().unwrap_or(&DefaultAgeBandPolicy);
     let mut checks = Vec::new();
 
-    match chain {
-        "solana" => {
-            checks.push(check_mint_authority_disabled(facts));
-            checks.push(check_freeze_authority_disabled(facts));
-            checks.push(check_holder_concentration(facts));
-            checks.push(check_token_age(facts));
-            checks.push(check_standard_sanity(facts, chain));
+    // Accept aliases ("eth", "SOL", ...) as well as canonical names
+    let chain = normalize_chain(chain).unwrap_or_else(|| chain.to_string());
+    let chain = chain.as_str();
+
+    let evm = matches!(chain, "base" | "evm" | "ethereum");
+    let known_chain = chain == "solana" || evm;
+
+    // Authority checks, which differ by chain
+    if chain == "solana" {
+        checks.push(check_mint_authority_disabled(facts));
+        checks.push(check_freeze_authority_disabled(facts));
+        checks.push(check_authority_separation(facts));
+    } else if evm {
+        checks.push(check_ownership_renounced_with_burn(facts, &config.burn_addresses));
+    }
+
+    if known_chain {
+        checks.push(check_authority_is_program(facts));
+        checks.push(check_holder_concentration_with_basis(facts, profile.concentration_basis));
+        checks.push(check_owner_is_top_holder(facts));
+        checks.push(check_distribution_organic(facts));
+        // Only a holder scan reports the balance outside the list
+        if facts.holders.as_ref().is_some_and(|h| h.unlisted_balance_raw.is_some()) {
+            checks.push(check_holder_supply_consistency(facts));
+        }
+        checks.push(check_residual_supply(facts));
+        checks.push(check_token_age_with_policy(facts, age_policy));
+        checks.push(check_standard_sanity_with_policy(facts, chain, profile.strict_token_2022));
+        // A provider that never resolves names cannot tell nameless from unnamed
+        if !facts.unsupported.iter().any(|f| f == "identity") {
+            checks.push(check_has_identity_with_severity(facts, profile.nameless_severity.clone()));
         }
-        "base" | "evm" | "ethereum" => {
-            checks.push(check_ownership_renounced(facts));
-            checks.push(check_holder_concentration(facts));
-            checks.push(check_token_age(facts));
-            checks.push(check_standard_sanity(facts, chain));
+        checks.push(check_circulating_ratio(facts));
+        checks.push(check_supply_magnitude_with_bounds(facts, &config.supply_bounds));
+        checks.push(check_unit_spoofing(facts));
+        // Only when a baseline was requested and the provider could serve it
+        if facts.historical_supply.is_some() {
+            checks.push(check_no_recent_inflation(facts));
+        }
+    } else {
+        // Unknown chain (`allow_unknown_chains` only) - run minimal checks
+        checks.push(check_holder_concentration_with_basis(facts, profile.concentration_basis));
+        checks.push(check_residual_supply(facts));
+        checks.push(check_token_age_with_policy(facts, age_policy));
+    }
+
+    // Account and contract checks only one chain family has data for
+    if chain == "solana" {
+        checks.push(check_mint_initialized(facts));
+        checks.push(check_rent_exempt(facts));
+        checks.push(check_default_state_unfrozen(facts));
+        checks.push(check_authority_stability(facts));
+    } else if evm {
+        checks.push(check_tax_destination(facts));
+        if !config.known_malicious_bytecode.is_empty() {
+            checks.push(check_bytecode_reputation(facts, &config.known_malicious_bytecode));
         }
-        _ => {
-            // Unknown chain - run minimal checks
-            checks.push(check_holder_concentration(facts));
-            checks.push(check_token_age(facts));
+        // Only when a mint simulation was requested and the provider could run it
+        if facts.mint_simulation.is_some() {
+            checks.push(check_supply_fixed_evm(facts));
         }
     }
+    checks.extend(config.check_registry.run(facts, chain));
+    discount_divergent_holders(&mut checks);
+
+    checks.into_iter()
+        .map(sanitize_check)
+        .map(|mut check| {
+            // Custom checks may bring their own link
+            if check.learn_more_url.is_none() {
+                check.learn_more_url = learn_more_url(&check.id, chain).map(str::to_string);
+            }
+            check
+        })
+        .collect()
+}
+
+/// The documented stable order: by category, then by id, both ascending.
+/// Independent of chain and of the order `run_checks` runs checks in.
+pub(super) fn sort_checks(checks: &mut [CheckResult]) {
+    checks.sort_by(|a, b| (&a.category, &a.id).cmp(&(&b.category, &b.id)));
+}
+
+/// Keep one malformed result from failing the whole response. `json!`
+/// already turns NaN/Infinity into null inside `value`/`evidence`; what
+/// remains is a non-finite `confidence` (which would not deserialize back)
+/// or a result the serializer rejects. Either is replaced with an
+/// error-marked `Unknown`.
+pub(super) fn sanitize_check(check: CheckResult) -> CheckResult {
+    let problem = if !check.confidence.is_finite() {
+        Some(format!("non-finite confidence {}", check.confidence))
+    } else {
+        serde_json::to_string(&check).err().map(|e| e.to_string())
+    };
+    let Some(problem) = problem else {
+        return check;
+    };
 
-    checks
+    CheckResult {
+        status: CheckStatus::Unknown,
+        value: serde_json::Value::Null,
+        evidence: serde_json::json!({ "error": format!("result could not be serialized: {}", problem) }),
+        score_component: None,
+        confidence: 0.0,
+        unknown_reason: Some(UnknownReason::InvalidResult),
+        learn_more_url: None,
+        ..check
+    }
 }
 
-fn build_token_metadata(facts: &TokenFacts) -> Option<TokenMetadata> {
+pub(super) fn build_token_metadata(facts: &TokenFacts, format: &NumberFormat) -> Option<TokenMetadata> {
     let metadata = facts.metadata.as_ref()?;
+    let total_supply = facts.supply.as_ref().and_then(|s| s.total_supply);
     
     Some(TokenMetadata {
         name: metadata.name.clone(),
         symbol: metadata.symbol.clone(),
         decimals: metadata.decimals,
-        total_supply: facts.supply.as_ref().and_then(|s| s.total_supply),
+        total_supply,
+        total_supply_display: total_supply
+            .map(|supply| format.number(supply, metadata.decimals.unwrap_or(0) as usize)),
         program_standard: format!("{:?}", metadata.standard),
         created_at: facts.creation.as_ref().and_then(|c| c.created_at.clone()),
         age_seconds: facts.creation.as_ref().and_then(|c| c.age_seconds),
         age_band: facts.creation.as_ref()
             .map(|c| format!("{:?}", c.age_band))
             .unwrap_or_else(|| "Unknown".to_string()),
+        image: facts.image.clone(),
+        features: metadata.features.clone(),
     })
 }
 
-fn generate_explanation(checks: &[CheckResult], score: &crate::scoring::ScoreResult) -> ExplainSection {
-    let summary = match score.grade {
-        Grade::Strong => "Structure looks sound. No major weaknesses detected.".to_string(),
-        Grade::Mixed => "Structure is mostly sound with some areas of concern.".to_string(),
-        Grade::Fragile => "Structure shows significant fragility. Proceed with caution.".to_string(),
-        Grade::Compromised => "Structure is fundamentally compromised. High risk.".to_string(),
+/// The one failing check to lead with: highest severity, then weight, then
+/// check order. Its message is the check's finding when it has one.
+pub(super) fn headline_finding(
+    checks: &[CheckResult],
+    templates: &ExplanationTemplates,
+    format: &NumberFormat,
+) -> Option<HeadlineFinding> {
+    let severity_rank = |severity: &Severity| match severity {
+        Severity::Critical => 3,
+        Severity::High => 2,
+        Severity::Medium => 1,
+        Severity::Low => 0,
     };
+    let worst = checks.iter()
+        .filter(|c| c.status == CheckStatus::Fail)
+        .rev()
+        .max_by_key(|c| (severity_rank(&c.severity), c.weight))?;
 
-    let method = vec![
-        "This tool evaluates structural fairness, not price prediction.".to_string(),
-        "Each check is verifiable on-chain and scored transparently.".to_string(),
-    ];
+    let key = if worst.id == "ownership_renounced" && worst.severity == Severity::High {
+        "ownership_renounced.pending_owner"
+    } else {
+        worst.id.as_str()
+    };
+    let values: Vec<(&str, String)> = ["top1_pct", "circulating_pct"].into_iter()
+        .map(|name| {
+            let value = worst.evidence[name].as_f64();
+            (name, value.map_or_else(|| "n/a".to_string(), |v| format.percent(v)))
+        })
+        .collect();
+    let message = templates.finding(key, &values)
+        .unwrap_or_else(|| format!("{} failed.", worst.label));
+
+    Some(HeadlineFinding {
+        id: worst.id.clone(),
+        label: worst.label.clone(),
+        severity: worst.severity.clone(),
+        message,
+    })
+}
+
+pub(super) fn generate_explanation(
+    checks: &[CheckResult],
+    score: &crate::scoring::ScoreResult,
+    level: ExplainLevel,
+    templates: &ExplanationTemplates,
+    registry: &CheckRegistry,
+    format: &NumberFormat,
+) -> Option<ExplainSection> {
+    if level == ExplainLevel::None {
+        return None;
+    }
+
+    let summary = templates.summary(&score.grade);
+
+    if level == ExplainLevel::Summary {
+        return Some(ExplainSection {
+            headline: String::new(),
+            summary,
+            details: Vec::new(),
+            method: Vec::new(),
+            interpretation: InterpretationSection::default(),
+        });
+    }
+
+    let headline = templates.headline(&score.grade);
+    let method = templates.method.clone();
 
     let mut what_to_do = Vec::new();
+    let mut push_finding = |key: &str, values: &[(&str, Option<f64>)]| {
+        let values: Vec<(&str, String)> = values.iter()
+            .map(|(name, value)| (*name, value.map_or_else(|| "n/a".to_string(), |v| format.percent(v))))
+            .collect();
+        if let Some(line) = templates.finding(key, &values) {
+            what_to_do.push(line);
+        }
+    };
 
     // Check for critical failures
     let has_failures = checks.iter().any(|c| matches!(c.status, CheckStatus::Fail));
     
     for check in checks {
         if matches!(check.severity, Severity::Critical) && matches!(check.status, CheckStatus::Fail) {
-            if check.id == "mint_authority_disabled" {
-                what_to_do.push("Mint authority exists: supply is mutable and can be inflated.".to_string());
-            } else if check.id == "ownership_renounced" {
-                what_to_do.push("Ownership not renounced: contract parameters can still be changed.".to_string());
+            match check.id.as_str() {
+                "mint_authority_disabled" | "ownership_renounced" | "bytecode_reputation" => {
+                    push_finding(&check.id, &[])
+                }
+                _ => {}
             }
         }
     }
 
     // Check for high severity failures
     for check in checks {
-        if matches!(check.severity, Severity::High) && matches!(check.status, CheckStatus::Fail) {
-            if check.id == "freeze_authority_disabled" {
-                what_to_do.push("Freeze authority exists: token balances can be frozen.".to_string());
+        if matches!(check.severity, Severity::High)
+            && matches!(check.status, CheckStatus::Fail)
+            && check.id == "freeze_authority_disabled"
+        {
+            push_finding("freeze_authority_disabled", &[]);
+        }
+        if matches!(check.status, CheckStatus::Fail) && check.id == "mint_initialized" {
+            push_finding("mint_initialized", &[]);
+        }
+    }
+
+    // Check for renounced ownership that a pending owner can reclaim
+    for check in checks {
+        if check.id == "ownership_renounced"
+            && matches!(check.status, CheckStatus::Fail)
+            && matches!(check.severity, Severity::High)
+        {
+            push_finding("ownership_renounced.pending_owner", &[]);
+        }
+    }
+
+    // Plain failures with a finding of their own: shared mint/freeze key,
+    // recent authority transfer, supply held back, single-wallet tax,
+    // misleading decimals, frozen-by-default accounts, owner holding the
+    // largest position, callable EVM mint, scripted-looking distribution,
+    // reclaimable mint account, holder totals that miss the supply, control
+    // held by a bare key, no name or symbol
+    for id in [
+        "authority_separation",
+        "authority_stability",
+        "circulating_ratio",
+        "tax_destination",
+        "unit_spoofing",
+        "no_recent_inflation",
+        "default_state_unfrozen",
+        "owner_is_top_holder",
+        "supply_fixed",
+        "distribution_organic",
+        "rent_exempt",
+        "holder_supply_consistency",
+        "authority_is_program",
+        "has_identity",
+    ] {
+        for check in checks {
+            if check.id == id && matches!(check.status, CheckStatus::Fail) {
+                let pct = check.evidence["circulating_pct"].as_f64();
+                push_finding(id, &[("circulating_pct", pct)]);
             }
         }
     }
 
+    // Failed custom checks, where the templates carry a finding for them
+    for check in checks {
+        if registry.contains(&check.id) && matches!(check.status, CheckStatus::Fail) {
+            push_finding(&check.id, &[]);
+        }
+    }
+
     // Check for high concentration
     for check in checks {
         if check.id == "holder_concentration" {
             if let Some(score_comp) = check.score_component {
                 if score_comp < 50 {
-                    what_to_do.push("High holder concentration increases structural fragility.".to_string());
+                    let pct = check.evidence["top1_pct"].as_f64();
+                    push_finding("holder_concentration", &[("top1_pct", pct)]);
                 }
             }
         }
@@ -196,29 +922,46 @@ fn generate_explanation(checks: &[CheckResult], score: &crate::scoring::ScoreRes
 
     // If no specific issues found but also no failures, it's a good launch
     if what_to_do.is_empty() && !has_failures {
-        what_to_do.push("All structural checks passed. Token appears fairly launched.".to_string());
+        what_to_do.push(templates.all_passed.clone());
     } else if what_to_do.is_empty() && has_failures {
         // Generic message for failures we haven't specifically categorized
-        what_to_do.push("Some structural checks failed. Review details above.".to_string());
+        what_to_do.push(templates.unlisted_failures.clone());
     }
 
-    ExplainSection {
+    let details = checks.iter().map(|check| check_detail(check, templates)).collect();
+
+    Some(ExplainSection {
+        headline,
         summary,
+        details,
         method,
         interpretation: InterpretationSection { what_to_do },
-    }
+    })
+}
+
+/// Narrative line for a single check
+fn check_detail(check: &CheckResult, templates: &ExplanationTemplates) -> String {
+    let template = match check.status {
+        CheckStatus::Pass => &templates.detail_pass,
+        CheckStatus::Fail => &templates.detail_fail,
+        CheckStatus::Unknown => &templates.detail_unknown,
+    };
+    templates.detail(template, check)
 }
 
-fn generate_analysis_id() -> String {
+pub(super) fn generate_analysis_id() -> String {
     // Simple ID generation - in production use UUID
+    // The sequence suffix keeps IDs unique within the same millisecond
+    static SEQUENCE: AtomicU64 = AtomicU64::new(0);
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap()
         .as_millis();
-    format!("analysis_{}", now)
+    let seq = SEQUENCE.fetch_add(1, Ordering::Relaxed);
+    format!("analysis_{}_{}", now, seq)
 }
 
-fn current_timestamp() -> String {
+pub(super) fn current_timestamp() -> String {
     // ISO 8601 timestamp - in production use proper datetime library
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -234,6 +977,7 @@ fn current_timestamp() -> String {
 mod tests {
     use super::*;
     use crate::providers::mocks::MockProvider;
+    use crate::api::baseline::DeviationReason;
 
     #[tokio::test]
     async fn test_analyze_fair_launch_solana() {
@@ -244,27 +988,34 @@ mod tests {
                 symbol: Some("FAIR".to_string()),
                 decimals: Some(9),
                 standard: TokenStandard::SplToken,
+                ..Default::default()
             }),
             supply: Some(SupplyInfo {
                 total_supply_raw: Some("1000000000000000".to_string()),
                 total_supply: Some(1000000.0),
+                circulating_supply: None,
             }),
             authorities: Some(AuthorityInfo {
                 mint_authority: None,
                 freeze_authority: None,
                 owner: None,
                 mint_mutable: Some(false),
+                ..Default::default()
             }),
             holders: Some(HolderInfo {
                 top1_pct: Some(8.5),
                 top5_pct: Some(28.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
                 top_holders: vec![],
             }),
             creation: Some(CreationInfo {
                 created_at: Some("2026-01-20T00:00:00Z".to_string()),
                 age_seconds: Some(864000),
                 age_band: AgeBand::GreaterThan7d,
+                estimated: false,
             }),
+            ..Default::default()
         };
 
         let provider = MockProvider::new("test").with_facts("test_address", facts);
@@ -284,89 +1035,1521 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_analyze_mint_authority_exists() {
+    async fn test_sources_cover_each_present_fact() {
         let facts = TokenFacts {
             metadata: Some(Metadata {
-                name: Some("BadToken".to_string()),
-                symbol: Some("BAD".to_string()),
-                decimals: Some(9),
                 standard: TokenStandard::SplToken,
+                ..Default::default()
             }),
-            supply: Some(SupplyInfo {
-                total_supply: Some(1000000.0),
-                total_supply_raw: Some("1000000000000000".to_string()),
-            }),
-            authorities: Some(AuthorityInfo {
-                mint_authority: Some("BadAuthority".to_string()),
-                freeze_authority: None,
-                owner: None,
-                mint_mutable: Some(true),
-            }),
+            authorities: Some(AuthorityInfo::default()),
             holders: Some(HolderInfo {
                 top1_pct: Some(5.0),
-                top5_pct: Some(20.0),
+                top5_pct: Some(15.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
                 top_holders: vec![],
             }),
-            creation: Some(CreationInfo {
-                created_at: Some("2026-01-20T00:00:00Z".to_string()),
-                age_seconds: Some(864000),
-                age_band: AgeBand::GreaterThan7d,
-            }),
+            ..Default::default()
         };
-
-        let provider = MockProvider::new("test").with_facts("bad_token", facts);
-
+        let provider = MockProvider::new("mock-rpc").with_facts("token", facts);
         let request = AnalyzeRequest {
             chain: "solana".to_string(),
-            address: "bad_token".to_string(),
+            address: "token".to_string(),
             options: AnalyzeOptions::default(),
         };
 
         let response = analyze(request, &provider).await;
 
-        // Grade must be Compromised due to critical failure
-        assert!(matches!(response.score.grade, Grade::Compromised));
-        assert!(response.explain.interpretation.what_to_do.iter()
-            .any(|s| s.contains("Mint authority exists")));
+        let mut sourced: Vec<&str> = response.sources.iter().map(|s| s.fact.as_str()).collect();
+        sourced.sort();
+        assert_eq!(sourced, vec!["authorities", "holders", "metadata"]);
+        for source in &response.sources {
+            assert_eq!(source.provider, "mock-rpc");
+            assert_eq!(source.method, format!("fetch_{}", source.fact));
+            assert!(!source.fetched_at.is_empty());
+        }
+    }
+
+    fn evm_request(address: &str) -> AnalyzeRequest {
+        AnalyzeRequest {
+            chain: "base".to_string(),
+            address: address.to_string(),
+            options: AnalyzeOptions::default(),
+        }
     }
 
     #[tokio::test]
-    async fn test_analyze_partial_data() {
+    async fn test_erc721_gets_not_fungible_message() {
         let facts = TokenFacts {
             metadata: Some(Metadata {
-                name: Some("PartialToken".to_string()),
-                symbol: Some("PART".to_string()),
-                decimals: Some(9),
-                standard: TokenStandard::SplToken,
+                standard: TokenStandard::NonFungibleOrUnknown,
+                ..Default::default()
+            }),
+            authorities: Some(AuthorityInfo::default()),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("0xnft", facts);
+
+        let response = analyze(evm_request("0xnft"), &provider).await;
+
+        assert_eq!(response.status, AnalysisStatus::Error);
+        assert!(response.checks.is_empty());
+        assert_eq!(response.errors, vec![NOT_FUNGIBLE_MESSAGE.to_string()]);
+        assert_eq!(response.explain.unwrap().summary, NOT_FUNGIBLE_MESSAGE);
+        // Nothing fetched past the metadata
+        assert_eq!(response.sources.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_erc20_analyzed_normally() {
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(18),
+                standard: TokenStandard::Erc20,
+                ..Default::default()
             }),
-            supply: None, // Missing supply
             authorities: Some(AuthorityInfo {
-                mint_authority: None,
-                freeze_authority: None,
                 owner: None,
                 mint_mutable: Some(false),
+                ..Default::default()
             }),
-            holders: None, // Missing holders
-            creation: None, // Missing creation
+            ..Default::default()
         };
+        let provider = MockProvider::new("test").with_facts("0xtoken", facts);
 
-        let provider = MockProvider::new("test").with_facts("partial_token", facts);
+        let response = analyze(evm_request("0xtoken"), &provider).await;
 
+        assert_ne!(response.status, AnalysisStatus::Error);
+        assert!(!response.checks.is_empty());
+        assert!(!response.errors.iter().any(|e| e == NOT_FUNGIBLE_MESSAGE));
+    }
+
+    #[tokio::test]
+    async fn test_custom_template_changes_compromised_summary() {
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            authorities: Some(AuthorityInfo {
+                mint_authority: Some("MintKey".to_string()),
+                mint_mutable: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("token", facts);
+        let mut templates = ExplanationTemplates::default();
+        templates.summaries.insert(
+            "Compromised".to_string(),
+            "Estructura comprometida. Riesgo alto.".to_string(),
+        );
+        let config = AnalyzeConfig { explanation_templates: templates, ..Default::default() };
         let request = AnalyzeRequest {
             chain: "solana".to_string(),
-            address: "partial_token".to_string(),
+            address: "token".to_string(),
             options: AnalyzeOptions::default(),
         };
 
-        let response = analyze(request, &provider).await;
+        let response = analyze_with_config(request, &provider, &config).await;
 
-        assert_eq!(response.status, AnalysisStatus::Partial);
-        assert!(response.errors.len() > 0);
-        
-        // Some checks should be unknown
-        let unknown_count = response.checks.iter()
-            .filter(|c| matches!(c.status, CheckStatus::Unknown))
-            .count();
-        assert!(unknown_count > 0);
+        assert!(matches!(response.score.grade, Grade::Compromised));
+        let explain = response.explain.unwrap();
+        assert_eq!(explain.summary, "Estructura comprometida. Riesgo alto.");
+        // Entries not overridden keep the default wording
+        assert!(explain.interpretation.what_to_do.iter()
+            .any(|s| s.contains("Mint authority exists")));
+    }
+
+    #[tokio::test]
+    async fn test_locale_formats_supply_and_findings() {
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(2),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            supply: Some(SupplyInfo {
+                total_supply_raw: Some("123456789050".to_string()),
+                total_supply: Some(1_234_567_890.5),
+                circulating_supply: None,
+            }),
+            holders: Some(HolderInfo {
+                top1_pct: Some(62.5),
+                top5_pct: Some(90.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![],
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("token", facts);
+        let run = |locale: &str| {
+            let request = AnalyzeRequest {
+                chain: "solana".to_string(),
+                address: "token".to_string(),
+                options: AnalyzeOptions { locale: Some(locale.to_string()), ..Default::default() },
+            };
+            analyze(request, &provider)
+        };
+
+        let en = run("en-US").await;
+        let de = run("de-DE").await;
+
+        let display = |r: &AnalyzeResponse| r.token.as_ref().unwrap().total_supply_display.clone();
+        assert_eq!(display(&en).as_deref(), Some("1,234,567,890.5"));
+        assert_eq!(display(&de).as_deref(), Some("1.234.567.890,5"));
+        // The numeric field itself is untouched
+        assert_eq!(de.token.as_ref().unwrap().total_supply, Some(1_234_567_890.5));
+
+        let finding = |r: &AnalyzeResponse| r.explain.as_ref().unwrap().interpretation.what_to_do.iter()
+            .find(|s| s.contains("concentration"))
+            .cloned()
+            .unwrap();
+        assert!(finding(&en).contains("62.5%"), "{}", finding(&en));
+        assert!(finding(&de).contains("62,5%"), "{}", finding(&de));
+    }
+
+    #[tokio::test]
+    async fn test_recent_inflation_against_two_blocks() {
+        let supply = |total: f64| SupplyInfo {
+            total_supply_raw: None,
+            total_supply: Some(total),
+            circulating_supply: None,
+        };
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(18),
+                standard: TokenStandard::Erc20,
+                ..Default::default()
+            }),
+            supply: Some(supply(1_500_000.0)),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test")
+            .with_facts("0xtoken", facts)
+            .with_supply_at("0xtoken", 100, supply(1_000_000.0))
+            .with_supply_at("0xtoken", 200, supply(1_500_000.0));
+        let inflation_check = |baseline: Option<u64>| {
+            let request = AnalyzeRequest {
+                chain: "base".to_string(),
+                address: "0xtoken".to_string(),
+                options: AnalyzeOptions { supply_baseline: baseline, ..Default::default() },
+            };
+            let provider = &provider;
+            async move {
+                analyze(request, provider).await.checks.into_iter()
+                    .find(|c| c.id == "no_recent_inflation")
+            }
+        };
+
+        let since_100 = inflation_check(Some(100)).await.unwrap();
+        assert_eq!(since_100.status, CheckStatus::Fail);
+        assert_eq!(since_100.evidence["growth_pct"], 50.0);
+
+        let since_200 = inflation_check(Some(200)).await.unwrap();
+        assert_eq!(since_200.status, CheckStatus::Pass);
+
+        // No baseline requested: the check is not run
+        assert!(inflation_check(None).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_analyze_mint_authority_exists() {
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                name: Some("BadToken".to_string()),
+                symbol: Some("BAD".to_string()),
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            supply: Some(SupplyInfo {
+                total_supply: Some(1000000.0),
+                total_supply_raw: Some("1000000000000000".to_string()),
+                circulating_supply: None,
+            }),
+            authorities: Some(AuthorityInfo {
+                mint_authority: Some("BadAuthority".to_string()),
+                freeze_authority: None,
+                owner: None,
+                mint_mutable: Some(true),
+                ..Default::default()
+            }),
+            holders: Some(HolderInfo {
+                top1_pct: Some(5.0),
+                top5_pct: Some(20.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![],
+            }),
+            creation: Some(CreationInfo {
+                created_at: Some("2026-01-20T00:00:00Z".to_string()),
+                age_seconds: Some(864000),
+                age_band: AgeBand::GreaterThan7d,
+                estimated: false,
+            }),
+            ..Default::default()
+        };
+
+        let provider = MockProvider::new("test").with_facts("bad_token", facts);
+
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "bad_token".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+
+        let response = analyze(request, &provider).await;
+
+        // Grade must be Compromised due to critical failure
+        assert!(matches!(response.score.grade, Grade::Compromised));
+        let explain = response.explain.as_ref().unwrap();
+        assert!(explain.interpretation.what_to_do.iter()
+            .any(|s| s.contains("Mint authority exists")));
+
+        // Headline is crisp, details enumerate the failing checks
+        assert!(explain.headline.contains("High risk"));
+        let failing: Vec<&String> = explain.details.iter()
+            .filter(|d| d.contains("failed"))
+            .collect();
+        assert_eq!(failing.len(), 1);
+        assert!(failing[0].starts_with("Mint authority disabled"));
+        assert!(failing[0].contains("critical"));
+        assert_eq!(explain.details.len(), response.checks.len());
+    }
+
+    #[tokio::test]
+    async fn test_baseline_reports_mint_authority_deviation() {
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            authorities: Some(AuthorityInfo {
+                mint_authority: Some("MintKey".to_string()),
+                mint_mutable: Some(true),
+                ..Default::default()
+            }),
+            holders: Some(HolderInfo {
+                top1_pct: Some(5.0),
+                top5_pct: Some(20.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![],
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("mint_token", facts);
+
+        let request = |include_baseline| AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "mint_token".to_string(),
+            options: AnalyzeOptions { include_baseline, ..Default::default() },
+        };
+
+        assert!(analyze(request(false), &provider).await.baseline_deltas.is_none());
+
+        let deltas = analyze(request(true), &provider).await.baseline_deltas.unwrap();
+        let mint = deltas.iter().find(|d| d.check_id == "mint_authority_disabled").unwrap();
+        assert_eq!(mint.reason, DeviationReason::FallsShort);
+        assert_eq!(mint.expected, "mint authority disabled");
+        // Top holder at 5% meets the baseline; missing age is unverified
+        assert!(!deltas.iter().any(|d| d.check_id == "holder_concentration"));
+        let age = deltas.iter().find(|d| d.check_id == "token_age").unwrap();
+        assert_eq!(age.reason, DeviationReason::Unverified);
+    }
+
+    #[tokio::test]
+    async fn test_stable_check_order_by_category_then_id() {
+        let key = |c: &CheckResult| (c.category.clone(), c.id.clone());
+        for chain in ["solana", "ethereum"] {
+            let provider = MockProvider::new("test").with_facts("token", TokenFacts::default());
+            let request = AnalyzeRequest {
+                chain: chain.to_string(),
+                address: "token".to_string(),
+                options: AnalyzeOptions { stable_check_order: true, ..Default::default() },
+            };
+
+            let response = analyze(request, &provider).await;
+
+            let keys: Vec<_> = response.checks.iter().map(key).collect();
+            let mut expected = keys.clone();
+            expected.sort();
+            assert_eq!(keys, expected, "{} checks out of order", chain);
+
+            // Whatever order the checks ran in
+            let mut reversed = run_checks(&TokenFacts::default(), chain, &AnalyzeConfig::default());
+            reversed.reverse();
+            sort_checks(&mut reversed);
+            assert_eq!(reversed.iter().map(key).collect::<Vec<_>>(), keys);
+        }
+    }
+
+    #[test]
+    fn test_builtin_check_ids_listed() {
+        let facts = TokenFacts {
+            historical_supply: Some(HistoricalSupply {
+                at: 0,
+                supply: SupplyInfo { total_supply: None, circulating_supply: None, total_supply_raw: None },
+            }),
+            mint_simulation: Some(MintSimulation {
+                outcome: MintCallOutcome::Unauthorized,
+                revert_reason: None,
+                caller: String::new(),
+            }),
+            holders: Some(HolderInfo {
+                top1_pct: None,
+                top5_pct: None,
+                holder_count: None,
+                unlisted_balance_raw: Some("0".to_string()),
+                top_holders: Vec::new(),
+            }),
+            ..Default::default()
+        };
+        let mut config = AnalyzeConfig::default();
+        config.known_malicious_bytecode.insert("00".to_string());
+        for chain in ["solana", "base"] {
+            for check in run_checks(&facts, chain, &config) {
+                assert!(BUILTIN_CHECK_IDS.contains(&check.id.as_str()), "{} not listed", check.id);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_facts_hash_tracks_facts_not_time() {
+        let facts = |mint_authority: Option<&str>| TokenFacts {
+            authorities: Some(AuthorityInfo {
+                mint_authority: mint_authority.map(str::to_string),
+                mint_mutable: Some(mint_authority.is_some()),
+                ..Default::default()
+            }),
+            creation: Some(CreationInfo {
+                created_at: Some("2026-01-01T00:00:00Z".to_string()),
+                age_seconds: None,
+                age_band: AgeBand::Unknown,
+                estimated: false,
+            }),
+            ..Default::default()
+        };
+        let request = |as_of| AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { include_facts_hash: true, as_of: Some(as_of), ..Default::default() },
+        };
+        let renounced = MockProvider::new("test").with_facts("token", facts(None));
+
+        // Same data seen a day apart: ages differ, the hash does not
+        let first = analyze(request(1_768_000_000), &renounced).await;
+        let second = analyze(request(1_768_086_400), &renounced).await;
+        assert_ne!(first.analysis_id, second.analysis_id);
+        assert_eq!(first.facts_hash, second.facts_hash);
+        assert_eq!(first.facts_hash.as_ref().unwrap().len(), 64);
+
+        let mintable = MockProvider::new("test").with_facts("token", facts(Some("MintAuth111")));
+        let changed = analyze(request(1_768_086_400), &mintable).await;
+        assert_ne!(changed.facts_hash, first.facts_hash);
+
+        // Opt-in
+        let mut plain = request(1_768_000_000);
+        plain.options.include_facts_hash = false;
+        assert_eq!(analyze(plain, &renounced).await.facts_hash, None);
+    }
+
+    #[tokio::test]
+    async fn test_invariant_violations_caught() {
+        let provider = MockProvider::new("test").with_facts("token", TokenFacts::default());
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+        let response = analyze(request, &provider).await;
+        assert_eq!(invariant_violations(&response), Vec::<String>::new());
+
+        // A well-scored analysis graded Compromised with only a minor failure
+        let well_scored = vec![ScoreComponent {
+            id: "distribution_organic".to_string(),
+            weight: 10,
+            component_score: Some(90),
+            weighted_points: Some(9.0),
+        }];
+        let mut minor_failure = check_distribution_organic(&TokenFacts::default());
+        minor_failure.status = CheckStatus::Fail;
+        let mut crafted = response.clone();
+        crafted.checks = vec![minor_failure];
+        crafted.score.components = well_scored.clone();
+        crafted.score.grade = Grade::Compromised;
+        crafted.score.fairness_score = Some(90);
+        crafted.explain.as_mut().unwrap().interpretation.what_to_do.clear();
+
+        let violations = invariant_violations(&crafted);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].contains("no failed critical check"));
+        assert!(violations[1].contains("what_to_do is empty"));
+
+        // A partial penalty pushed the reported score under the cutoff, but
+        // the checks themselves score well
+        let mut penalized = response.clone();
+        penalized.checks = vec![check_distribution_organic(&TokenFacts::default())];
+        penalized.score.components = well_scored;
+        penalized.score.fairness_score = Some(20);
+        penalized.score.grade = Grade::Compromised;
+        let violations = invariant_violations(&penalized);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].contains("no failed critical check"));
+    }
+
+    #[tokio::test]
+    async fn test_nan_check_does_not_break_serialization() {
+        let provider = MockProvider::new("test").with_facts("token", TokenFacts::default());
+        let mut response = analyze(AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions::default(),
+        }, &provider).await;
+
+        let mut bad = response.checks[0].clone();
+        bad.value = serde_json::json!({ "ratio": f64::NAN });
+        bad.confidence = f64::INFINITY;
+        assert!(bad.value["ratio"].is_null());
+
+        let sanitized = sanitize_check(bad);
+        assert_eq!(sanitized.status, CheckStatus::Unknown);
+        assert_eq!(sanitized.unknown_reason, Some(UnknownReason::InvalidResult));
+        assert!(sanitized.evidence["error"].as_str().unwrap().contains("confidence"));
+
+        response.checks[0] = sanitized;
+        let json = serde_json::to_string(&response).unwrap();
+        let back: AnalyzeResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.checks.len(), response.checks.len());
+
+        // Well-formed results pass through untouched
+        let good = response.checks[1].clone();
+        assert_eq!(sanitize_check(good.clone()).status, good.status);
+    }
+
+    #[test]
+    fn test_total_supply_derived_from_raw_before_checks() {
+        let mut facts = TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(6),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            supply: Some(SupplyInfo {
+                total_supply_raw: Some("1000000000000".to_string()),
+                total_supply: None,
+                circulating_supply: None,
+            }),
+            ..Default::default()
+        };
+
+        prepare_facts(&mut facts, "solana", None, &AnalyzeConfig::default());
+        assert_eq!(facts.supply.as_ref().unwrap().total_supply, Some(1_000_000.0));
+
+        let checks = run_checks(&facts, "solana", &AnalyzeConfig::default());
+        let magnitude = checks.iter().find(|c| c.id == "supply_magnitude").unwrap();
+        assert_eq!(magnitude.status, CheckStatus::Pass);
+    }
+
+    #[test]
+    fn test_circulating_supply_excludes_labeled_contracts() {
+        let holder = |address: &str, pct: f64, kind: Option<LabelKind>| HolderBalance {
+            address: address.to_string(),
+            balance_raw: "0".to_string(),
+            balance: None,
+            pct_of_supply: Some(pct),
+            kind: HolderKind::Unclassified,
+            label: kind.map(|kind| AddressLabel { kind, name: None }),
+        };
+        let facts = |vesting: Option<LabelKind>| TokenFacts {
+            supply: Some(SupplyInfo {
+                total_supply_raw: None,
+                total_supply: Some(1_000_000.0),
+                circulating_supply: None,
+            }),
+            holders: Some(HolderInfo {
+                top1_pct: Some(60.0),
+                top5_pct: Some(70.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![holder("vesting", 60.0, vesting), holder("whale", 10.0, None)],
+            }),
+            ..Default::default()
+        };
+
+        let mut locked = facts(Some(LabelKind::Contract));
+        prepare_facts(&mut locked, "base", None, &AnalyzeConfig::default());
+        assert_eq!(locked.supply.unwrap().circulating_supply, Some(400_000.0));
+
+        let mut unlabeled = facts(None);
+        prepare_facts(&mut unlabeled, "base", None, &AnalyzeConfig::default());
+        assert_eq!(unlabeled.supply.unwrap().circulating_supply, None);
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_holders_not_fetched() {
+        use crate::providers::ProviderCapabilities;
+
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            holders: Some(HolderInfo {
+                top1_pct: Some(5.0),
+                top5_pct: Some(20.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![],
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test")
+            .with_facts("token", facts)
+            .with_capabilities(ProviderCapabilities { holders: false, ..ProviderCapabilities::all() });
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { trace_rpc: true, ..Default::default() },
+        };
+
+        let response = analyze(request, &provider).await;
+
+        assert!(!response.rpc_trace.iter().any(|e| e.method == "fetch_holders"));
+        assert!(response.rpc_trace.iter().any(|e| e.method == "fetch_metadata"));
+        let holders = response.checks.iter().find(|c| c.id == "holder_concentration").unwrap();
+        assert_eq!(holders.status, CheckStatus::Unknown);
+        assert_eq!(holders.unknown_reason, Some(UnknownReason::Unsupported));
+        assert!(!response.errors.iter().any(|e| e.contains("holders")));
+    }
+
+    /// Every fetch sleeps briefly and counts how many run at once
+    #[derive(Default)]
+    struct CountingProvider {
+        in_flight: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+    }
+
+    impl CountingProvider {
+        async fn fetch<T>(&self, value: T) -> Result<T, ProviderError> {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(value)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl TokenProvider for CountingProvider {
+        fn provider_name(&self) -> &str {
+            "counting"
+        }
+
+        async fn fetch_metadata(&self, _address: &str) -> Result<Metadata, ProviderError> {
+            self.fetch(Metadata { standard: TokenStandard::SplToken, ..Default::default() }).await
+        }
+
+        async fn fetch_supply(&self, _address: &str) -> Result<SupplyInfo, ProviderError> {
+            self.fetch(SupplyInfo { total_supply_raw: None, total_supply: Some(1e9), circulating_supply: None }).await
+        }
+
+        async fn fetch_authorities(&self, _address: &str) -> Result<AuthorityInfo, ProviderError> {
+            self.fetch(AuthorityInfo::default()).await
+        }
+
+        async fn fetch_holders(
+            &self,
+            _address: &str,
+            _limit: usize,
+            _strategy: HolderStrategy,
+        ) -> Result<HolderInfo, ProviderError> {
+            self.fetch(HolderInfo { top1_pct: None, top5_pct: None, holder_count: None, unlisted_balance_raw: None, top_holders: vec![] }).await
+        }
+
+        async fn fetch_creation_time(&self, _address: &str) -> Result<CreationInfo, ProviderError> {
+            self.fetch(CreationInfo {
+                created_at: None,
+                age_seconds: None,
+                age_band: AgeBand::Unknown,
+                estimated: false,
+            }).await
+        }
+
+        async fn fetch_tax_info(&self, _address: &str) -> Result<TaxInfo, ProviderError> {
+            self.fetch(()).await.and(Err(ProviderError::NotFound))
+        }
+
+        async fn fetch_authority_history(&self, _address: &str) -> Result<Vec<AuthorityChange>, ProviderError> {
+            self.fetch(Vec::new()).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetches_concurrent_but_capped() {
+        for limit in [1, 2, 5] {
+            let provider = CountingProvider::default();
+            let request = AnalyzeRequest {
+                chain: "solana".to_string(),
+                address: "token".to_string(),
+                options: AnalyzeOptions { max_concurrent_fetches: limit, ..Default::default() },
+            };
+
+            let response = analyze(request, &provider).await;
+
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+            // Six fetches follow metadata, so every limit up to 5 is reached
+            assert_eq!(provider.peak.load(Ordering::SeqCst), limit);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_headline_prefers_critical_mint_failure() {
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            authorities: Some(AuthorityInfo {
+                // Same key: authority_separation (Medium) fails too
+                mint_authority: Some("DevKey".to_string()),
+                freeze_authority: Some("DevKey".to_string()),
+                mint_mutable: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("token", facts);
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+
+        let response = analyze(request.clone(), &provider).await;
+
+        let failing = |id: &str| response.checks.iter()
+            .any(|c| c.id == id && c.status == CheckStatus::Fail);
+        assert!(failing("authority_separation") && failing("freeze_authority_disabled"));
+        let headline = response.headline.as_ref().unwrap();
+        assert_eq!(headline.id, "mint_authority_disabled");
+        assert_eq!(headline.severity, Severity::Critical);
+        assert!(headline.message.starts_with("Mint authority exists"));
+
+        // Nothing failing, no headline
+        let clean = MockProvider::new("test").with_facts("token", TokenFacts {
+            metadata: Some(Metadata {
+                name: Some("Clean".to_string()),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        assert!(analyze(request, &clean).await.headline.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_timeout_reported_as_error_detail() {
+        let provider = MockProvider::new("test").with_error("token", ProviderError::Timeout);
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+
+        let response = analyze(request, &provider).await;
+
+        let supply = response.error_details.iter().find(|d| d.fact == "supply").unwrap();
+        assert_eq!(supply.code, "timeout");
+        assert!(response.errors.contains(&supply.message));
+        assert_eq!(response.error_details.len(), response.errors.len());
+    }
+
+    #[tokio::test]
+    async fn test_age_falls_back_to_authority_history() {
+        let as_of = 1_700_000_000;
+        let facts = TokenFacts {
+            creation: Some(CreationInfo {
+                created_at: None,
+                age_seconds: None,
+                age_band: AgeBand::Unknown,
+                estimated: false,
+            }),
+            authority_history: Some(vec![AuthorityChange {
+                authority_type: "freezeAccount".to_string(),
+                new_authority: None,
+                signature: Some("sig".to_string()),
+                block_time: Some(as_of as i64 - 2 * 24 * 3_600),
+                age_seconds: None,
+            }]),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("token", facts);
+
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { as_of: Some(as_of), ..AnalyzeOptions::default() },
+        };
+        let response = analyze(request, &provider).await;
+
+        let age = response.checks.iter().find(|c| c.id == "token_age").unwrap();
+        assert_eq!(age.status, CheckStatus::Pass);
+        assert_eq!(age.value["age_band"], "Day1To7");
+        assert_eq!(age.evidence["estimated"], true);
+    }
+
+    #[tokio::test]
+    async fn test_incinerator_holding_counted_as_burn() {
+        let holder = |address: &str, pct: f64| HolderBalance {
+            address: address.to_string(),
+            balance_raw: "0".to_string(),
+            balance: None,
+            pct_of_supply: Some(pct),
+            kind: HolderKind::Unclassified,
+            label: None,
+        };
+        let facts = TokenFacts {
+            holders: Some(HolderInfo {
+                top1_pct: Some(40.0),
+                top5_pct: Some(45.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![
+                    holder(crate::burn::SOLANA_INCINERATOR, 40.0),
+                    holder("wallet", 5.0),
+                ],
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("token", facts);
+
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { holder_strategy: HolderStrategy::Full, ..Default::default() },
+        };
+        let response = analyze(request, &provider).await;
+
+        let residual = response.checks.iter().find(|c| c.id == "residual_supply").unwrap();
+        assert_eq!(residual.evidence["burn_pct"], 40.0);
+        assert_eq!(residual.evidence["top_n_pct"], 5.0);
+    }
+
+    #[tokio::test]
+    async fn test_holder_supply_consistency_needs_unlisted_balance() {
+        let holders = |unlisted_balance_raw: Option<&str>| TokenFacts {
+            holders: Some(HolderInfo {
+                top1_pct: Some(10.0),
+                top5_pct: Some(30.0),
+                holder_count: None,
+                unlisted_balance_raw: unlisted_balance_raw.map(str::to_string),
+                top_holders: Vec::new(),
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test")
+            .with_facts("listed", holders(None))
+            .with_facts("scanned", holders(Some("0")));
+        let has_check = |response: &AnalyzeResponse| {
+            response.checks.iter().any(|c| c.id == "holder_supply_consistency")
+        };
+        let request = |address: &str| AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: address.to_string(),
+            options: AnalyzeOptions::default(),
+        };
+
+        assert!(!has_check(&analyze(request("listed"), &provider).await));
+        assert!(has_check(&analyze(request("scanned"), &provider).await));
+    }
+
+    #[test]
+    fn test_has_identity_needs_name_resolution() {
+        let has_check = |facts: &TokenFacts| {
+            run_checks(facts, "base", &AnalyzeConfig::default()).iter().any(|c| c.id == "has_identity")
+        };
+        let unresolved = TokenFacts { unsupported: vec!["identity".to_string()], ..Default::default() };
+
+        assert!(has_check(&TokenFacts::default()));
+        assert!(!has_check(&unresolved));
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_holder_strategy_skips_full_list() {
+        let wallet = HolderBalance {
+            address: "wallet".to_string(),
+            balance_raw: "400".to_string(),
+            balance: None,
+            pct_of_supply: Some(40.0),
+            kind: HolderKind::Wallet,
+            label: None,
+        };
+        let facts = TokenFacts {
+            holders: Some(HolderInfo {
+                top1_pct: Some(40.0),
+                top5_pct: Some(60.0),
+                holder_count: Some(12),
+                unlisted_balance_raw: None,
+                top_holders: vec![wallet],
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("token", facts);
+        let request = |holder_strategy| AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { holder_strategy, trace_rpc: true, ..Default::default() },
+        };
+        let methods = |response: &AnalyzeResponse| {
+            response.rpc_trace.iter().map(|e| e.method.clone()).collect::<Vec<_>>()
+        };
+
+        let aggregate = analyze(request(HolderStrategy::default()), &provider).await;
+        assert!(methods(&aggregate).contains(&"fetch_holders".to_string()));
+        assert!(!methods(&aggregate).contains(&"fetch_holder_list".to_string()));
+        // Percentages alone still score concentration
+        let concentration = aggregate.checks.iter().find(|c| c.id == "holder_concentration").unwrap();
+        assert_eq!(concentration.evidence["top1_pct"], 40.0);
+        // List-based checks say why they have nothing to judge, and are
+        // not counted as missing coverage
+        let residual = aggregate.checks.iter().find(|c| c.id == "residual_supply").unwrap();
+        assert_eq!(residual.status, CheckStatus::Unknown);
+        assert_eq!(residual.unknown_reason, Some(UnknownReason::NotRequested));
+        assert_eq!(residual.evidence["error"], HOLDER_LIST_NOT_REQUESTED);
+        assert!(!residual.is_expected());
+
+        let full = analyze(request(HolderStrategy::Full), &provider).await;
+        assert!(methods(&full).contains(&"fetch_holder_list".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_explain_level_shapes() {
+        let provider = MockProvider::new("test").with_facts("token", TokenFacts::default());
+        let request = |explain_level| AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { explain_level, ..AnalyzeOptions::default() },
+        };
+
+        let none = analyze(request(ExplainLevel::None), &provider).await;
+        assert!(none.explain.is_none());
+        assert!(serde_json::to_value(&none).unwrap().get("explain").is_none());
+
+        let summary = analyze(request(ExplainLevel::Summary), &provider).await;
+        let json = serde_json::to_value(&summary).unwrap();
+        let keys: Vec<&String> = json["explain"].as_object().unwrap().keys().collect();
+        assert_eq!(keys, vec!["summary"]);
+
+        let full = analyze(request(ExplainLevel::Full), &provider).await;
+        let json = serde_json::to_value(&full).unwrap();
+        for key in ["headline", "summary", "details", "method", "interpretation"] {
+            assert!(json["explain"].get(key).is_some(), "full explain missing {}", key);
+        }
+        assert_eq!(full.explain.unwrap().summary, summary.explain.unwrap().summary);
+    }
+
+    #[tokio::test]
+    async fn test_rpc_calls_counted_per_analysis() {
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("counted_token", facts);
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "counted_token".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+
+        let response = analyze(request, &provider).await;
+
+        // Counted without `trace_rpc`: one call per fetched fact
+        assert!(response.rpc_trace.is_empty());
+        let counts = &response.rpc_calls;
+        assert_eq!(counts.total, 8);
+        for method in [
+            "fetch_metadata",
+            "fetch_supply",
+            "fetch_authorities",
+            "fetch_holders",
+            "fetch_creation_time",
+            "fetch_tax_info",
+            "fetch_authority_history",
+            "fetch_rent_status",
+        ] {
+            assert_eq!(counts.by_method.get(method), Some(&1), "{}", method);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_analyze_rpc_trace_lists_methods() {
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                name: Some("TraceToken".to_string()),
+                symbol: Some("TRACE".to_string()),
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let provider = MockProvider::new("test").with_facts("trace_token", facts);
+
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "trace_token".to_string(),
+            options: AnalyzeOptions {
+                trace_rpc: true,
+                ..AnalyzeOptions::default()
+            },
+        };
+
+        let response = analyze(request.clone(), &provider).await;
+
+        let methods: Vec<&str> = response.rpc_trace.iter().map(|e| e.method.as_str()).collect();
+        for expected in [
+            "fetch_metadata",
+            "fetch_supply",
+            "fetch_authorities",
+            "fetch_holders",
+            "fetch_creation_time",
+            "fetch_tax_info",
+        ] {
+            assert!(methods.contains(&expected), "trace missing {}", expected);
+        }
+        assert_eq!(response.rpc_trace[0].status, "ok");
+        assert_eq!(response.rpc_trace[1].status, "NotFound");
+        assert!(response.rpc_trace.iter().all(|e| e.response.is_none()));
+
+        // Trace stays off unless requested
+        let untraced = AnalyzeRequest {
+            options: AnalyzeOptions::default(),
+            ..request
+        };
+        let response = analyze(untraced, &provider).await;
+        assert!(response.rpc_trace.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_analyze_with_config_min_coverage() {
+        // Only metadata is known: standard sanity is the sole scored check
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("thin_token", facts);
+
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "thin_token".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+        let config = AnalyzeConfig {
+            scoring: crate::scoring::ScoringProfile { min_coverage: 60.0, ..Default::default() },
+            ..Default::default()
+        };
+
+        let response = analyze_with_config(request, &provider, &config).await;
+
+        assert!(matches!(response.score.grade, Grade::Insufficient));
+        assert!(response.explain.as_ref().unwrap().summary.contains("Not enough"));
+    }
+
+    #[tokio::test]
+    async fn test_scoring_config_echoes_applied_weights() {
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            holders: Some(HolderInfo {
+                top1_pct: Some(5.0),
+                top5_pct: Some(20.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![],
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("token", facts);
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { include_scoring_config: true, ..Default::default() },
+        };
+        let config = AnalyzeConfig {
+            scoring: crate::scoring::ScoringProfile {
+                min_coverage: 30.0,
+                confidence_weighting: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let response = analyze_with_config(request, &provider, &config).await;
+        let echoed = response.scoring_config.as_ref().unwrap();
+
+        assert_eq!(echoed.weights.len(), response.score.components.len());
+        for component in &response.score.components {
+            assert_eq!(echoed.weights[&component.id], component.weight, "{}", component.id);
+        }
+        assert!(echoed.confidence_weighting);
+        assert_eq!(echoed.grade_cutoffs.strong, 80);
+        assert!(echoed.override_rules.iter().any(|r| r.contains("30% known check weight")));
+    }
+
+    #[tokio::test]
+    async fn test_analyze_partial_data() {
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                name: Some("PartialToken".to_string()),
+                symbol: Some("PART".to_string()),
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            supply: None, // Missing supply
+            authorities: Some(AuthorityInfo {
+                mint_authority: None,
+                freeze_authority: None,
+                owner: None,
+                mint_mutable: Some(false),
+                ..Default::default()
+            }),
+            holders: None, // Missing holders
+            creation: None, // Missing creation
+            ..Default::default()
+        };
+
+        let provider = MockProvider::new("test").with_facts("partial_token", facts);
+
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "partial_token".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+
+        let response = analyze(request, &provider).await;
+
+        assert_eq!(response.status, AnalysisStatus::Partial);
+        assert!(!response.errors.is_empty());
+        
+        // Some checks should be unknown
+        let unknown_count = response.checks.iter()
+            .filter(|c| matches!(c.status, CheckStatus::Unknown))
+            .count();
+        assert!(unknown_count > 0);
+    }
+
+    #[tokio::test]
+    async fn test_preflight_auth_failure_fails_fast() {
+        let provider = MockProvider::new("test")
+            .with_facts("token", TokenFacts::default())
+            .with_ping_error(ProviderError::Unauthorized);
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { preflight_ping: true, trace_rpc: true, ..AnalyzeOptions::default() },
+        };
+
+        let response = analyze(request.clone(), &provider).await;
+
+        assert_eq!(response.status, AnalysisStatus::Error);
+        assert!(response.checks.is_empty());
+        assert_eq!(response.errors, vec![PROVIDER_DOWN_MESSAGE.to_string()]);
+        assert_eq!(response.error_details[0].code, "unauthorized");
+        assert_eq!(response.explain.unwrap().summary, PROVIDER_DOWN_MESSAGE);
+        // The ping was the only call made
+        let methods: Vec<&str> = response.rpc_trace.iter().map(|e| e.method.as_str()).collect();
+        assert_eq!(methods, vec!["ping"]);
+
+        // Without the flag the analysis runs as before
+        let unchecked = AnalyzeRequest {
+            options: AnalyzeOptions { trace_rpc: true, ..AnalyzeOptions::default() },
+            ..request
+        };
+        let response = analyze(unchecked, &provider).await;
+        assert!(!response.checks.is_empty());
+        assert!(!response.rpc_trace.iter().any(|e| e.method == "ping"));
+    }
+
+    #[tokio::test]
+    async fn test_preflight_ignores_transient_ping_failure() {
+        let provider = MockProvider::new("test")
+            .with_facts("token", TokenFacts::default())
+            .with_ping_error(ProviderError::Timeout);
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { preflight_ping: true, ..AnalyzeOptions::default() },
+        };
+
+        let response = analyze(request, &provider).await;
+        assert!(!response.checks.is_empty());
+        assert!(!response.errors.contains(&PROVIDER_DOWN_MESSAGE.to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_partial_penalty_downranks_partial_analysis() {
+        use crate::scoring::{PartialPenalty, ScoringProfile};
+
+        // Authorities revoked and metadata clean, but supply, holders and
+        // creation are missing
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            authorities: Some(AuthorityInfo {
+                mint_mutable: Some(false),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("partial_token", facts);
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "partial_token".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+        let with_penalty = |partial_penalty| AnalyzeConfig {
+            scoring: ScoringProfile { partial_penalty, ..Default::default() },
+            ..Default::default()
+        };
+
+        let unpenalized = analyze_with_config(request.clone(), &provider, &AnalyzeConfig::default()).await;
+        assert_eq!(unpenalized.status, AnalysisStatus::Partial);
+        assert!(matches!(unpenalized.score.grade, Grade::Strong));
+        assert_eq!(unpenalized.score.override_reason, None);
+
+        let demoted = analyze_with_config(request.clone(), &provider, &with_penalty(PartialPenalty::DemoteGrade)).await;
+        assert!(matches!(demoted.score.grade, Grade::Mixed));
+        assert_eq!(demoted.score.fairness_score, unpenalized.score.fairness_score);
+        assert!(demoted.score.override_reason.unwrap().contains("demoted one step"));
+
+        let points = analyze_with_config(request, &provider, &with_penalty(PartialPenalty::Points(30))).await;
+        let expected = unpenalized.score.fairness_score.unwrap() - 30;
+        assert_eq!(points.score.fairness_score, Some(expected));
+        assert!(!matches!(points.score.grade, Grade::Strong));
+        assert!(points.score.override_reason.unwrap().contains("30 points deducted"));
+    }
+
+    #[tokio::test]
+    async fn test_at_slot_analyzes_facts_as_of_that_slot() {
+        let facts_at = |mint_mutable: bool| TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(6),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            authorities: Some(AuthorityInfo {
+                mint_authority: mint_mutable.then(|| "minter".to_string()),
+                mint_mutable: Some(mint_mutable),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        // Mintable at slot 100, authority revoked by slot 200
+        let provider = MockProvider::new("test")
+            .with_facts("token", facts_at(false))
+            .with_facts_at("token", 100, facts_at(true))
+            .with_facts_at("token", 200, facts_at(false));
+        let request = |at_slot| AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { at_slot: Some(at_slot), trace_rpc: true, ..AnalyzeOptions::default() },
+        };
+        let mint_status = |response: &AnalyzeResponse| {
+            response.checks.iter().find(|c| c.id == "mint_authority_disabled").unwrap().status.clone()
+        };
+
+        let early = analyze(request(100), &provider).await;
+        assert_eq!(early.at_slot, Some(100));
+        assert_eq!(early.at_block, None);
+        assert_eq!(mint_status(&early), CheckStatus::Fail);
+        assert!(early.rpc_trace.iter().any(|e| e.method == "fetch_authorities_at"));
+        assert!(!early.rpc_trace.iter().any(|e| e.method == "fetch_authorities"));
+
+        let late = analyze(request(200), &provider).await;
+        assert_eq!(late.at_slot, Some(200));
+        assert_eq!(mint_status(&late), CheckStatus::Pass);
+        assert!(late.sources.iter().any(|s| s.fact == "authorities" && s.method == "fetch_authorities_at"));
+    }
+
+    #[tokio::test]
+    async fn test_current_only_facts_unknown_at_a_slot() {
+        let facts = TokenFacts {
+            creation: Some(CreationInfo {
+                created_at: None,
+                age_seconds: Some(365 * 86_400),
+                age_band: AgeBand::GreaterThan7d,
+                estimated: false,
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test")
+            .with_facts("token", facts.clone())
+            .with_facts_at("token", 100, facts);
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { at_slot: Some(100), trace_rpc: true, ..AnalyzeOptions::default() },
+        };
+
+        let response = analyze(request, &provider).await;
+
+        let age = response.checks.iter().find(|c| c.id == "token_age").unwrap();
+        assert_eq!(age.status, CheckStatus::Unknown);
+        assert_eq!(age.unknown_reason, Some(UnknownReason::Unsupported));
+        assert!(!response.rpc_trace.iter().any(|e| e.method == "fetch_creation_time"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_chain_fails_closed_unless_allowed() {
+        let facts = TokenFacts {
+            holders: Some(HolderInfo {
+                top1_pct: Some(5.0),
+                top5_pct: Some(15.0),
+                holder_count: Some(5_000),
+                top_holders: Vec::new(),
+                unlisted_balance_raw: None,
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("token", facts);
+        let request = AnalyzeRequest {
+            chain: "dogechain".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { trace_rpc: true, ..AnalyzeOptions::default() },
+        };
+
+        let closed = analyze(request.clone(), &provider).await;
+        assert_eq!(closed.status, AnalysisStatus::Error);
+        assert!(closed.checks.is_empty());
+        assert_eq!(closed.score.fairness_score, None);
+        assert_eq!(closed.errors, vec![UNSUPPORTED_CHAIN_MESSAGE.to_string()]);
+        assert_eq!(closed.error_details[0].code, "unsupported_chain");
+        assert!(closed.rpc_trace.is_empty());
+
+        let config = AnalyzeConfig { allow_unknown_chains: true, ..Default::default() };
+        let minimal = analyze_with_config(request, &provider, &config).await;
+        let ids: Vec<&str> = minimal.checks.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["holder_concentration", "residual_supply", "token_age"]);
+        assert!(!minimal.errors.contains(&UNSUPPORTED_CHAIN_MESSAGE.to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_mint_authority_check_links_documentation() {
+        let provider = MockProvider::new("test").with_facts("token", TokenFacts {
+            authorities: Some(AuthorityInfo { mint_mutable: Some(false), ..Default::default() }),
+            ..Default::default()
+        });
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+
+        let response = analyze(request, &provider).await;
+
+        let mint = response.checks.iter().find(|c| c.id == "mint_authority_disabled").unwrap();
+        assert!(mint.learn_more_url.as_deref().is_some_and(|url| !url.is_empty()));
+        let json = serde_json::to_value(mint).unwrap();
+        assert_eq!(json["learn_more_url"], mint.learn_more_url.clone().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_registered_check_is_scored_and_reported() {
+        let facts = TokenFacts {
+            authorities: Some(AuthorityInfo {
+                mint_mutable: Some(false),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("token", facts);
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+        let mut config = AnalyzeConfig::default();
+        config.check_registry.register("house_model", &["solana"], Box::new(|facts, _| CheckResult {
+            id: String::new(),
+            label: "House risk model".to_string(),
+            category: "custom".to_string(),
+            status: CheckStatus::Pass,
+            severity: Severity::Low,
+            value: serde_json::json!(facts.authorities.is_some()),
+            evidence: serde_json::json!({ "source": "house" }),
+            weight: 5,
+            score_component: Some(100),
+            confidence: confidence::INFERRED,
+            unknown_reason: None,
+            learn_more_url: None,
+        })).unwrap();
+
+        let baseline = analyze_with_config(request.clone(), &provider, &AnalyzeConfig::default()).await;
+        let response = analyze_with_config(request, &provider, &config).await;
+
+        let custom = response.checks.iter().find(|c| c.id == "house_model").unwrap();
+        assert_eq!(custom.status, CheckStatus::Pass);
+        assert!(!baseline.checks.iter().any(|c| c.id == "house_model"));
+        assert_eq!(response.score.weights_total, baseline.score.weights_total + 5);
+        assert!(response.score.components.iter().any(|c| c.id == "house_model"));
+    }
+
+    #[test]
+    fn test_contradictory_mint_mutable_normalized() {
+        let mut facts = TokenFacts {
+            authorities: Some(AuthorityInfo {
+                mint_authority: None,
+                mint_mutable: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        normalize_authorities(&mut facts, "solana");
+        assert_eq!(facts.authorities.as_ref().unwrap().mint_mutable, Some(false));
+
+        let mut facts = TokenFacts {
+            authorities: Some(AuthorityInfo {
+                mint_authority: Some("Authority".to_string()),
+                mint_mutable: None,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        normalize_authorities(&mut facts, "solana");
+        assert_eq!(facts.authorities.as_ref().unwrap().mint_mutable, Some(true));
+
+        // EVM mutability follows the owner, not a mint authority
+        let mut facts = TokenFacts {
+            authorities: Some(AuthorityInfo {
+                owner: Some("0xowner".to_string()),
+                mint_mutable: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        normalize_authorities(&mut facts, "base");
+        assert_eq!(facts.authorities.as_ref().unwrap().mint_mutable, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_check_image_reports_unreachable_as_unknown() {
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                // Nothing listens on port 1
+                image_uri: Some("http://127.0.0.1:1/logo.png".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("image_token", facts);
+
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "image_token".to_string(),
+            options: AnalyzeOptions {
+                check_image: true,
+                ..AnalyzeOptions::default()
+            },
+        };
+        let response = analyze(request.clone(), &provider).await;
+
+        let image = response.token.unwrap().image.unwrap();
+        assert_eq!(image.reachable, None);
+        assert!(image.error.is_some());
+        // Informational only: no check is added for it
+        assert!(response.checks.iter().all(|c| !c.id.contains("image")));
+
+        let unprobed = AnalyzeRequest {
+            options: AnalyzeOptions::default(),
+            ..request
+        };
+        let response = analyze(unprobed, &provider).await;
+        assert!(response.token.unwrap().image.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_reason_distinguishes_provider_error() {
+        let provider = MockProvider::new("test")
+            .with_error("flaky_token", ProviderError::Timeout);
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "flaky_token".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+
+        let response = analyze(request.clone(), &provider).await;
+        let mint = response.checks.iter().find(|c| c.id == "mint_authority_disabled").unwrap();
+        assert_eq!(mint.unknown_reason, Some(UnknownReason::ProviderError));
+
+        // Absent (not failed) data is reported as missing
+        let empty = MockProvider::new("test").with_facts("flaky_token", TokenFacts::default());
+        let response = analyze(request, &empty).await;
+        let mint = response.checks.iter().find(|c| c.id == "mint_authority_disabled").unwrap();
+        assert_eq!(mint.unknown_reason, Some(UnknownReason::DataMissing));
+    }
+
+    #[tokio::test]
+    async fn test_as_of_measures_age_from_reference_time() {
+        let created_at = "2026-01-20T00:00:00Z";
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            creation: Some(CreationInfo {
+                created_at: Some(created_at.to_string()),
+                age_seconds: None,
+                age_band: AgeBand::Unknown,
+                estimated: false,
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test").with_facts("aged_token", facts);
+
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "aged_token".to_string(),
+            options: AnalyzeOptions {
+                // One hour after creation
+                as_of: Some(1_768_867_200 + 3_600),
+                ..AnalyzeOptions::default()
+            },
+        };
+        let response = analyze(request.clone(), &provider).await;
+        let token = response.token.unwrap();
+        assert_eq!(token.age_band, "LessThan24h");
+        assert_eq!(token.age_seconds, Some(3_600));
+
+        let now = AnalyzeRequest {
+            options: AnalyzeOptions::default(),
+            ..request
+        };
+        let response = analyze(now, &provider).await;
+        assert_eq!(response.token.unwrap().age_band, "GreaterThan7d");
     }
 }
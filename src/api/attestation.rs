@@ -0,0 +1,166 @@
+// src/api/attestation.rs
+//
+// HMAC attestation over a response, so a third party holding the shared
+// secret can check a result came from this service unmodified.
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use super::types::AnalyzeResponse;
+
+pub const ATTESTATION_ALG: &str = "HMAC-SHA256";
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Attestation {
+    pub alg: String,
+    /// Hex-encoded MAC over the canonical response
+    pub value: String,
+}
+
+/// Deterministic serialization: object keys sorted at every level, no
+/// whitespace, and the top-level `attestation` field excluded.
+pub fn canonical_json(value: &serde_json::Value) -> String {
+    let mut out = String::new();
+    write_canonical(value, true, &mut out);
+    out
+}
+
+fn write_canonical(value: &serde_json::Value, top_level: bool, out: &mut String) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys()
+                .filter(|k| !(top_level && k.as_str() == "attestation"))
+                .collect();
+            keys.sort();
+
+            out.push('{');
+            for (i, key) in keys.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&serde_json::Value::String((*key).clone()).to_string());
+                out.push(':');
+                write_canonical(&map[key.as_str()], false, out);
+            }
+            out.push('}');
+        }
+        serde_json::Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, false, out);
+            }
+            out.push(']');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+fn mac_for(value: &serde_json::Value, secret: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(secret)
+        .expect("HMAC accepts keys of any length");
+    mac.update(canonical_json(value).as_bytes());
+    mac
+}
+
+/// Sign `response` (ignoring any attestation already attached); None when
+/// it cannot be serialized, since there is then nothing to vouch for
+pub fn sign_response(response: &AnalyzeResponse, secret: &[u8]) -> Option<Attestation> {
+    let value = serde_json::to_value(response).ok()?;
+
+    Some(Attestation {
+        alg: ATTESTATION_ALG.to_string(),
+        value: hex::encode(mac_for(&value, secret).finalize().into_bytes()),
+    })
+}
+
+/// Sign `response` and attach the attestation to it. A response that
+/// cannot be signed goes out without one rather than with a MAC over
+/// something else.
+pub fn attach_attestation(response: &mut AnalyzeResponse, secret: &[u8]) {
+    response.attestation = sign_response(response, secret);
+}
+
+/// Verify a response as received over the wire (JSON with `attestation`)
+pub fn verify_attestation_json(value: &serde_json::Value, secret: &[u8]) -> bool {
+    let attestation: Attestation = match value.get("attestation")
+        .and_then(|a| serde_json::from_value(a.clone()).ok())
+    {
+        Some(a) => a,
+        None => return false,
+    };
+
+    if attestation.alg != ATTESTATION_ALG {
+        return false;
+    }
+
+    let expected = match hex::decode(&attestation.value) {
+        Ok(bytes) => bytes,
+        Err(_) => return false,
+    };
+
+    // Constant-time comparison
+    mac_for(value, secret).verify_slice(&expected).is_ok()
+}
+
+/// Verify an in-memory response against its attached attestation
+pub fn verify_attestation(response: &AnalyzeResponse, secret: &[u8]) -> bool {
+    match serde_json::to_value(response) {
+        Ok(value) => verify_attestation_json(&value, secret),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::simple_cache::tests::make_test_response;
+    use serde_json::json;
+
+    const SECRET: &[u8] = b"test-secret";
+
+    #[test]
+    fn test_sign_and_verify() {
+        let mut response = make_test_response();
+        attach_attestation(&mut response, SECRET);
+
+        let attestation = response.attestation.clone().unwrap();
+        assert_eq!(attestation.alg, "HMAC-SHA256");
+        assert_eq!(attestation.value.len(), 64);
+
+        assert!(verify_attestation(&response, SECRET));
+        assert!(!verify_attestation(&response, b"wrong-secret"));
+
+        // Survives a JSON round trip, as a third party would receive it
+        let wire = serde_json::to_string(&response).unwrap();
+        let received: serde_json::Value = serde_json::from_str(&wire).unwrap();
+        assert!(verify_attestation_json(&received, SECRET));
+    }
+
+    #[test]
+    fn test_tampering_fails_verification() {
+        let mut response = make_test_response();
+        attach_attestation(&mut response, SECRET);
+
+        let mut tampered = response.clone();
+        tampered.score.fairness_score = Some(42);
+        assert!(!verify_attestation(&tampered, SECRET));
+
+        let mut received = serde_json::to_value(&response).unwrap();
+        received["address"] = json!("other_address");
+        assert!(!verify_attestation_json(&received, SECRET));
+    }
+
+    #[test]
+    fn test_canonical_json_is_key_order_independent() {
+        let a = json!({"b": 1, "a": {"y": [1, 2], "x": null}});
+        let b: serde_json::Value = serde_json::from_str(r#"{"a":{"x":null,"y":[1,2]},"b":1}"#).unwrap();
+
+        assert_eq!(canonical_json(&a), canonical_json(&b));
+        assert_eq!(canonical_json(&a), r#"{"a":{"x":null,"y":[1,2]},"b":1}"#);
+    }
+}
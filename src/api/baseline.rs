@@ -0,0 +1,83 @@
+// src/api/baseline.rs
+//
+// Comparison against a textbook fair launch: mint and freeze disabled,
+// ownership renounced, top holder under 10%, older than a week, standard
+// token program. Answers "how does this compare to a fair launch?".
+
+use crate::types::{CheckResult, CheckStatus};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use super::types::AnalyzeResponse;
+
+/// Top-holder share a fair launch stays under
+pub const BASELINE_MAX_TOP1_PCT: f64 = 10.0;
+
+/// One way the token falls short of the reference fair launch
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BaselineDelta {
+    pub check_id: String,
+    /// What the fair launch looks like on this dimension
+    pub expected: String,
+    /// The check's `value` for this token
+    pub actual: Value,
+    pub reason: DeviationReason,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviationReason {
+    /// The check shows the token differs from the baseline
+    FallsShort,
+    /// The check could not be verified, so the baseline is not shown to hold
+    Unverified,
+}
+
+struct Expectation {
+    check_id: &'static str,
+    expected: &'static str,
+    meets: fn(&CheckResult) -> bool,
+}
+
+fn passes(check: &CheckResult) -> bool {
+    check.status == CheckStatus::Pass
+}
+
+fn top1_below_baseline(check: &CheckResult) -> bool {
+    check.evidence["top1_pct"].as_f64().is_some_and(|pct| pct < BASELINE_MAX_TOP1_PCT)
+}
+
+fn older_than_week(check: &CheckResult) -> bool {
+    check.value["age_band"] == "GreaterThan7d"
+}
+
+const FAIR_LAUNCH: [Expectation; 6] = [
+    Expectation { check_id: "mint_authority_disabled", expected: "mint authority disabled", meets: passes },
+    Expectation { check_id: "freeze_authority_disabled", expected: "freeze authority disabled", meets: passes },
+    Expectation { check_id: "ownership_renounced", expected: "ownership renounced", meets: passes },
+    Expectation { check_id: "holder_concentration", expected: "top holder below 10% of supply", meets: top1_below_baseline },
+    Expectation { check_id: "token_age", expected: "older than 7 days", meets: older_than_week },
+    Expectation { check_id: "standard_sanity", expected: "standard token program", meets: passes },
+];
+
+/// Where `response` falls short of the reference fair launch. Dimensions
+/// whose check did not run for this chain are skipped.
+pub fn compare_to_baseline(response: &AnalyzeResponse) -> Vec<BaselineDelta> {
+    FAIR_LAUNCH.iter()
+        .filter_map(|expectation| {
+            let check = response.checks.iter().find(|c| c.id == expectation.check_id)?;
+            let reason = if check.status == CheckStatus::Unknown {
+                DeviationReason::Unverified
+            } else if (expectation.meets)(check) {
+                return None;
+            } else {
+                DeviationReason::FallsShort
+            };
+            Some(BaselineDelta {
+                check_id: check.id.clone(),
+                expected: expectation.expected.to_string(),
+                actual: check.value.clone(),
+                reason,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,66 @@
+// src/api/cache_delta.rs
+//
+// What a forced refresh changed relative to the cached result it replaced.
+
+use crate::types::{CheckStatus, Grade};
+use serde::{Deserialize, Serialize};
+use super::types::AnalyzeResponse;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CacheDelta {
+    /// `analysis_id` of the cached result
+    pub previous_analysis_id: String,
+    pub previous_grade: Grade,
+    pub previous_score: Option<u8>,
+    /// Fresh score minus cached score; None when either is unscored
+    pub score_delta: Option<i16>,
+    pub grade_changed: bool,
+    /// Checks whose status differs, in fresh response order
+    pub changed_checks: Vec<CheckStatusChange>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CheckStatusChange {
+    pub check_id: String,
+    /// None when the check did not run in the cached result
+    pub previous: Option<CheckStatus>,
+    /// None when the check no longer runs
+    pub current: Option<CheckStatus>,
+}
+
+/// Delta of `current` against the `previous` cached response
+pub fn diff_responses(previous: &AnalyzeResponse, current: &AnalyzeResponse) -> CacheDelta {
+    let status_in = |response: &AnalyzeResponse, id: &str| {
+        response.checks.iter().find(|c| c.id == id).map(|c| c.status.clone())
+    };
+
+    let mut changed_checks: Vec<CheckStatusChange> = current.checks.iter()
+        .map(|c| CheckStatusChange {
+            check_id: c.id.clone(),
+            previous: status_in(previous, &c.id),
+            current: Some(c.status.clone()),
+        })
+        .filter(|change| change.previous != change.current)
+        .collect();
+    changed_checks.extend(previous.checks.iter()
+        .filter(|c| status_in(current, &c.id).is_none())
+        .map(|c| CheckStatusChange {
+            check_id: c.id.clone(),
+            previous: Some(c.status.clone()),
+            current: None,
+        }));
+
+    let score_delta = previous.score.fairness_score
+        .zip(current.score.fairness_score)
+        .map(|(before, after)| after as i16 - before as i16);
+
+    CacheDelta {
+        previous_analysis_id: previous.analysis_id.clone(),
+        previous_grade: previous.score.grade.clone(),
+        previous_score: previous.score.fairness_score,
+        score_delta,
+        grade_changed: std::mem::discriminant(&previous.score.grade)
+            != std::mem::discriminant(&current.score.grade),
+        changed_checks,
+    }
+}
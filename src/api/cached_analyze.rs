@@ -1,39 +1,246 @@
-use crate::api::types::{AnalyzeRequest, AnalyzeResponse};
-use crate::providers::TokenProvider;
-use crate::cache::{SimpleCache, simple_cache::ttl_for_response};
-use super::analyze::analyze;
+use crate::api::types::{AnalysisStatus, AnalyzeRequest, AnalyzeResponse, CheckSummary, Verdict};
+use crate::providers::{RpcCallCount, TokenProvider};
+use crate::scoring::aggregate_score_with_profile;
+use crate::cache::{CacheBackend, simple_cache::{self, mark_cached, ttl_for_response}};
+use super::analyze::{analyze_with_config, current_timestamp, generate_analysis_id};
+use super::cache_delta::diff_responses;
+use super::config::AnalyzeConfig;
+use super::chain::{normalize_address, normalize_chain};
+use futures_util::stream::{self, StreamExt};
+use std::collections::{HashMap, HashSet};
+use tokio::sync::Mutex;
 
-pub async fn analyze_with_cache<P: TokenProvider>(
+/// Analyses `analyze_many` runs at once
+pub const ANALYZE_MANY_CONCURRENCY: usize = 4;
+
+/// Error reported on a `cache_only` miss
+pub const NOT_CACHED_MESSAGE: &str = "no cached result available";
+
+pub async fn analyze_with_cache<P: TokenProvider + ?Sized>(
     request: AnalyzeRequest,
     provider: &P,
-    cache: &mut SimpleCache,
+    cache: &mut impl CacheBackend,
 ) -> AnalyzeResponse {
-    // Generate cache key
-    let cache_key = format!(
-        "{}:{}:{}:{}",
-        request.chain,
-        request.address,
-        request.options.include_holders,
-        request.options.max_holders
-    );
+    analyze_with_cache_and_config(request, provider, cache, &AnalyzeConfig::default()).await
+}
+
+/// `analyze_with_cache` with operator-supplied configuration
+pub async fn analyze_with_cache_and_config<P: TokenProvider + ?Sized>(
+    request: AnalyzeRequest,
+    provider: &P,
+    cache: &mut impl CacheBackend,
+    config: &AnalyzeConfig,
+) -> AnalyzeResponse {
+    let cache_key = cache_key(&request);
 
     // Check cache first (unless force_refresh)
-    if !request.options.force_refresh {
-        if let Some(cached_response) = cache.get(&cache_key) {
+    if !request.options.force_refresh || request.options.cache_only {
+        if let Some(cached_response) = cached(cache, &cache_key) {
             return cached_response;
         }
     }
+    if request.options.cache_only {
+        return not_cached(&request, config);
+    }
 
     // Cache miss or force refresh - fetch fresh data
-    let response = analyze(request, provider).await;
+    let previous = cached(cache, &cache_key);
+    let mut response = analyze_with_config(request, provider, config).await;
+    store(cache, cache_key, &response);
+    response.changed_since_cache = previous.map(|previous| diff_responses(&previous, &response));
 
-    // Determine TTL based on token age
-    let ttl = ttl_for_response(&response);
+    response
+}
 
-    // Store in cache
-    cache.set(cache_key, response.clone(), ttl);
+/// `analyze_with_cache_and_config` for a cache shared between tasks. The
+/// lock is held only to read and to store, never across provider calls, so
+/// concurrent analyses of different tokens proceed in parallel.
+/// With `config.coalescer` set, concurrent misses for the same key share
+/// one analysis; the extra responses are marked as served from cache.
+pub async fn analyze_with_shared_cache<P: TokenProvider + ?Sized, C: CacheBackend>(
+    request: AnalyzeRequest,
+    provider: &P,
+    cache: &Mutex<C>,
+    config: &AnalyzeConfig,
+) -> AnalyzeResponse {
+    let cache_key = cache_key(&request);
 
-    response
+    if !request.options.force_refresh || request.options.cache_only {
+        let hit = cached(&*cache.lock().await, &cache_key);
+        if let Some(cached_response) = hit {
+            return cached_response;
+        }
+    }
+    if request.options.cache_only {
+        return not_cached(&request, config);
+    }
+
+    // Identical requests arriving together share one analysis
+    let coalescer = config.coalescer.as_ref().filter(|_| !request.options.force_refresh);
+    let store_key = cache_key.clone();
+    let fresh = move || async move {
+        let mut response = analyze_with_config(request, provider, config).await;
+        let previous = {
+            let mut cache = cache.lock().await;
+            let previous = cached(&*cache, &store_key);
+            store(&mut *cache, store_key, &response);
+            previous
+        };
+        response.changed_since_cache = previous.map(|previous| diff_responses(&previous, &response));
+        response
+    };
+    match coalescer {
+        Some(coalescer) => {
+            let (mut response, shared) = coalescer.run(&cache_key, fresh).await;
+            if shared {
+                response.rpc_trace.clear();
+                response.rpc_calls = RpcCallCount::default();
+                mark_cached(&mut response, simple_cache::current_timestamp());
+            }
+            response
+        }
+        None => fresh().await,
+    }
+}
+
+/// Analyze a batch of tokens with one provider and cache, running at most
+/// `ANALYZE_MANY_CONCURRENCY` analyses at a time. Output order matches input.
+/// Repeats of a request within the batch are served from cache.
+pub async fn analyze_many<P: TokenProvider + ?Sized>(
+    requests: Vec<AnalyzeRequest>,
+    provider: &P,
+    cache: &mut impl CacheBackend,
+    config: &AnalyzeConfig,
+) -> Vec<AnalyzeResponse> {
+    let mut results: Vec<Option<AnalyzeResponse>> = vec![None; requests.len()];
+    let mut to_fetch = Vec::new();
+    let mut deferred = Vec::new();
+    let mut pending_keys = HashSet::new();
+
+    for (index, request) in requests.into_iter().enumerate() {
+        let key = cache_key(&request);
+        if !request.options.force_refresh || request.options.cache_only {
+            if pending_keys.contains(&key) {
+                deferred.push((index, key));
+                continue;
+            }
+            if let Some(cached_response) = cached(cache, &key) {
+                results[index] = Some(cached_response);
+                continue;
+            }
+        }
+        if request.options.cache_only {
+            results[index] = Some(not_cached(&request, config));
+            continue;
+        }
+        pending_keys.insert(key.clone());
+        to_fetch.push((index, key, request));
+    }
+
+    let fresh: Vec<(usize, String, AnalyzeResponse)> = stream::iter(to_fetch)
+        .map(|(index, key, request)| {
+            async move { (index, key, analyze_with_config(request, provider, config).await) }
+        })
+        .buffered(ANALYZE_MANY_CONCURRENCY)
+        .collect()
+        .await;
+
+    // Repeats are answered from this batch's own results, not re-read from
+    // the backend, which may have evicted or never kept the entry
+    let mut by_key: HashMap<String, AnalyzeResponse> = HashMap::new();
+    for (index, key, response) in fresh {
+        store(cache, key.clone(), &response);
+        by_key.insert(key, response.clone());
+        results[index] = Some(response);
+    }
+
+    let now = simple_cache::current_timestamp();
+    for (index, key) in deferred {
+        results[index] = by_key.get(&key).map(|response| {
+            let mut response = response.clone();
+            response.rpc_trace.clear();
+            response.rpc_calls = RpcCallCount::default();
+            mark_cached(&mut response, now);
+            response
+        });
+    }
+
+    results.into_iter().flatten().collect()
+}
+
+/// Response for a `cache_only` miss: no checks, no provider calls
+fn not_cached(request: &AnalyzeRequest, config: &AnalyzeConfig) -> AnalyzeResponse {
+    let chain = normalize_chain(&request.chain).unwrap_or_else(|| request.chain.clone());
+    let score = aggregate_score_with_profile(&[], &config.scoring);
+    let verdict = Verdict::from_score(&score, &[]);
+
+    AnalyzeResponse {
+        schema_version: "1.0.0".to_string(),
+        analysis_id: generate_analysis_id(),
+        requested_at: current_timestamp(),
+        address: normalize_address(&chain, &request.address),
+        chain,
+        status: AnalysisStatus::NotCached,
+        token: None,
+        checks: Vec::new(),
+        score,
+        verdict,
+        summary: CheckSummary::default(),
+        explain: None,
+        errors: vec![NOT_CACHED_MESSAGE.to_string()],
+        error_details: Vec::new(),
+        sources: Vec::new(),
+        baseline_deltas: None,
+        scoring_config: None,
+        headline: None,
+        rpc_trace: Vec::new(),
+        attestation: None,
+        changed_since_cache: None,
+        age_of_analysis_seconds: None,
+        facts_hash: None,
+        rpc_calls: RpcCallCount::default(),
+        at_slot: None,
+        at_block: None,
+    }
+}
+
+fn cache_key(request: &AnalyzeRequest) -> String {
+    // Aliases share an entry with their canonical chain
+    let chain = normalize_chain(&request.chain).unwrap_or_else(|| request.chain.clone());
+    format!(
+        "{}:{}:{}:{}:{}:{:?}:{:?}:{:?}:{:?}:{}:{}:{}:{}:{:?}:{:?}:{}:{:?}",
+        chain,
+        normalize_address(&chain, &request.address),
+        request.options.include_holders,
+        request.options.max_holders,
+        request.options.check_image,
+        request.options.as_of,
+        request.options.explain_level,
+        request.options.number_format(),
+        request.options.supply_baseline,
+        request.options.include_baseline,
+        request.options.include_scoring_config,
+        request.options.simulate_mint,
+        request.options.stable_check_order,
+        request.options.rpc_url_override,
+        request.options.holder_strategy,
+        request.options.include_facts_hash,
+        request.options.point_in_time(&chain)
+    )
+}
+
+fn cached(cache: &impl CacheBackend, key: &str) -> Option<AnalyzeResponse> {
+    let mut response = cache.get(key)?;
+    // A cache hit makes no provider calls
+    response.rpc_trace.clear();
+    response.rpc_calls = RpcCallCount::default();
+    Some(response)
+}
+
+fn store(cache: &mut impl CacheBackend, key: String, response: &AnalyzeResponse) {
+    // Determine TTL based on token age
+    let ttl = ttl_for_response(response);
+    cache.set(key, response.clone(), ttl);
 }
 
 #[cfg(test)]
@@ -42,6 +249,7 @@ mod tests {
     use crate::providers::mocks::MockProvider;
     use crate::types::*;
     use crate::api::types::AnalyzeOptions;
+    use crate::cache::{SimpleCache, simple_cache::is_from_cache};
 
     #[tokio::test]
     async fn test_cache_hit() {
@@ -51,27 +259,34 @@ mod tests {
                 symbol: Some("TEST".to_string()),
                 decimals: Some(9),
                 standard: TokenStandard::SplToken,
+                ..Default::default()
             }),
             authorities: Some(AuthorityInfo {
                 mint_authority: None,
                 freeze_authority: None,
                 owner: None,
                 mint_mutable: Some(false),
+                ..Default::default()
             }),
             supply: Some(SupplyInfo {
                 total_supply: Some(1000000.0),
                 total_supply_raw: Some("1000000".to_string()),
+                circulating_supply: None,
             }),
             holders: Some(HolderInfo {
                 top1_pct: Some(10.0),
                 top5_pct: Some(30.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
                 top_holders: vec![],
             }),
             creation: Some(CreationInfo {
                 created_at: Some("2026-01-20T00:00:00Z".to_string()),
                 age_seconds: Some(864000),
                 age_band: AgeBand::GreaterThan7d,
+                estimated: false,
             }),
+            ..Default::default()
         };
 
         let provider = MockProvider::new("test").with_facts("test_token", facts);
@@ -94,6 +309,56 @@ mod tests {
         // Should return same analysis (from cache)
         assert_eq!(analysis_id1, analysis_id2);
         assert_eq!(cache.size(), 1);
+
+        // Only the miss cost provider calls
+        assert!(response1.rpc_calls.total > 0);
+        assert_eq!(response2.rpc_calls, RpcCallCount::default());
+    }
+
+    #[tokio::test]
+    async fn test_force_refresh_reports_change_since_cache() {
+        let authorities = |mint_authority: Option<&str>| TokenFacts {
+            authorities: Some(AuthorityInfo {
+                mint_authority: mint_authority.map(str::to_string),
+                freeze_authority: None,
+                mint_mutable: Some(mint_authority.is_some()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let renounced = MockProvider::new("test").with_facts("test_token", authorities(None));
+        let reenabled = MockProvider::new("test")
+            .with_facts("test_token", authorities(Some("MintAuth111")));
+        let mut cache = SimpleCache::new();
+
+        let request = |force_refresh| AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "test_token".to_string(),
+            options: AnalyzeOptions { force_refresh, ..AnalyzeOptions::default() },
+        };
+
+        // Nothing cached yet: no annotation
+        let before = analyze_with_cache(request(true), &renounced, &mut cache).await;
+        assert!(before.changed_since_cache.is_none());
+
+        let after = analyze_with_cache(request(true), &reenabled, &mut cache).await;
+        let delta = after.changed_since_cache.as_ref().unwrap();
+        assert_eq!(delta.previous_analysis_id, before.analysis_id);
+        assert_eq!(delta.previous_score, before.score.fairness_score);
+        assert!(delta.score_delta.unwrap() < 0);
+        assert!(delta.grade_changed);
+        let mint = delta.changed_checks.iter()
+            .find(|c| c.check_id == "mint_authority_disabled")
+            .unwrap();
+        assert_eq!(mint.previous, Some(CheckStatus::Pass));
+        assert_eq!(mint.current, Some(CheckStatus::Fail));
+
+        // The delta is not stored with the cached result
+        let hit = analyze_with_cache(request(false), &reenabled, &mut cache).await;
+        assert!(is_from_cache(&hit));
+        assert!(hit.changed_since_cache.is_none());
+        assert!(hit.age_of_analysis_seconds.is_some());
+        assert_eq!(after.age_of_analysis_seconds, None);
     }
 
     #[tokio::test]
@@ -104,16 +369,19 @@ mod tests {
                 symbol: Some("TEST".to_string()),
                 decimals: Some(9),
                 standard: TokenStandard::SplToken,
+                ..Default::default()
             }),
             authorities: Some(AuthorityInfo {
                 mint_authority: None,
                 freeze_authority: None,
                 owner: None,
                 mint_mutable: Some(false),
+                ..Default::default()
             }),
             supply: None,
             holders: None,
             creation: None,
+            ..Default::default()
         };
 
         let provider = MockProvider::new("test").with_facts("test_token", facts);
@@ -126,6 +394,7 @@ mod tests {
                 include_holders: true,
                 max_holders: 10,
                 force_refresh: false,
+                ..AnalyzeOptions::default()
             },
         };
 
@@ -148,4 +417,270 @@ mod tests {
         // Should have different analysis IDs (fresh analysis)
         assert_ne!(id1, id2);
     }
+
+    #[tokio::test]
+    async fn test_analyze_many_preserves_order_and_reuses_cache() {
+        let token = |name: &str| TokenFacts {
+            metadata: Some(Metadata {
+                name: Some(name.to_string()),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let provider = MockProvider::new("test")
+            .with_facts("token_a", token("A"))
+            .with_facts("token_b", token("B"));
+        let mut cache = SimpleCache::new();
+
+        let request = |address: &str| AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: address.to_string(),
+            options: AnalyzeOptions::default(),
+        };
+
+        let responses = analyze_many(
+            vec![request("token_a"), request("token_b"), request("token_a")],
+            &provider,
+            &mut cache,
+            &AnalyzeConfig::default(),
+        ).await;
+
+        let addresses: Vec<&str> = responses.iter().map(|r| r.address.as_str()).collect();
+        assert_eq!(addresses, vec!["token_a", "token_b", "token_a"]);
+
+        assert!(!is_from_cache(&responses[0]));
+        assert!(!is_from_cache(&responses[1]));
+        assert!(is_from_cache(&responses[2]));
+        assert_eq!(responses[2].analysis_id, responses[0].analysis_id);
+        assert_eq!(cache.size(), 2);
+    }
+
+    /// Keeps nothing: every `get` misses
+    struct NullCache;
+
+    impl CacheBackend for NullCache {
+        fn get(&self, _key: &str) -> Option<AnalyzeResponse> {
+            None
+        }
+
+        fn set(&mut self, _key: String, _response: AnalyzeResponse, _ttl_seconds: u64) {}
+    }
+
+    #[tokio::test]
+    async fn test_analyze_many_repeats_without_backend_retention() {
+        let provider = MockProvider::new("test")
+            .with_facts("token_a", TokenFacts::default());
+        let request = || AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token_a".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+
+        let responses = analyze_many(
+            vec![request(), request()],
+            &provider,
+            &mut NullCache,
+            &AnalyzeConfig::default(),
+        ).await;
+
+        assert_eq!(responses.len(), 2);
+        assert!(!is_from_cache(&responses[0]));
+        assert!(is_from_cache(&responses[1]));
+        assert_eq!(responses[1].analysis_id, responses[0].analysis_id);
+        assert!(responses[1].rpc_trace.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_evm_address_casings_share_cache_entry() {
+        let facts = TokenFacts {
+            authorities: Some(AuthorityInfo {
+                owner: Some("0x00000000000000000000000000000000000000aa".to_string()),
+                mint_mutable: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let checksummed = "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913";
+        let provider = MockProvider::new("test")
+            .with_facts(&checksummed.to_ascii_lowercase(), facts);
+        let mut cache = SimpleCache::new();
+
+        let request = |address: &str| AnalyzeRequest {
+            chain: "base".to_string(),
+            address: address.to_string(),
+            options: AnalyzeOptions::default(),
+        };
+
+        let first = analyze_with_cache(request(checksummed), &provider, &mut cache).await;
+        let second = analyze_with_cache(
+            request(&checksummed.to_ascii_lowercase()),
+            &provider,
+            &mut cache,
+        ).await;
+
+        assert_eq!(cache.size(), 1);
+        assert!(is_from_cache(&second));
+        assert_eq!(first.address, checksummed.to_ascii_lowercase());
+        let statuses = |r: &AnalyzeResponse| -> Vec<CheckStatus> {
+            r.checks.iter().map(|c| c.status.clone()).collect()
+        };
+        assert_eq!(statuses(&first), statuses(&second));
+        let ownership = first.checks.iter().find(|c| c.id == "ownership_renounced").unwrap();
+        assert_eq!(ownership.status, CheckStatus::Fail);
+    }
+
+    /// Delays every metadata fetch, standing in for provider latency
+    struct SlowProvider {
+        inner: MockProvider,
+        delay: std::time::Duration,
+        /// Metadata fetches wait here until every party has arrived
+        gate: Option<std::sync::Arc<tokio::sync::Barrier>>,
+    }
+
+    #[async_trait::async_trait]
+    impl TokenProvider for SlowProvider {
+        fn provider_name(&self) -> &str {
+            "slow"
+        }
+
+        async fn fetch_metadata(&self, address: &str) -> Result<Metadata, crate::providers::ProviderError> {
+            if let Some(gate) = &self.gate {
+                gate.wait().await;
+            }
+            tokio::time::sleep(self.delay).await;
+            self.inner.fetch_metadata(address).await
+        }
+
+        async fn fetch_supply(&self, address: &str) -> Result<SupplyInfo, crate::providers::ProviderError> {
+            self.inner.fetch_supply(address).await
+        }
+
+        async fn fetch_authorities(&self, address: &str) -> Result<AuthorityInfo, crate::providers::ProviderError> {
+            self.inner.fetch_authorities(address).await
+        }
+
+        async fn fetch_holders(
+            &self,
+            address: &str,
+            limit: usize,
+            strategy: HolderStrategy,
+        ) -> Result<HolderInfo, crate::providers::ProviderError> {
+            self.inner.fetch_holders(address, limit, strategy).await
+        }
+
+        async fn fetch_creation_time(&self, address: &str) -> Result<CreationInfo, crate::providers::ProviderError> {
+            self.inner.fetch_creation_time(address).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shared_cache_not_held_across_provider_calls() {
+        // Each analysis blocks in the provider until the other one is in
+        // there too, which only happens if the cache lock is not held
+        let provider = SlowProvider {
+            inner: MockProvider::new("test")
+                .with_facts("token_a", TokenFacts::default())
+                .with_facts("token_b", TokenFacts::default()),
+            delay: std::time::Duration::ZERO,
+            gate: Some(std::sync::Arc::new(tokio::sync::Barrier::new(2))),
+        };
+        let cache = Mutex::new(SimpleCache::new());
+        let config = AnalyzeConfig::default();
+
+        let request = |address: &str| AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: address.to_string(),
+            options: AnalyzeOptions::default(),
+        };
+
+        let (a, b) = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            async {
+                tokio::join!(
+                    analyze_with_shared_cache(request("token_a"), &provider, &cache, &config),
+                    analyze_with_shared_cache(request("token_b"), &provider, &cache, &config),
+                )
+            },
+        )
+        .await
+        .expect("analyses serialized on the cache lock");
+
+        assert_eq!((a.address.as_str(), b.address.as_str()), ("token_a", "token_b"));
+        assert_eq!(cache.lock().await.size(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_burst_of_misses_shares_one_analysis() {
+        use crate::api::coalesce::{RequestCoalescer, DEFAULT_COALESCE_WINDOW};
+        use std::sync::Arc;
+
+        let provider = SlowProvider {
+            inner: MockProvider::new("test").with_facts("token", TokenFacts::default()),
+            delay: std::time::Duration::from_millis(50),
+            gate: None,
+        };
+        let cache = Mutex::new(SimpleCache::new());
+        let config = AnalyzeConfig {
+            coalescer: Some(Arc::new(RequestCoalescer::new(DEFAULT_COALESCE_WINDOW))),
+            ..Default::default()
+        };
+        let request = || AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { trace_rpc: true, ..Default::default() },
+        };
+
+        let responses = futures_util::future::join_all(
+            (0..8).map(|_| analyze_with_shared_cache(request(), &provider, &cache, &config)),
+        ).await;
+
+        // Only the analysis that ran keeps its trace
+        let metadata_fetches = responses.iter()
+            .flat_map(|r| &r.rpc_trace)
+            .filter(|e| e.method == "fetch_metadata")
+            .count();
+        assert_eq!(metadata_fetches, 1);
+        assert!(responses.iter().all(|r| r.analysis_id == responses[0].analysis_id));
+        assert_eq!(responses.iter().filter(|r| !is_from_cache(r)).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cache_only_miss_and_hit() {
+        use crate::providers::{trace, TraceCollector};
+
+        let provider = MockProvider::new("test").with_facts("token", TokenFacts {
+            metadata: Some(Metadata {
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let mut cache = SimpleCache::new();
+        let request = |cache_only: bool| AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { cache_only, ..Default::default() },
+        };
+
+        let collector = TraceCollector::new(false);
+        let miss = trace::with_trace(
+            collector.clone(),
+            analyze_with_cache(request(true), &provider, &mut cache),
+        ).await;
+
+        assert_eq!(miss.status, AnalysisStatus::NotCached);
+        assert_eq!(miss.errors, vec![NOT_CACHED_MESSAGE.to_string()]);
+        assert!(miss.checks.is_empty());
+        assert!(collector.entries().is_empty(), "provider was called on a cache_only miss");
+        assert_eq!(cache.size(), 0);
+
+        // Warm the entry with a normal analysis; cache_only then serves it
+        let fresh = analyze_with_cache(request(false), &provider, &mut cache).await;
+        let hit = analyze_with_cache(request(true), &provider, &mut cache).await;
+
+        assert!(is_from_cache(&hit));
+        assert_eq!(hit.analysis_id, fresh.analysis_id);
+        assert_ne!(hit.status, AnalysisStatus::NotCached);
+    }
 }
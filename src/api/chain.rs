@@ -0,0 +1,105 @@
+// src/api/chain.rs
+
+/// Map a client-supplied chain name or alias to its canonical form.
+/// Matching is case-insensitive and ignores surrounding whitespace.
+/// Returns `None` for chains we don't recognize.
+pub fn normalize_chain(input: &str) -> Option<String> {
+    let canonical = match input.trim().to_ascii_lowercase().as_str() {
+        "solana" | "sol" | "solana-mainnet" | "solana_mainnet" | "mainnet-beta" => "solana",
+        "ethereum" | "eth" | "mainnet" | "eth-mainnet" | "ethereum-mainnet" => "ethereum",
+        "base" | "base-mainnet" => "base",
+        "evm" => "evm",
+        _ => return None,
+    };
+
+    Some(canonical.to_string())
+}
+
+/// Canonical form of `address` on `chain`: EVM addresses are lowercased so
+/// checksummed (EIP-55) and lowercase spellings are the same token. Solana's
+/// base58 is case-sensitive and only trimmed.
+pub fn normalize_address(chain: &str, address: &str) -> String {
+    let address = address.trim();
+    match normalize_chain(chain).as_deref() {
+        Some("ethereum" | "base" | "evm") => address.to_ascii_lowercase(),
+        _ => address.to_string(),
+    }
+}
+
+/// Reject an address that cannot exist on `chain`: EVM addresses are `0x`
+/// and 40 hex digits, Solana addresses 32 to 44 base58 characters. Only
+/// the format is checked, not that the account exists.
+pub fn validate_address(chain: &str, address: &str) -> Result<(), String> {
+    let address = address.trim();
+    let valid = match normalize_chain(chain).as_deref() {
+        Some("ethereum" | "base" | "evm") => address.strip_prefix("0x")
+            .or_else(|| address.strip_prefix("0X"))
+            .is_some_and(|hex| hex.len() == 40 && hex.bytes().all(|b| b.is_ascii_hexdigit())),
+        Some("solana") => (32..=44).contains(&address.len())
+            && address.bytes().all(|b| b.is_ascii_alphanumeric() && !matches!(b, b'0' | b'O' | b'I' | b'l')),
+        _ => return Err(format!("Unsupported chain '{}'", chain)),
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("'{}' is not a valid {} address", address, chain))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alias_table() {
+        let cases = [
+            ("solana", "solana"),
+            ("Solana", "solana"),
+            ("SOL", "solana"),
+            ("sol", "solana"),
+            ("mainnet-beta", "solana"),
+            ("eth", "ethereum"),
+            ("ETH", "ethereum"),
+            ("Ethereum", "ethereum"),
+            ("mainnet", "ethereum"),
+            ("eth-mainnet", "ethereum"),
+            ("base", "base"),
+            ("Base", "base"),
+            ("base-mainnet", "base"),
+            ("evm", "evm"),
+            ("  sol  ", "solana"),
+        ];
+
+        for (alias, expected) in cases {
+            assert_eq!(normalize_chain(alias).as_deref(), Some(expected), "alias {}", alias);
+        }
+    }
+
+    #[test]
+    fn test_evm_addresses_lowercased_solana_untouched() {
+        let checksummed = "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913";
+        assert_eq!(normalize_address("base", checksummed), checksummed.to_ascii_lowercase());
+        assert_eq!(normalize_address("ETH", checksummed), checksummed.to_ascii_lowercase());
+
+        let mint = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+        assert_eq!(normalize_address("solana", &format!(" {} ", mint)), mint);
+    }
+
+    #[test]
+    fn test_address_format_validated_per_chain() {
+        assert!(validate_address("solana", "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").is_ok());
+        assert!(validate_address("base", "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913").is_ok());
+
+        // 0 is not in the base58 alphabet; an EVM address on Solana is wrong too
+        assert!(validate_address("solana", "0PjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").is_err());
+        assert!(validate_address("solana", "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913").is_err());
+        assert!(validate_address("ethereum", "0x833589fcd6edb6e08f4c7c32d4f71b54bda0291").is_err());
+        assert!(validate_address("ethereum", "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").is_err());
+    }
+
+    #[test]
+    fn test_unknown_alias() {
+        assert_eq!(normalize_chain("dogechain"), None);
+        assert_eq!(normalize_chain(""), None);
+    }
+}
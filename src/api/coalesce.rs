@@ -0,0 +1,83 @@
+// src/api/coalesce.rs
+//
+// Share one analysis between identical requests that arrive together, so a
+// burst of cache misses for the same key makes one set of provider calls.
+
+use super::types::AnalyzeResponse;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+
+/// Window after an analysis starts during which identical requests join it
+pub const DEFAULT_COALESCE_WINDOW: Duration = Duration::from_millis(250);
+
+struct Flight {
+    started: Instant,
+    result: watch::Receiver<Option<AnalyzeResponse>>,
+}
+
+impl Flight {
+    /// Still running, or finished less than `window` after it started
+    fn joinable(&self, window: Duration) -> bool {
+        let running = self.result.borrow().is_none() && self.result.has_changed().is_ok();
+        running || self.started.elapsed() < window
+    }
+}
+
+/// Per-key single-flight over a short rolling window
+pub struct RequestCoalescer {
+    window: Duration,
+    flights: Mutex<HashMap<String, Flight>>,
+}
+
+impl std::fmt::Debug for RequestCoalescer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestCoalescer").field("window", &self.window).finish()
+    }
+}
+
+impl RequestCoalescer {
+    pub fn new(window: Duration) -> Self {
+        Self { window, flights: Mutex::new(HashMap::new()) }
+    }
+
+    /// Run `analyze` for `key`, or wait for the in-flight analysis of the
+    /// same key and share its response. The flag is true for a shared
+    /// response. Should the leading analysis be dropped before finishing,
+    /// waiters run their own.
+    pub async fn run<F, Fut>(&self, key: &str, analyze: F) -> (AnalyzeResponse, bool)
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = AnalyzeResponse>,
+    {
+        let sender = {
+            let mut flights = self.flights.lock().unwrap();
+            flights.retain(|_, flight| flight.joinable(self.window));
+            match flights.get(key) {
+                Some(flight) => Err(flight.result.clone()),
+                None => {
+                    let (sender, result) = watch::channel(None);
+                    flights.insert(key.to_string(), Flight { started: Instant::now(), result });
+                    Ok(sender)
+                }
+            }
+        };
+
+        match sender {
+            Ok(sender) => {
+                let response = analyze().await;
+                sender.send_replace(Some(response.clone()));
+                (response, false)
+            }
+            Err(mut result) => {
+                let shared = result.wait_for(Option::is_some).await.ok().and_then(|r| r.clone());
+                match shared {
+                    Some(response) => (response, true),
+                    None => (analyze().await, false),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+// src/api/config.rs
+
+use crate::burn::BurnAddresses;
+use crate::labels::AddressLabels;
+use crate::checks::{AgeBandPolicy, CheckRegistry, SupplyBounds};
+use crate::providers::ProviderExecutor;
+use super::coalesce::RequestCoalescer;
+use crate::scoring::ScoringProfile;
+use super::templates::ExplanationTemplates;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Operator/library-side analysis configuration. Unlike `AnalyzeOptions`,
+/// this is never supplied by API clients.
+#[derive(Clone, Debug, Default)]
+pub struct AnalyzeConfig {
+    pub scoring: ScoringProfile,
+    /// Shared pool bounding concurrent provider fetches; unbounded when None
+    pub executor: Option<Arc<ProviderExecutor>>,
+    /// Shares one analysis between identical requests arriving together
+    /// (`analyze_with_shared_cache` only); no coalescing when None
+    pub coalescer: Option<Arc<RequestCoalescer>>,
+    /// Keccak-256 hashes of known-malicious EVM bytecode. When empty, the
+    /// bytecode reputation check (and its `eth_getCode` call) is skipped.
+    pub known_malicious_bytecode: HashSet<String>,
+    /// Age banding for the token-age check; `DefaultAgeBandPolicy` when None
+    pub age_band_policy: Option<Arc<dyn AgeBandPolicy>>,
+    /// Burn addresses per chain; the well-known ones by default
+    pub burn_addresses: BurnAddresses,
+    /// Fallback holder labels where the provider has none; a few
+    /// well-known exchange wallets by default
+    pub address_labels: AddressLabels,
+    /// Sane range of human-readable total supply for the magnitude check
+    pub supply_bounds: SupplyBounds,
+    /// Wording of the `explain` section; built-in English by default
+    pub explanation_templates: ExplanationTemplates,
+    /// Library users' own checks, run after the built-in ones; none by
+    /// default. A failed custom check gets a `what_to_do` line when
+    /// `explanation_templates.findings` has one under its id.
+    pub check_registry: CheckRegistry,
+    /// Run the minimal chain-agnostic checks for chains `normalize_chain`
+    /// does not recognize. Off by default: such analyses fail closed with
+    /// `status: error` and no score.
+    pub allow_unknown_chains: bool,
+}
@@ -0,0 +1,36 @@
+// src/api/facts_hash.rs
+//
+// Content hash of the facts behind an analysis, so clients can tell whether
+// the underlying data changed between two analyses of the same token, or
+// deduplicate by data rather than by address.
+
+use crate::types::TokenFacts;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use super::attestation::canonical_json;
+
+/// Bookkeeping about the fetch rather than the token
+const FETCH_FIELDS: [&str; 4] = ["sources", "error_details", "fetch_errors", "unsupported"];
+
+/// Hex SHA-256 over the canonical JSON of `facts`. Fetch bookkeeping and
+/// values relative to the current time (ages, age band) are left out, so
+/// unchanged on-chain data hashes the same whenever it is fetched.
+pub fn facts_hash(facts: &TokenFacts) -> String {
+    let mut value = serde_json::to_value(facts).unwrap_or(Value::Null);
+    if let Some(fields) = value.as_object_mut() {
+        for field in FETCH_FIELDS {
+            fields.remove(field);
+        }
+    }
+    if let Some(creation) = value["creation"].as_object_mut() {
+        creation.remove("age_seconds");
+        creation.remove("age_band");
+    }
+    if let Some(history) = value["authority_history"].as_array_mut() {
+        for change in history.iter_mut().filter_map(Value::as_object_mut) {
+            change.remove("age_seconds");
+        }
+    }
+
+    hex::encode(Sha256::digest(canonical_json(&value).as_bytes()))
+}
@@ -0,0 +1,142 @@
+// src/api/fields.rs
+//
+// Sparse fieldsets: a client lists the top-level response fields it renders
+// and receives only those (plus `schema_version`).
+
+use super::types::AnalyzeResponse;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde_json::{Map, Value};
+
+/// Always kept so clients can tell which format they received
+const ALWAYS_INCLUDED: &str = "schema_version";
+
+/// Split a `fields=a,b` list, dropping blanks
+pub fn parse_fields(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(|f| f.trim())
+        .filter(|f| !f.is_empty())
+        .map(|f| f.to_string())
+        .collect()
+}
+
+/// Serialize `response` keeping only `fields`. Names that are not top-level
+/// response fields are ignored and listed under `ignored_fields`.
+pub fn sparse_view(response: &AnalyzeResponse, fields: &[String]) -> Value {
+    let full = match serde_json::to_value(response) {
+        Ok(Value::Object(map)) => map,
+        _ => return Value::Null,
+    };
+
+    let mut view = Map::new();
+    if let Some(version) = full.get(ALWAYS_INCLUDED) {
+        view.insert(ALWAYS_INCLUDED.to_string(), version.clone());
+    }
+
+    let mut ignored = Vec::new();
+    for field in fields {
+        match full.get(field) {
+            Some(value) => {
+                view.insert(field.clone(), value.clone());
+            }
+            // Known but omitted from this response (e.g. empty `rpc_trace`)
+            None if is_response_field(field) => {}
+            None => ignored.push(Value::String(field.clone())),
+        }
+    }
+
+    if !ignored.is_empty() {
+        view.insert("ignored_fields".to_string(), Value::Array(ignored));
+    }
+
+    Value::Object(view)
+}
+
+fn is_response_field(name: &str) -> bool {
+    response_field_names().contains(&name)
+}
+
+/// Every top-level field of `AnalyzeResponse`, read from its `Deserialize`
+/// impl so fields skipped when empty still count as known
+fn response_field_names() -> &'static [&'static str] {
+    let mut fields: &'static [&'static str] = &[];
+    let _ = AnalyzeResponse::deserialize(FieldNames(&mut fields));
+    fields
+}
+
+/// Deserializer that records the field list serde passes to
+/// `deserialize_struct`, then stops
+struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+impl<'de> Deserializer<'de> for FieldNames<'_> {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom("only struct field names are read"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        *self.0 = fields;
+        Err(de::Error::custom("only struct field names are read"))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::simple_cache::tests::make_test_response;
+
+    #[test]
+    fn test_sparse_view_keeps_only_requested_fields() {
+        let mut response = make_test_response();
+        response.checks.push(crate::checks::check_token_age(&Default::default()));
+        assert!(serde_json::to_string(&response).unwrap().contains("evidence"));
+
+        let view = sparse_view(&response, &parse_fields("score,grade"));
+
+        assert!(view.get("score").is_some());
+        assert!(view.get("checks").is_none());
+        assert!(view.get("explain").is_none());
+        assert!(!view.to_string().contains("evidence"));
+        assert_eq!(view["schema_version"], response.schema_version);
+
+        // `grade` lives inside `score`; it is not a top-level field
+        assert_eq!(view["ignored_fields"], serde_json::json!(["grade"]));
+    }
+
+    #[test]
+    fn test_omitted_fields_are_not_ignored() {
+        let response = make_test_response();
+        assert!(response.baseline_deltas.is_none() && response.at_slot.is_none());
+
+        let view = sparse_view(&response, &parse_fields("baseline_deltas,explain,scoring_config,at_slot,at_block,bogus"));
+
+        assert_eq!(view["ignored_fields"], serde_json::json!(["bogus"]));
+    }
+
+    #[test]
+    fn test_response_field_names_cover_serialized_fields() {
+        let names = response_field_names();
+        let full = serde_json::to_value(make_test_response()).unwrap();
+
+        for key in full.as_object().unwrap().keys() {
+            assert!(names.contains(&key.as_str()), "{}", key);
+        }
+        assert!(names.contains(&"at_block"));
+    }
+
+    #[test]
+    fn test_parse_fields_trims_and_drops_blanks() {
+        assert_eq!(parse_fields(" score, token,,"), vec!["score", "token"]);
+    }
+}
@@ -0,0 +1,249 @@
+// src/api/incremental.rs
+//
+// Re-run only the checks a prior analysis could not decide, fetching just
+// the facts they depend on.
+
+use crate::types::*;
+use crate::providers::{trace, TokenProvider, TraceCollector};
+use crate::scoring::{aggregate_score_with_profile, apply_partial_penalty, scoring_config};
+use super::analyze::{
+    build_token_metadata, current_timestamp, gather_facts, generate_analysis_id,
+    generate_explanation, headline_finding, prepare_facts, run_checks, sort_checks,
+};
+use super::baseline::compare_to_baseline;
+use super::config::AnalyzeConfig;
+use super::types::*;
+use std::collections::HashSet;
+
+/// Facts each check reads. Checks not listed here are never re-run.
+fn check_dependencies(check_id: &str) -> &'static [&'static str] {
+    match check_id {
+        "mint_authority_disabled" | "freeze_authority_disabled" | "ownership_renounced"
+            | "authority_separation" | "authority_is_program" => &["authorities"],
+        "holder_concentration" | "residual_supply" => &["holders"],
+        "token_age" => &["creation", "authority_history"],
+        "standard_sanity" | "mint_initialized" | "default_state_unfrozen" => &["metadata"],
+        "authority_stability" => &["authority_history"],
+        "tax_destination" => &["tax", "authorities"],
+        "bytecode_reputation" => &["bytecode"],
+        "circulating_ratio" | "supply_magnitude" => &["supply"],
+        "unit_spoofing" => &["metadata", "supply"],
+        "no_recent_inflation" => &["supply", "historical_supply"],
+        "owner_is_top_holder" => &["authorities", "holders"],
+        "supply_fixed" => &["authorities", "mint_simulation"],
+        "distribution_organic" => &["holders", "metadata"],
+        "rent_exempt" => &["rent"],
+        "holder_supply_consistency" => &["holders", "supply"],
+        "has_identity" => &["metadata", "identity"],
+        _ => &[],
+    }
+}
+
+/// Re-run the `Unknown` checks of `prior`, re-fetching only their facts,
+/// and merge the results over the prior's decided checks. The score,
+/// verdict and explanation are recomputed from the merged checks under
+/// `config`, which should be the one `prior` was produced with.
+pub async fn analyze_incremental<P: TokenProvider + ?Sized>(
+    prior: &AnalyzeResponse,
+    provider: &P,
+    options: &AnalyzeOptions,
+    config: &AnalyzeConfig,
+) -> AnalyzeResponse {
+
+    let rerun: HashSet<&str> = prior.checks.iter()
+        .filter(|c| c.status == CheckStatus::Unknown)
+        .map(|c| c.id.as_str())
+        .collect();
+    let needed: HashSet<&str> = rerun.iter()
+        .flat_map(|id| check_dependencies(id).iter().copied())
+        .collect();
+
+    let mut errors = Vec::new();
+    let collector = TraceCollector::new(options.include_raw_evidence);
+    let mut facts = if needed.is_empty() {
+        TokenFacts::default()
+    } else {
+        trace::with_trace(
+            collector.clone(),
+            gather_facts(provider, &prior.address, prior.at_slot.or(prior.at_block), options, config, Some(&needed), &mut errors),
+        ).await
+    };
+    prepare_facts(&mut facts, &prior.chain, options.as_of, config);
+
+    let fresh: Vec<CheckResult> = run_checks(&facts, &prior.chain, config)
+        .into_iter()
+        .filter(|c| rerun.contains(c.id.as_str()))
+        .collect();
+
+    let mut checks: Vec<CheckResult> = prior.checks.iter()
+        .map(|prior_check| {
+            fresh.iter()
+                .find(|c| c.id == prior_check.id)
+                .cloned()
+                .unwrap_or_else(|| prior_check.clone())
+        })
+        .collect();
+    if options.stable_check_order {
+        sort_checks(&mut checks);
+    }
+
+    // Re-fetched facts supersede the prior's lineage for the same fact
+    let mut sources: Vec<FactSource> = prior.sources.iter()
+        .filter(|s| !facts.sources.iter().any(|fresh| fresh.fact == s.fact))
+        .cloned()
+        .collect();
+    sources.extend(facts.sources.iter().cloned());
+
+    let number_format = options.number_format();
+    let status = if errors.is_empty() {
+        AnalysisStatus::Ok
+    } else {
+        AnalysisStatus::Partial
+    };
+
+    let mut score = aggregate_score_with_profile(&checks, &config.scoring);
+    if status == AnalysisStatus::Partial {
+        apply_partial_penalty(&mut score, config.scoring.partial_penalty);
+    }
+    let verdict = Verdict::from_score(&score, &checks);
+    let summary = CheckSummary::from_checks(&checks);
+    let explain = generate_explanation(
+        &checks,
+        &score,
+        options.explain_level,
+        &config.explanation_templates,
+        &config.check_registry,
+        &number_format,
+    );
+
+    let headline = headline_finding(&checks, &config.explanation_templates, &number_format);
+
+    let mut response = AnalyzeResponse {
+        schema_version: prior.schema_version.clone(),
+        analysis_id: generate_analysis_id(),
+        requested_at: current_timestamp(),
+        chain: prior.chain.clone(),
+        address: prior.address.clone(),
+        status,
+        token: prior.token.clone().or_else(|| build_token_metadata(&facts, &number_format)),
+        checks,
+        score,
+        verdict,
+        summary,
+        explain,
+        errors,
+        error_details: facts.error_details.clone(),
+        sources,
+        baseline_deltas: None,
+        scoring_config: None,
+        headline,
+        rpc_trace: if options.trace_rpc { collector.entries() } else { Vec::new() },
+        attestation: None,
+        changed_since_cache: None,
+        age_of_analysis_seconds: None,
+        // Only the re-fetched facts are at hand; the prior's hash holds
+        // while none were
+        facts_hash: options.include_facts_hash
+            .then(|| prior.facts_hash.clone().filter(|_| needed.is_empty()))
+            .flatten(),
+        rpc_calls: collector.call_count(),
+        at_slot: prior.at_slot,
+        at_block: prior.at_block,
+    };
+
+    if options.include_baseline {
+        response.baseline_deltas = Some(compare_to_baseline(&response));
+    }
+    if options.include_scoring_config {
+        response.scoring_config = Some(scoring_config(&response.checks, &config.scoring));
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::analyze::analyze;
+    use crate::providers::mocks::MockProvider;
+
+    fn base_facts() -> TokenFacts {
+        TokenFacts {
+            metadata: Some(Metadata {
+                name: Some("Token".to_string()),
+                standard: TokenStandard::SplToken,
+                is_initialized: Some(true),
+                ..Default::default()
+            }),
+            authorities: Some(AuthorityInfo {
+                mint_authority: None,
+                freeze_authority: None,
+                mint_mutable: Some(false),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_incremental_facts_hash_only_without_refetch() {
+        let provider = MockProvider::new("test").with_facts("token", base_facts());
+        let options = AnalyzeOptions { include_facts_hash: true, ..AnalyzeOptions::default() };
+        let mut prior = analyze(AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: options.clone(),
+        }, &provider).await;
+        assert!(prior.facts_hash.is_some());
+
+        let refetched = analyze_incremental(&prior, &provider, &options, &AnalyzeConfig::default()).await;
+        assert_eq!(refetched.facts_hash, None);
+
+        prior.checks.retain(|c| c.status != CheckStatus::Unknown);
+        let untouched = analyze_incremental(&prior, &provider, &options, &AnalyzeConfig::default()).await;
+        assert_eq!(untouched.facts_hash, prior.facts_hash);
+    }
+
+    #[tokio::test]
+    async fn test_incremental_upgrades_unknown_holder_check() {
+        let before = MockProvider::new("test").with_facts("token", base_facts());
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+        let prior = analyze(request, &before).await;
+        let holder_check = |r: &AnalyzeResponse| {
+            r.checks.iter().find(|c| c.id == "holder_concentration").unwrap().clone()
+        };
+        assert_eq!(holder_check(&prior).status, CheckStatus::Unknown);
+
+        let after = MockProvider::new("test").with_facts("token", TokenFacts {
+            holders: Some(HolderInfo {
+                top1_pct: Some(5.0),
+                top5_pct: Some(15.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![],
+            }),
+            ..base_facts()
+        });
+        let options = AnalyzeOptions { trace_rpc: true, ..AnalyzeOptions::default() };
+
+        let merged = analyze_incremental(&prior, &after, &options, &AnalyzeConfig::default()).await;
+
+        assert_eq!(holder_check(&merged).status, CheckStatus::Pass);
+        assert_ne!(merged.score.weights_total, prior.score.weights_total);
+        assert_eq!(merged.checks.len(), prior.checks.len());
+
+        // Decided checks are carried over, not re-run. Authorities are still
+        // fetched for the undecided owner_is_top_holder check.
+        let methods: Vec<&str> = merged.rpc_trace.iter().map(|e| e.method.as_str()).collect();
+        assert!(methods.contains(&"fetch_holders"));
+        assert!(methods.contains(&"fetch_authorities"));
+        let mint_check = |r: &AnalyzeResponse| {
+            r.checks.iter().find(|c| c.id == "mint_authority_disabled").unwrap().clone()
+        };
+        assert_eq!(mint_check(&merged).status, CheckStatus::Pass);
+        assert_eq!(mint_check(&merged).evidence, mint_check(&prior).evidence);
+    }
+}
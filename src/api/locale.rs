@@ -0,0 +1,103 @@
+// src/api/locale.rs
+//
+// Locale-aware rendering of numbers embedded in human-facing strings
+// (explanations, token display fields). Machine-readable fields stay plain
+// JSON numbers.
+
+/// Digit grouping and decimal mark of a locale
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NumberFormat {
+    pub group_separator: char,
+    pub decimal_mark: char,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::EN_US
+    }
+}
+
+impl NumberFormat {
+    pub const EN_US: NumberFormat = NumberFormat { group_separator: ',', decimal_mark: '.' };
+
+    /// Format for a BCP 47 tag ("de-DE", "fr", ...). Unrecognized tags get
+    /// `en-US`.
+    pub fn for_locale(tag: &str) -> Self {
+        let tag = tag.trim().replace('_', "-").to_ascii_lowercase();
+        let language = tag.split('-').next().unwrap_or("");
+        match (language, tag.as_str()) {
+            (_, "de-ch") => NumberFormat { group_separator: '\'', decimal_mark: '.' },
+            ("de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da", _) => {
+                NumberFormat { group_separator: '.', decimal_mark: ',' }
+            }
+            ("fr" | "ru" | "pl" | "cs" | "sv" | "fi" | "nb" | "uk", _) => {
+                NumberFormat { group_separator: '\u{a0}', decimal_mark: ',' }
+            }
+            _ => Self::EN_US,
+        }
+    }
+
+    /// `value` with grouped thousands and at most `max_fraction_digits`
+    /// decimals (trailing zeros dropped)
+    pub fn number(&self, value: f64, max_fraction_digits: usize) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+
+        let rendered = format!("{:.*}", max_fraction_digits, value.abs());
+        let (integer, fraction) = match rendered.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction.trim_end_matches('0')),
+            None => (rendered.as_str(), ""),
+        };
+
+        let mut out = String::new();
+        if value < 0.0 && rendered.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            out.push('-');
+        }
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                out.push(self.group_separator);
+            }
+            out.push(digit);
+        }
+        if !fraction.is_empty() {
+            out.push(self.decimal_mark);
+            out.push_str(fraction);
+        }
+        out
+    }
+
+    /// Percentage with one decimal, e.g. "12.5%" / "12,5%"
+    pub fn percent(&self, pct: f64) -> String {
+        format!("{}%", self.number(pct, 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supply_en_us_vs_de_de() {
+        let supply = 1_234_567_890.5;
+
+        assert_eq!(NumberFormat::for_locale("en-US").number(supply, 2), "1,234,567,890.5");
+        assert_eq!(NumberFormat::for_locale("de-DE").number(supply, 2), "1.234.567.890,5");
+    }
+
+    #[test]
+    fn test_percent_and_small_values() {
+        let de = NumberFormat::for_locale("de_DE");
+
+        assert_eq!(de.percent(12.345), "12,3%");
+        assert_eq!(NumberFormat::EN_US.number(999.0, 2), "999");
+        assert_eq!(NumberFormat::EN_US.number(-1000.0, 0), "-1,000");
+        assert_eq!(NumberFormat::EN_US.number(-0.001, 2), "0");
+    }
+
+    #[test]
+    fn test_unknown_locale_falls_back_to_en_us() {
+        assert_eq!(NumberFormat::for_locale("xx-YY"), NumberFormat::EN_US);
+        assert_eq!(NumberFormat::for_locale("fr-FR").number(1500.0, 0), "1\u{a0}500");
+    }
+}
@@ -3,7 +3,28 @@
 pub mod types;
 pub mod analyze;
 pub mod cached_analyze;
+pub mod coalesce;
+pub mod chain;
+pub mod config;
+pub mod attestation;
+pub mod fields;
+pub mod incremental;
+pub mod wire;
+pub mod templates;
+pub mod locale;
+pub mod baseline;
+pub mod cache_delta;
+pub mod facts_hash;
 
 pub use types::{AnalyzeRequest, AnalyzeResponse, AnalyzeOptions};
-pub use analyze::analyze;
-pub use cached_analyze::analyze_with_cache;
+pub use analyze::{analyze, analyze_with_config};
+pub use config::AnalyzeConfig;
+pub use templates::ExplanationTemplates;
+pub use incremental::analyze_incremental;
+pub use baseline::{compare_to_baseline, BaselineDelta};
+pub use cache_delta::{diff_responses, CacheDelta};
+pub use cached_analyze::{
+    analyze_many, analyze_with_cache, analyze_with_cache_and_config, analyze_with_shared_cache,
+};
+pub use chain::{normalize_address, normalize_chain};
+pub use attestation::{attach_attestation, verify_attestation, verify_attestation_json, Attestation};
@@ -0,0 +1,118 @@
+// src/api/templates.rs
+//
+// Wording of the `explain` section. The default is the built-in English;
+// callers localize or reword by overriding entries of the default.
+
+use crate::types::{CheckResult, Grade};
+use std::collections::HashMap;
+
+/// Explanation strings keyed by grade and by finding. A missing key yields
+/// an empty summary/headline or drops the finding line.
+#[derive(Clone, Debug)]
+pub struct ExplanationTemplates {
+    /// `explain.summary`, keyed by grade name ("Strong", "Compromised", ...)
+    pub summaries: HashMap<String, String>,
+    /// `explain.headline`, keyed by grade name
+    pub headlines: HashMap<String, String>,
+    /// `what_to_do` lines keyed by check id, or `check_id.variant` where one
+    /// check has several findings (e.g. "ownership_renounced.pending_owner").
+    /// `{top1_pct}` / `{circulating_pct}` are filled with locale-formatted
+    /// percentages.
+    pub findings: HashMap<String, String>,
+    pub method: Vec<String>,
+    /// `what_to_do` when every check passed
+    pub all_passed: String,
+    /// `what_to_do` when checks failed without a finding of their own
+    pub unlisted_failures: String,
+    /// Per-check detail lines; `{label}` and `{severity}` are substituted
+    pub detail_pass: String,
+    pub detail_fail: String,
+    pub detail_unknown: String,
+}
+
+impl ExplanationTemplates {
+    pub fn summary(&self, grade: &Grade) -> String {
+        self.summaries.get(&grade_key(grade)).cloned().unwrap_or_default()
+    }
+
+    pub fn headline(&self, grade: &Grade) -> String {
+        self.headlines.get(&grade_key(grade)).cloned().unwrap_or_default()
+    }
+
+    /// Finding line with `{name}` placeholders filled from `values`
+    pub fn finding(&self, key: &str, values: &[(&str, String)]) -> Option<String> {
+        let mut line = self.findings.get(key)?.clone();
+        for (name, value) in values {
+            line = line.replace(&format!("{{{}}}", name), value);
+        }
+        Some(line)
+    }
+
+    /// Fill a detail template for `check`
+    pub fn detail(&self, template: &str, check: &CheckResult) -> String {
+        template
+            .replace("{label}", &check.label)
+            .replace("{severity}", &format!("{:?}", check.severity).to_lowercase())
+    }
+}
+
+fn grade_key(grade: &Grade) -> String {
+    format!("{:?}", grade)
+}
+
+fn entries(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+impl Default for ExplanationTemplates {
+    fn default() -> Self {
+        Self {
+            summaries: entries(&[
+                ("Strong", "Structure looks sound. No major weaknesses detected."),
+                ("Mixed", "Structure is mostly sound with some areas of concern."),
+                ("Fragile", "Structure shows significant fragility. Proceed with caution."),
+                ("Compromised", "Structure is fundamentally compromised. High risk."),
+                ("Insufficient", "Not enough verifiable data to grade this structure."),
+            ]),
+            headlines: entries(&[
+                ("Strong", "Low structural risk: no major weaknesses detected."),
+                ("Mixed", "Moderate structural risk: some areas of concern."),
+                ("Fragile", "Elevated structural risk: the launch structure is fragile."),
+                ("Compromised", "High risk: the launch structure is compromised."),
+                ("Insufficient", "Risk unknown: too little verifiable data to grade."),
+            ]),
+            findings: entries(&[
+                ("mint_authority_disabled", "Mint authority exists: supply is mutable and can be inflated."),
+                ("ownership_renounced", "Ownership not renounced: contract parameters can still be changed."),
+                ("bytecode_reputation", "Contract bytecode matches a known-malicious template."),
+                ("freeze_authority_disabled", "Freeze authority exists: token balances can be frozen."),
+                ("mint_initialized", "Mint account is not initialized: this is not a usable token."),
+                ("ownership_renounced.pending_owner", "Ownership renounced but a pending owner can still reclaim it."),
+                ("authority_separation", "Mint and freeze authority are held by the same key."),
+                ("authority_stability", "An authority was recently transferred: control of the token changed hands."),
+                ("circulating_ratio", "Most of the supply is not circulating ({circulating_pct} circulates): future unlocks may dilute holders."),
+                ("tax_destination", "Transfer tax is routed to a single wallet: every trade pays one key holder."),
+                ("unit_spoofing", "Decimals and raw supply combine into a misleading displayed supply."),
+                ("default_state_unfrozen", "New token accounts start frozen: holders cannot transfer until the freeze authority thaws them."),
+                ("owner_is_top_holder", "The largest holder is the owner or mint authority: the key that controls the token also holds the biggest position."),
+                ("no_recent_inflation", "Supply grew since the baseline: new tokens were minted recently."),
+                ("supply_fixed", "The contract can still mint: supply is not fixed."),
+                ("distribution_organic", "Top-holder balances look scripted (identical, round or sequential amounts): the launch distribution may not be organic."),
+                ("rent_exempt", "The mint account holds less than the rent-exempt minimum: it can be closed and its lamports reclaimed."),
+                ("authority_is_program", "The mint authority or owner is a bare private key, not a program or contract: one compromised or careless key controls the token."),
+                ("has_identity", "The token has neither a name nor a symbol: legitimate projects almost always publish both."),
+                ("holder_supply_consistency", "Listed holder balances do not add up to the total supply: holder data looks truncated or stale, so concentration figures are less reliable."),
+                ("holder_concentration", "High holder concentration increases structural fragility (top holder owns {top1_pct})."),
+            ]),
+            method: vec![
+                "This tool evaluates structural fairness, not price prediction.".to_string(),
+                "Each check is verifiable on-chain and scored transparently.".to_string(),
+            ],
+            all_passed: "All structural checks passed. Token appears fairly launched.".to_string(),
+            unlisted_failures: "Some structural checks failed. Review details above.".to_string(),
+            detail_pass: "{label}: passed.".to_string(),
+            detail_fail: "{label}: failed ({severity} severity).".to_string(),
+            detail_unknown: "{label}: could not be verified.".to_string(),
+        }
+    }
+}
@@ -1,9 +1,17 @@
 use serde::{Deserialize, Serialize};
 use crate::types::*;
-use crate::scoring::ScoreResult;
+use crate::scoring::{ProfileWarning, ScoreResult, ScoringConfig, ScoringProfile};
+use crate::providers::{RpcCallCount, RpcTraceEntry};
+use super::attestation::Attestation;
+use super::locale::NumberFormat;
+use super::baseline::BaselineDelta;
+use super::cache_delta::CacheDelta;
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct AnalyzeRequest {
+    /// Empty when omitted from the body; the server then uses its
+    /// configured default chain
+    #[serde(default)]
     pub chain: String,
     pub address: String,
     #[serde(default)]
@@ -18,10 +26,116 @@ pub struct AnalyzeOptions {
     pub max_holders: usize,
     #[serde(default)]
     pub force_refresh: bool,
+    /// Record every provider RPC call into `rpc_trace`
+    #[serde(default)]
+    pub trace_rpc: bool,
+    /// Include raw provider payloads (e.g. RPC response bodies in the trace)
+    #[serde(default)]
+    pub include_raw_evidence: bool,
+    /// Attach an HMAC `attestation` (requires a server-side secret)
+    #[serde(default)]
+    pub attest: bool,
+    /// HEAD-request the metadata image URI and report `token.image`
+    #[serde(default)]
+    pub check_image: bool,
+    /// Reference time (unix seconds) for age checks, for backtesting.
+    /// Defaults to now.
+    #[serde(default)]
+    pub as_of: Option<u64>,
+    /// Top-level response fields to return (sparse fieldset); all when None
+    #[serde(default)]
+    pub fields: Option<Vec<String>>,
+    /// How much of the `explain` section to produce
+    #[serde(default)]
+    pub explain_level: ExplainLevel,
+    /// Locale (e.g. "de-DE") for numbers rendered into explanation and
+    /// display strings; en-US when None
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// Slot (Solana) or block (EVM) to compare current supply against;
+    /// enables the recent-inflation check
+    #[serde(default)]
+    pub supply_baseline: Option<u64>,
+    /// Serve only from cache: a miss returns `status: not_cached` instead
+    /// of running a (paid) analysis
+    #[serde(default)]
+    pub cache_only: bool,
+    /// Add `baseline_deltas`: where the token falls short of a textbook
+    /// fair launch
+    #[serde(default)]
+    pub include_baseline: bool,
+    /// Add `scoring_config`: the weights, grade cutoffs and override rules
+    /// that produced the grade
+    #[serde(default)]
+    pub include_scoring_config: bool,
+    /// Provider fetches this analysis runs at once (per-key RPC limits)
+    #[serde(default = "default_max_concurrent_fetches")]
+    pub max_concurrent_fetches: usize,
+    /// Simulate a `mint` call to confirm mintability (EVM, best effort);
+    /// enables the fixed-supply check
+    #[serde(default)]
+    pub simulate_mint: bool,
+    /// Return `checks` sorted by category, then id, instead of the
+    /// chain-specific order they run in
+    #[serde(default)]
+    pub stable_check_order: bool,
+    /// Run this analysis against the caller's own JSON-RPC endpoint. The
+    /// server must enable it and the caller authenticate (see
+    /// `RpcOverridePolicy`).
+    #[serde(default)]
+    pub rpc_url_override: Option<String>,
+    /// Holder data to fetch: percentages only (`aggregate`, the default,
+    /// cheaper) or the `top_holders` list too (`full`, needed by list-based
+    /// checks and address labels)
+    #[serde(default)]
+    pub holder_strategy: HolderStrategy,
+    /// Add `facts_hash`: a content hash of the facts, equal across
+    /// analyses of unchanged on-chain data
+    #[serde(default)]
+    pub include_facts_hash: bool,
+    /// Ping the provider before fetching facts; if it rejects our
+    /// credentials or cannot be reached, return `status: error` at once
+    /// instead of making every fetch fail
+    #[serde(default)]
+    pub preflight_ping: bool,
+    /// Analyze a Solana token as of this slot: metadata, supply and
+    /// authorities are read at that point (needs archival state). Other
+    /// facts, such as holders, cannot be, and their checks are `Unknown`.
+    #[serde(default)]
+    pub at_slot: Option<u64>,
+    /// `at_slot` for EVM chains: analyze as of this block
+    #[serde(default)]
+    pub at_block: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExplainLevel {
+    /// No `explain` section
+    None,
+    /// Only `explain.summary`
+    Summary,
+    /// Headline, summary, per-check details, method and interpretation
+    #[default]
+    Full,
+}
+
+impl AnalyzeOptions {
+    /// Number format for `locale`, en-US when unset
+    pub fn number_format(&self) -> NumberFormat {
+        self.locale.as_deref().map(NumberFormat::for_locale).unwrap_or_default()
+    }
+
+    /// Slot or block to read facts at on canonical `chain`: `at_slot` on
+    /// Solana, `at_block` elsewhere. None for a current analysis.
+    pub fn point_in_time(&self, chain: &str) -> Option<u64> {
+        if chain == "solana" { self.at_slot } else { self.at_block }
+    }
 }
 
 fn default_true() -> bool { true }
 fn default_max_holders() -> usize { 10 }
+fn default_max_concurrent_fetches() -> usize { 5 }
 
 impl Default for AnalyzeOptions {
     fn default() -> Self {
@@ -29,11 +143,51 @@ impl Default for AnalyzeOptions {
             include_holders: true,
             max_holders: 10,
             force_refresh: false,
+            trace_rpc: false,
+            include_raw_evidence: false,
+            attest: false,
+            check_image: false,
+            as_of: None,
+            fields: None,
+            explain_level: ExplainLevel::Full,
+            locale: None,
+            supply_baseline: None,
+            cache_only: false,
+            include_baseline: false,
+            include_scoring_config: false,
+            max_concurrent_fetches: default_max_concurrent_fetches(),
+            simulate_mint: false,
+            stable_check_order: false,
+            rpc_url_override: None,
+            holder_strategy: HolderStrategy::default(),
+            include_facts_hash: false,
+            preflight_ping: false,
+            at_slot: None,
+            at_block: None,
         }
     }
 }
 
+/// Body of `POST /api/v1/rescore`. A full prior `AnalyzeResponse` may be
+/// posted as-is (with `profile` added); only its `checks` are read.
+#[derive(Clone, Debug, Deserialize)]
+pub struct RescoreRequest {
+    pub checks: Vec<CheckResult>,
+    #[serde(default)]
+    pub profile: ScoringProfile,
+}
+
+/// Reply to `POST /api/v1/rescore`: the score, plus any inconsistencies
+/// found in the posted profile
 #[derive(Clone, Debug, Serialize)]
+pub struct RescoreResponse {
+    #[serde(flatten)]
+    pub score: ScoreResult,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<ProfileWarning>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AnalyzeResponse {
     pub schema_version: String,
     pub analysis_id: String,
@@ -44,38 +198,288 @@ pub struct AnalyzeResponse {
     pub token: Option<TokenMetadata>,
     pub checks: Vec<CheckResult>,
     pub score: ScoreResult,
-    pub explain: ExplainSection,
+    /// Recommended field for programmatic gating
+    pub verdict: Verdict,
+    /// Check counts for at-a-glance badges
+    #[serde(default)]
+    pub summary: CheckSummary,
+    /// Omitted at `explain_level: none`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explain: Option<ExplainSection>,
     pub errors: Vec<String>,
+    /// `errors` as machine-readable fetch failures
+    #[serde(default)]
+    pub error_details: Vec<ErrorDetail>,
+    /// Raw data lineage: provider, method and fetch time of each fact used
+    pub sources: Vec<FactSource>,
+    /// Present when `include_baseline` was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline_deltas: Option<Vec<BaselineDelta>>,
+    /// Present when `include_scoring_config` was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scoring_config: Option<ScoringConfig>,
+    /// The one failing check to lead with; None when nothing failed
+    #[serde(default)]
+    pub headline: Option<HeadlineFinding>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rpc_trace: Vec<RpcTraceEntry>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attestation: Option<Attestation>,
+    /// On a `force_refresh` that replaced a cached result: how this
+    /// result differs from it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changed_since_cache: Option<CacheDelta>,
+    /// Seconds since the analysis ran, on responses served from cache;
+    /// None for a fresh analysis
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age_of_analysis_seconds: Option<u64>,
+    /// Present when `include_facts_hash` was requested. Opt-in rather than
+    /// always set: responses built without the full facts (cache misses
+    /// under `cache_only`, incremental re-runs that re-fetched some facts)
+    /// have nothing sound to hash, and an empty hash would read as "same
+    /// data" to clients comparing them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facts_hash: Option<String>,
+    /// Provider calls made to produce this response (zero when served
+    /// from cache), in total and per method
+    #[serde(default)]
+    pub rpc_calls: RpcCallCount,
+    /// Slot the analysis was run as of (Solana `at_slot`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at_slot: Option<u64>,
+    /// Block the analysis was run as of (EVM `at_block`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at_block: Option<u64>,
 }
 
-#[derive(Clone, Debug, Serialize, PartialEq)]
+/// Single gate for bots: branch on this rather than interpreting grade and
+/// critical flags directly
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
+pub enum Verdict {
+    Safe,
+    Caution,
+    Danger,
+}
+
+impl Verdict {
+    /// Strong -> safe, Mixed/Fragile -> caution, Compromised/Insufficient ->
+    /// danger. A known critical failure is danger whatever the grade.
+    pub fn from_score(score: &ScoreResult, checks: &[CheckResult]) -> Self {
+        let critical_failure = checks.iter().any(|c| {
+            c.severity == Severity::Critical && c.status == CheckStatus::Fail
+        });
+        if critical_failure {
+            return Verdict::Danger;
+        }
+
+        match score.grade {
+            Grade::Strong => Verdict::Safe,
+            Grade::Mixed | Grade::Fragile => Verdict::Caution,
+            Grade::Compromised | Grade::Insufficient => Verdict::Danger,
+        }
+    }
+}
+
+/// Counts of the response's checks. Not-applicable checks are left out
+/// entirely; `by_severity` counts failed checks only.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct CheckSummary {
+    pub passed: u32,
+    pub failed: u32,
+    pub unknown: u32,
+    pub by_severity: SeverityCounts,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct SeverityCounts {
+    pub critical: u32,
+    pub high: u32,
+    pub medium: u32,
+    pub low: u32,
+}
+
+impl CheckSummary {
+    pub fn from_checks(checks: &[CheckResult]) -> Self {
+        let mut summary = CheckSummary::default();
+        let applicable = checks.iter()
+            .filter(|c| c.is_expected());
+        for check in applicable {
+            match check.status {
+                CheckStatus::Pass => summary.passed += 1,
+                CheckStatus::Unknown => summary.unknown += 1,
+                CheckStatus::Fail => {
+                    summary.failed += 1;
+                    let by_severity = &mut summary.by_severity;
+                    match check.severity {
+                        Severity::Critical => by_severity.critical += 1,
+                        Severity::High => by_severity.high += 1,
+                        Severity::Medium => by_severity.medium += 1,
+                        Severity::Low => by_severity.low += 1,
+                    }
+                }
+            }
+        }
+        summary
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
 pub enum AnalysisStatus {
     Ok,
     Partial,
     Error,
+    /// `cache_only` request with no cached result; nothing was analyzed
+    NotCached,
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TokenMetadata {
     pub name: Option<String>,
     pub symbol: Option<String>,
     pub decimals: Option<u8>,
     pub total_supply: Option<f64>,
+    /// `total_supply` formatted for the requested locale
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_supply_display: Option<String>,
     pub program_standard: String,
     pub created_at: Option<String>,
     pub age_seconds: Option<u64>,
     pub age_band: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<ImageInfo>,
+    /// Informational: optional interfaces such as EIP-2612 `permit`; not scored
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub features: Vec<TokenFeature>,
 }
 
-#[derive(Clone, Debug, Serialize)]
+/// Most severe failing check, ties broken by weight
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HeadlineFinding {
+    pub id: String,
+    pub label: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// At `explain_level: summary` only `summary` is filled; the empty parts
+/// are left out of the JSON
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ExplainSection {
+    /// One-sentence verdict for compact UIs
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub headline: String,
     pub summary: String,
+    /// One narrative line per check
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub method: Vec<String>,
+    #[serde(default, skip_serializing_if = "InterpretationSection::is_empty")]
     pub interpretation: InterpretationSection,
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct InterpretationSection {
     pub what_to_do: Vec<String>,
 }
+
+impl InterpretationSection {
+    pub fn is_empty(&self) -> bool {
+        self.what_to_do.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn score(grade: Grade, override_reason: Option<&str>) -> ScoreResult {
+        ScoreResult {
+            model: "weighted_sum_v1".to_string(),
+            fairness_score: Some(50),
+            grade,
+            components: vec![],
+            weights_total: 100,
+            notes: vec![],
+            override_reason: override_reason.map(|r| r.to_string()),
+        }
+    }
+
+    fn check(severity: Severity, status: CheckStatus) -> CheckResult {
+        CheckResult {
+            id: "check".to_string(),
+            label: "check".to_string(),
+            category: "test".to_string(),
+            status,
+            severity,
+            value: json!(null),
+            evidence: json!({}),
+            weight: 10,
+            score_component: None,
+            confidence: 1.0,
+            unknown_reason: None,
+            learn_more_url: None,
+        }
+    }
+
+    #[test]
+    fn test_verdict_per_grade() {
+        assert_eq!(Verdict::from_score(&score(Grade::Strong, None), &[]), Verdict::Safe);
+        assert_eq!(Verdict::from_score(&score(Grade::Mixed, None), &[]), Verdict::Caution);
+        assert_eq!(Verdict::from_score(&score(Grade::Fragile, None), &[]), Verdict::Caution);
+        assert_eq!(Verdict::from_score(&score(Grade::Compromised, None), &[]), Verdict::Danger);
+        assert_eq!(Verdict::from_score(&score(Grade::Insufficient, None), &[]), Verdict::Danger);
+    }
+
+    #[test]
+    fn test_verdict_with_overrides() {
+        // Strong capped at Mixed by an unverified critical check
+        let capped = score(Grade::Mixed, Some("Grade capped at Mixed"));
+        let unknown_critical = [check(Severity::Critical, CheckStatus::Unknown)];
+        assert_eq!(Verdict::from_score(&capped, &unknown_critical), Verdict::Caution);
+
+        // A known critical failure is danger even if the grade says otherwise
+        let critical_failure = [check(Severity::Critical, CheckStatus::Fail)];
+        assert_eq!(Verdict::from_score(&score(Grade::Strong, None), &critical_failure), Verdict::Danger);
+
+        // Non-critical failures follow the grade
+        let high_failure = [check(Severity::High, CheckStatus::Fail)];
+        assert_eq!(Verdict::from_score(&score(Grade::Strong, None), &high_failure), Verdict::Safe);
+    }
+
+    #[test]
+    fn test_summary_counts_mixed_checks() {
+        let not_applicable = CheckResult {
+            unknown_reason: Some(UnknownReason::NotApplicable),
+            learn_more_url: None,
+            ..check(Severity::Medium, CheckStatus::Unknown)
+        };
+        let checks = [
+            check(Severity::Critical, CheckStatus::Fail),
+            check(Severity::Critical, CheckStatus::Fail),
+            check(Severity::High, CheckStatus::Fail),
+            check(Severity::Low, CheckStatus::Fail),
+            check(Severity::Critical, CheckStatus::Pass),
+            check(Severity::High, CheckStatus::Pass),
+            check(Severity::Medium, CheckStatus::Pass),
+            check(Severity::High, CheckStatus::Unknown),
+            not_applicable,
+        ];
+
+        let summary = CheckSummary::from_checks(&checks);
+        assert_eq!(summary, CheckSummary {
+            passed: 3,
+            failed: 4,
+            unknown: 1,
+            by_severity: SeverityCounts { critical: 2, high: 1, medium: 0, low: 1 },
+        });
+    }
+
+    #[test]
+    fn test_verdict_serializes_lowercase() {
+        assert_eq!(serde_json::to_value(Verdict::Caution).unwrap(), json!("caution"));
+    }
+}
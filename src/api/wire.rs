@@ -0,0 +1,76 @@
+// src/api/wire.rs
+//
+// Response content negotiation: JSON by default, MessagePack or CBOR for
+// high-frequency clients that ask for a compact binary body.
+
+use serde::Serialize;
+
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+pub const MSGPACK_CONTENT_TYPE: &str = "application/msgpack";
+pub const CBOR_CONTENT_TYPE: &str = "application/cbor";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum WireFormat {
+    #[default]
+    Json,
+    MsgPack,
+    Cbor,
+}
+
+impl WireFormat {
+    /// Format for an `Accept` header: the first binary type listed wins,
+    /// anything else (or no header) is JSON
+    pub fn from_accept(accept: Option<&str>) -> Self {
+        let Some(accept) = accept else {
+            return WireFormat::Json;
+        };
+        for media_type in accept.split(',') {
+            let media_type = media_type.split(';').next().unwrap_or("").trim();
+            match media_type.to_ascii_lowercase().as_str() {
+                MSGPACK_CONTENT_TYPE | "application/x-msgpack" => return WireFormat::MsgPack,
+                CBOR_CONTENT_TYPE => return WireFormat::Cbor,
+                _ => {}
+            }
+        }
+        WireFormat::Json
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            WireFormat::Json => JSON_CONTENT_TYPE,
+            WireFormat::MsgPack => MSGPACK_CONTENT_TYPE,
+            WireFormat::Cbor => CBOR_CONTENT_TYPE,
+        }
+    }
+
+    /// Serialize `value`. MessagePack structs are written as maps, not
+    /// positional arrays, since fields skipped when empty would otherwise
+    /// shift every later field.
+    pub fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, String> {
+        match self {
+            WireFormat::Json => serde_json::to_vec(value).map_err(|e| e.to_string()),
+            WireFormat::MsgPack => rmp_serde::to_vec_named(value).map_err(|e| e.to_string()),
+            WireFormat::Cbor => {
+                let mut body = Vec::new();
+                ciborium::into_writer(value, &mut body).map_err(|e| e.to_string())?;
+                Ok(body)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_negotiation() {
+        assert_eq!(WireFormat::from_accept(None), WireFormat::Json);
+        assert_eq!(WireFormat::from_accept(Some("*/*")), WireFormat::Json);
+        assert_eq!(WireFormat::from_accept(Some("application/msgpack")), WireFormat::MsgPack);
+        assert_eq!(
+            WireFormat::from_accept(Some("text/html, application/CBOR;q=0.9")),
+            WireFormat::Cbor
+        );
+    }
+}
@@ -0,0 +1,86 @@
+// src/burn.rs
+//
+// Addresses that tokens are sent to in order to destroy them. Used for
+// ownership renouncement, holder classification and burn metrics.
+
+use crate::api::chain::{normalize_address, normalize_chain};
+use std::collections::{HashMap, HashSet};
+
+pub const SOLANA_INCINERATOR: &str = "1nc1nerator11111111111111111111111111111111";
+pub const EVM_ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
+pub const EVM_DEAD_ADDRESS: &str = "0x000000000000000000000000000000000000dead";
+
+const EVM_CHAINS: [&str; 3] = ["ethereum", "base", "evm"];
+
+/// Burn addresses per canonical chain. `default()` holds the well-known
+/// ones; operators extend it with `with_address`.
+#[derive(Clone, Debug)]
+pub struct BurnAddresses {
+    by_chain: HashMap<String, HashSet<String>>,
+}
+
+impl BurnAddresses {
+    /// No burn addresses at all
+    pub fn empty() -> Self {
+        Self {
+            by_chain: HashMap::new(),
+        }
+    }
+
+    /// Add `address` as a burn address on `chain` (aliases accepted)
+    pub fn with_address(mut self, chain: &str, address: &str) -> Self {
+        let chain = canonical(chain);
+        let address = normalize_address(&chain, address);
+        self.by_chain.entry(chain).or_default().insert(address);
+        self
+    }
+
+    pub fn is_burn(&self, chain: &str, address: &str) -> bool {
+        let chain = canonical(chain);
+        let address = normalize_address(&chain, address);
+        self.by_chain.get(&chain).is_some_and(|set| set.contains(&address))
+    }
+}
+
+impl Default for BurnAddresses {
+    fn default() -> Self {
+        let mut burn = Self::empty().with_address("solana", SOLANA_INCINERATOR);
+        for chain in EVM_CHAINS {
+            burn = burn
+                .with_address(chain, EVM_ZERO_ADDRESS)
+                .with_address(chain, EVM_DEAD_ADDRESS);
+        }
+        burn
+    }
+}
+
+fn canonical(chain: &str) -> String {
+    normalize_chain(chain).unwrap_or_else(|| chain.trim().to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_well_known_burn_addresses() {
+        let burn = BurnAddresses::default();
+
+        assert!(burn.is_burn("solana", SOLANA_INCINERATOR));
+        assert!(burn.is_burn("base", "0x000000000000000000000000000000000000dEaD"));
+        assert!(burn.is_burn("eth", EVM_ZERO_ADDRESS));
+
+        // Burn addresses are per chain
+        assert!(!burn.is_burn("solana", EVM_DEAD_ADDRESS));
+        assert!(!burn.is_burn("base", SOLANA_INCINERATOR));
+    }
+
+    #[test]
+    fn test_configured_burn_address() {
+        let custom = "0x1111111111111111111111111111111111111111";
+        let burn = BurnAddresses::default().with_address("base", custom);
+
+        assert!(burn.is_burn("base", custom));
+        assert!(!burn.is_burn("ethereum", custom));
+    }
+}
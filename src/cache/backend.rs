@@ -0,0 +1,22 @@
+// src/cache/backend.rs
+
+use crate::api::types::AnalyzeResponse;
+use super::SimpleCache;
+
+/// Storage for analysis responses, keyed by request
+pub trait CacheBackend {
+    /// A fresh (unexpired) response for `key`
+    fn get(&self, key: &str) -> Option<AnalyzeResponse>;
+
+    fn set(&mut self, key: String, response: AnalyzeResponse, ttl_seconds: u64);
+}
+
+impl CacheBackend for SimpleCache {
+    fn get(&self, key: &str) -> Option<AnalyzeResponse> {
+        SimpleCache::get(self, key)
+    }
+
+    fn set(&mut self, key: String, response: AnalyzeResponse, ttl_seconds: u64) {
+        SimpleCache::set(self, key, response, ttl_seconds)
+    }
+}
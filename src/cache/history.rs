@@ -0,0 +1,175 @@
+// src/cache/history.rs
+//
+// Rolling per-address history of grades and scores from fresh analyses,
+// bounded in both addresses tracked and points kept per address.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::Serialize;
+use crate::api::types::AnalyzeResponse;
+use crate::types::Grade;
+
+pub const DEFAULT_MAX_POINTS_PER_ADDRESS: usize = 50;
+pub const DEFAULT_MAX_ADDRESSES: usize = 1000;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct HistoryPoint {
+    pub timestamp: u64,
+    pub analysis_id: String,
+    pub grade: Grade,
+    pub fairness_score: Option<u8>,
+}
+
+/// Bounded in-memory timeline of fresh analyses, keyed by `chain:address`.
+/// Memory is capped at `max_addresses * max_points_per_address` points:
+/// each address keeps a ring buffer of its latest points, and the least
+/// recently updated address is evicted once `max_addresses` is reached.
+pub struct AnalysisHistory {
+    max_points_per_address: usize,
+    max_addresses: usize,
+    entries: HashMap<String, VecDeque<HistoryPoint>>,
+    // Least recently updated key at the front
+    order: VecDeque<String>,
+}
+
+impl AnalysisHistory {
+    pub fn new(max_points_per_address: usize, max_addresses: usize) -> Self {
+        Self {
+            max_points_per_address,
+            max_addresses,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    pub fn record(&mut self, key: &str, response: &AnalyzeResponse) {
+        if self.max_points_per_address == 0 || self.max_addresses == 0 {
+            return;
+        }
+
+        let point = HistoryPoint {
+            timestamp: current_timestamp(),
+            analysis_id: response.analysis_id.clone(),
+            grade: response.score.grade.clone(),
+            fairness_score: response.score.fairness_score,
+        };
+
+        // Mark key as most recently updated
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            self.order.remove(pos);
+        } else if self.entries.len() >= self.max_addresses {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.order.push_back(key.to_string());
+
+        let points = self.entries.entry(key.to_string()).or_default();
+        if points.len() >= self.max_points_per_address {
+            points.pop_front();
+        }
+        points.push_back(point);
+    }
+
+    /// Points for `key`, oldest first
+    pub fn get(&self, key: &str) -> Vec<HistoryPoint> {
+        self.entries.get(key)
+            .map(|points| points.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn tracked_addresses(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+impl Default for AnalysisHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_POINTS_PER_ADDRESS, DEFAULT_MAX_ADDRESSES)
+    }
+}
+
+fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{analyze, AnalyzeOptions, AnalyzeRequest};
+    use crate::providers::mocks::MockProvider;
+    use crate::types::*;
+
+    fn facts_with_mint_authority(mint_authority: Option<&str>) -> TokenFacts {
+        TokenFacts {
+            metadata: Some(Metadata {
+                name: Some("Hist".to_string()),
+                symbol: Some("HIST".to_string()),
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            authorities: Some(AuthorityInfo {
+                mint_authority: mint_authority.map(|s| s.to_string()),
+                freeze_authority: None,
+                owner: None,
+                mint_mutable: Some(mint_authority.is_some()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn request() -> AnalyzeRequest {
+        AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "hist_token".to_string(),
+            options: AnalyzeOptions::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_two_analyses_record_two_points() {
+        let mut history = AnalysisHistory::default();
+
+        let clean = MockProvider::new("test")
+            .with_facts("hist_token", facts_with_mint_authority(None));
+        let response = analyze(request(), &clean).await;
+        history.record("solana:hist_token", &response);
+
+        let minted = MockProvider::new("test")
+            .with_facts("hist_token", facts_with_mint_authority(Some("Authority")));
+        let response = analyze(request(), &minted).await;
+        history.record("solana:hist_token", &response);
+
+        let points = history.get("solana:hist_token");
+        assert_eq!(points.len(), 2);
+        assert!(matches!(points[0].grade, Grade::Strong));
+        assert!(matches!(points[1].grade, Grade::Compromised));
+        assert!(history.get("solana:other").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_history_is_bounded() {
+        let mut history = AnalysisHistory::new(2, 2);
+        let provider = MockProvider::new("test")
+            .with_facts("hist_token", facts_with_mint_authority(None));
+        let response = analyze(request(), &provider).await;
+
+        for _ in 0..5 {
+            history.record("solana:a", &response);
+        }
+        assert_eq!(history.get("solana:a").len(), 2);
+
+        history.record("solana:b", &response);
+        history.record("solana:c", &response);
+
+        // Least recently updated address evicted
+        assert_eq!(history.tracked_addresses(), 2);
+        assert!(history.get("solana:a").is_empty());
+        assert_eq!(history.get("solana:c").len(), 1);
+    }
+}
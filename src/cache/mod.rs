@@ -1,5 +1,9 @@
 // src/cache/mod.rs
 
 pub mod simple_cache;
+pub mod history;
+pub mod backend;
 
 pub use simple_cache::SimpleCache;
+pub use history::{AnalysisHistory, HistoryPoint};
+pub use backend::CacheBackend;
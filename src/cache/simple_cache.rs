@@ -2,6 +2,20 @@ use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 use crate::api::types::AnalyzeResponse;
 
+/// Prefix written into `requested_at` for responses served from cache
+const CACHED_MARKER: &str = "cached_";
+
+/// Whether `response` was served from cache rather than freshly analyzed
+pub fn is_from_cache(response: &AnalyzeResponse) -> bool {
+    response.requested_at.starts_with(CACHED_MARKER)
+}
+
+/// Mark `response` as served from a result computed at `cached_at`
+pub fn mark_cached(response: &mut AnalyzeResponse, cached_at: u64) {
+    response.requested_at = format!("{}{}", CACHED_MARKER, cached_at);
+    response.age_of_analysis_seconds = Some(current_timestamp().saturating_sub(cached_at));
+}
+
 #[derive(Clone)]
 pub struct CacheEntry {
     pub response: AnalyzeResponse,
@@ -30,7 +44,7 @@ impl SimpleCache {
                 let mut response = entry.response.clone();
                 
                 // Update cache metadata in response
-                response.requested_at = format!("cached_{}", entry.cached_at);
+                mark_cached(&mut response, entry.cached_at);
                 
                 return Some(response);
             }
@@ -76,7 +90,7 @@ impl Default for SimpleCache {
     }
 }
 
-fn current_timestamp() -> u64 {
+pub(crate) fn current_timestamp() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap()
@@ -100,13 +114,13 @@ pub fn ttl_for_response(response: &AnalyzeResponse) -> u64 {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
-    use crate::api::types::{AnalyzeResponse, AnalysisStatus, ExplainSection, InterpretationSection};
+    use crate::api::types::{AnalyzeResponse, AnalysisStatus, ExplainSection, InterpretationSection, Verdict};
     use crate::scoring::ScoreResult;
     use crate::types::Grade;
 
-    fn make_test_response() -> AnalyzeResponse {
+    pub(crate) fn make_test_response() -> AnalyzeResponse {
         AnalyzeResponse {
             schema_version: "1.0.0".to_string(),
             analysis_id: "test123".to_string(),
@@ -123,15 +137,33 @@ mod tests {
                 components: vec![],
                 weights_total: 100,
                 notes: vec![],
+                override_reason: None,
             },
-            explain: ExplainSection {
+            verdict: Verdict::Safe,
+            summary: Default::default(),
+            explain: Some(ExplainSection {
+                headline: "Test".to_string(),
                 summary: "Test".to_string(),
+                details: vec![],
                 method: vec![],
                 interpretation: InterpretationSection {
                     what_to_do: vec![],
                 },
-            },
+            }),
             errors: vec![],
+            error_details: vec![],
+            sources: vec![],
+            baseline_deltas: None,
+            scoring_config: None,
+            headline: None,
+            rpc_trace: vec![],
+            attestation: None,
+            changed_since_cache: None,
+            age_of_analysis_seconds: None,
+            facts_hash: None,
+            rpc_calls: Default::default(),
+            at_slot: None,
+            at_block: None,
         }
     }
 
@@ -147,6 +179,22 @@ mod tests {
         
         let cached_response = cached.unwrap();
         assert_eq!(cached_response.analysis_id, "test123");
+        assert!(is_from_cache(&cached_response));
+        assert!(!is_from_cache(&response));
+    }
+
+    #[test]
+    fn test_cached_response_reports_analysis_age() {
+        let mut cache = SimpleCache::new();
+        let response = make_test_response();
+        assert_eq!(response.age_of_analysis_seconds, None);
+
+        cache.set("test_key".to_string(), response, 3600);
+        // Analysis ran two minutes ago
+        cache.entries.get_mut("test_key").unwrap().cached_at -= 120;
+
+        let age = cache.get("test_key").unwrap().age_of_analysis_seconds.unwrap();
+        assert!((120..=121).contains(&age), "{}", age);
     }
 
     #[test]
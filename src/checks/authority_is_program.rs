@@ -0,0 +1,118 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+/// Score when the authority sits behind code instead of a single key
+const PROGRAM_SCORE: u8 = 50;
+
+/// Refines the binary authority checks: a live mint authority (Solana) or
+/// owner (EVM) held by a program or contract (DAO, timelock, multisig) gets
+/// partial credit, a bare private key none. Not applicable once the
+/// authority is gone.
+pub fn check_authority_is_program(facts: &TokenFacts) -> CheckResult {
+    let authorities = match &facts.authorities {
+        Some(auth) => auth,
+        None => return unknown_result(facts.missing_reason("authorities"), "authority data unavailable"),
+    };
+    let (role, controller) = match (&authorities.mint_authority, &authorities.owner) {
+        (Some(mint_authority), _) => ("mint_authority", mint_authority),
+        (None, Some(owner)) => ("owner", owner),
+        (None, None) => return unknown_result(UnknownReason::NotApplicable, "no mint authority or owner"),
+    };
+    let kind = match authorities.controller_kind {
+        Some(kind) => kind,
+        None => return unknown_result(UnknownReason::DataMissing, "authority account type unavailable"),
+    };
+    let is_program = kind == ControllerKind::Program;
+
+    CheckResult {
+        id: "authority_is_program".to_string(),
+        label: "Authority held by a program".to_string(),
+        category: "supply_control".to_string(),
+        status: if is_program { CheckStatus::Pass } else { CheckStatus::Fail },
+        severity: Severity::Medium,
+        value: json!(is_program),
+        evidence: json!({
+            "source": "provider",
+            "role": role,
+            "authority": controller,
+            "controller_kind": kind,
+            "m_of_n": authorities.mint_authority_multisig.as_ref().map(|m| m.m_of_n()),
+        }),
+        weight: 10,
+        score_component: if is_program { Some(PROGRAM_SCORE) } else { Some(0) },
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason, reason: &str) -> CheckResult {
+    CheckResult {
+        id: "authority_is_program".to_string(),
+        label: "Authority held by a program".to_string(),
+        category: "supply_control".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::Medium,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": reason
+        }),
+        weight: 10,
+        score_component: None,
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts_with(mint_authority: Option<&str>, owner: Option<&str>, kind: Option<ControllerKind>) -> TokenFacts {
+        TokenFacts {
+            authorities: Some(AuthorityInfo {
+                mint_authority: mint_authority.map(str::to_string),
+                owner: owner.map(str::to_string),
+                controller_kind: kind,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_eoa_owner_gets_no_credit() {
+        let result = check_authority_is_program(&facts_with(None, Some("0xowner"), Some(ControllerKind::Key)));
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.score_component, Some(0));
+        assert_eq!(result.evidence["role"], "owner");
+        assert_eq!(result.evidence["controller_kind"], "key");
+    }
+
+    #[test]
+    fn test_contract_owner_gets_partial_credit() {
+        let result = check_authority_is_program(&facts_with(None, Some("0xtimelock"), Some(ControllerKind::Program)));
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.score_component, Some(PROGRAM_SCORE));
+        assert_eq!(result.evidence["controller_kind"], "program");
+    }
+
+    #[test]
+    fn test_mint_authority_classified_and_renounced_not_applicable() {
+        let result = check_authority_is_program(&facts_with(Some("MultisigPda"), None, Some(ControllerKind::Program)));
+        assert_eq!(result.evidence["role"], "mint_authority");
+        assert_eq!(result.status, CheckStatus::Pass);
+
+        let result = check_authority_is_program(&facts_with(None, None, None));
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::NotApplicable));
+
+        let result = check_authority_is_program(&facts_with(Some("Key"), None, None));
+        assert_eq!(result.unknown_reason, Some(UnknownReason::DataMissing));
+    }
+}
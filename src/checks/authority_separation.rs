@@ -0,0 +1,112 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+/// One key holding both mint and freeze authority can inflate supply and
+/// freeze holders at once. A real multisig (two or more required signers)
+/// holding both is not a single key and passes.
+pub fn check_authority_separation(facts: &TokenFacts) -> CheckResult {
+    let authorities = match &facts.authorities {
+        Some(auth) => auth,
+        None => return unknown_result(facts.missing_reason("authorities")),
+    };
+
+    let shared_key = match (&authorities.mint_authority, &authorities.freeze_authority) {
+        (Some(mint), Some(freeze)) if mint == freeze => Some(mint),
+        _ => None,
+    };
+    let multisig = authorities.mint_authority_multisig.as_ref()
+        .filter(|m| m.is_threshold_protected());
+    let is_separated = shared_key.is_none() || multisig.is_some();
+
+    CheckResult {
+        id: "authority_separation".to_string(),
+        label: "Authority separation".to_string(),
+        category: "supply_control".to_string(),
+        status: if is_separated { CheckStatus::Pass } else { CheckStatus::Fail },
+        severity: Severity::Medium,
+        value: json!(is_separated),
+        evidence: json!({
+            "source": "provider",
+            "mint_authority": authorities.mint_authority,
+            "freeze_authority": authorities.freeze_authority,
+            "same_key": shared_key.is_some(),
+            "m_of_n": multisig.map(|m| m.m_of_n()),
+        }),
+        weight: 10,
+        score_component: if is_separated { Some(100) } else { Some(0) },
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason) -> CheckResult {
+    CheckResult {
+        id: "authority_separation".to_string(),
+        label: "Authority separation".to_string(),
+        category: "supply_control".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::Medium,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": "authority data unavailable"
+        }),
+        weight: 10,
+        score_component: None,
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts_with(mint: Option<&str>, freeze: Option<&str>) -> TokenFacts {
+        TokenFacts {
+            authorities: Some(AuthorityInfo {
+                mint_authority: mint.map(|s| s.to_string()),
+                freeze_authority: freeze.map(|s| s.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_same_key_fails() {
+        let result = check_authority_separation(&facts_with(Some("DevKey"), Some("DevKey")));
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.severity, Severity::Medium);
+        assert_eq!(result.evidence["mint_authority"], "DevKey");
+        assert_eq!(result.evidence["freeze_authority"], "DevKey");
+        assert_eq!(result.evidence["same_key"], true);
+    }
+
+    #[test]
+    fn test_disabled_authorities_pass() {
+        let result = check_authority_separation(&facts_with(None, None));
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.score_component, Some(100));
+    }
+
+    #[test]
+    fn test_separated_authorities_pass() {
+        let result = check_authority_separation(&facts_with(Some("MintKey"), Some("FreezeKey")));
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.evidence["same_key"], false);
+    }
+
+    #[test]
+    fn test_authorities_unavailable_unknown() {
+        let result = check_authority_separation(&TokenFacts::default());
+
+        assert_eq!(result.status, CheckStatus::Unknown);
+    }
+}
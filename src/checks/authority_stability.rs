@@ -0,0 +1,135 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+/// Authority transfers younger than this are flagged
+pub const RECENT_TRANSFER_WINDOW_SECS: u64 = 7 * 24 * 60 * 60;
+
+pub fn check_authority_stability(facts: &TokenFacts) -> CheckResult {
+    let history = match &facts.authority_history {
+        Some(h) => h,
+        None => return unknown_result(facts.missing_reason("authority_history"), "authority history unavailable"),
+    };
+
+    // Revocations (no new authority) only ever reduce control
+    let transfers: Vec<&AuthorityChange> = history.iter()
+        .filter(|c| c.new_authority.is_some())
+        .collect();
+
+    let recent: Vec<&AuthorityChange> = transfers.iter()
+        .copied()
+        .filter(|c| matches!(c.age_seconds, Some(age) if age < RECENT_TRANSFER_WINDOW_SECS))
+        .collect();
+
+    if recent.is_empty() && transfers.iter().any(|c| c.age_seconds.is_none()) {
+        return unknown_result(UnknownReason::DataMissing, "authority transfer time unavailable");
+    }
+
+    let is_stable = recent.is_empty();
+
+    CheckResult {
+        id: "authority_stability".to_string(),
+        label: "Authority stability".to_string(),
+        category: "supply_control".to_string(),
+        status: if is_stable { CheckStatus::Pass } else { CheckStatus::Fail },
+        severity: Severity::Medium,
+        value: json!(recent.len()),
+        evidence: json!({
+            "source": "provider",
+            "authority_changes": history.len(),
+            "recent_transfers": recent,
+            "window_seconds": RECENT_TRANSFER_WINDOW_SECS,
+        }),
+        weight: 10,
+        score_component: if is_stable { Some(100) } else { Some(0) },
+        confidence: confidence::PROVIDER_REPORTED,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason, reason: &str) -> CheckResult {
+    CheckResult {
+        id: "authority_stability".to_string(),
+        label: "Authority stability".to_string(),
+        category: "supply_control".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::Medium,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": reason
+        }),
+        weight: 10,
+        score_component: None,
+        confidence: confidence::PROVIDER_REPORTED,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(new_authority: Option<&str>, age_seconds: u64) -> AuthorityChange {
+        AuthorityChange {
+            authority_type: "mintTokens".to_string(),
+            new_authority: new_authority.map(|s| s.to_string()),
+            signature: Some("sig".to_string()),
+            block_time: Some(1_700_000_000),
+            age_seconds: Some(age_seconds),
+        }
+    }
+
+    #[test]
+    fn test_recent_transfer_fail() {
+        let facts = TokenFacts {
+            authority_history: Some(vec![change(Some("NewAuthority"), 3600)]),
+            ..Default::default()
+        };
+
+        let result = check_authority_stability(&facts);
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.severity, Severity::Medium);
+        assert_eq!(result.score_component, Some(0));
+        assert_eq!(result.evidence["recent_transfers"][0]["new_authority"], "NewAuthority");
+    }
+
+    #[test]
+    fn test_no_changes_pass() {
+        let facts = TokenFacts {
+            authority_history: Some(vec![]),
+            ..Default::default()
+        };
+
+        let result = check_authority_stability(&facts);
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.score_component, Some(100));
+    }
+
+    #[test]
+    fn test_old_transfer_and_recent_revoke_pass() {
+        let facts = TokenFacts {
+            authority_history: Some(vec![
+                change(None, 60),
+                change(Some("OldAuthority"), RECENT_TRANSFER_WINDOW_SECS * 10),
+            ]),
+            ..Default::default()
+        };
+
+        let result = check_authority_stability(&facts);
+
+        assert_eq!(result.status, CheckStatus::Pass);
+    }
+
+    #[test]
+    fn test_history_unavailable_unknown() {
+        let result = check_authority_stability(&TokenFacts::default());
+
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.score_component, None);
+    }
+}
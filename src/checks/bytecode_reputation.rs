@@ -0,0 +1,105 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+use std::collections::HashSet;
+
+/// Flags contracts whose runtime bytecode matches a known-malicious template.
+/// `known_malicious` holds `0x`-prefixed keccak-256 hashes (any case).
+/// A match is decisive, but a non-match proves nothing, so the check carries
+/// no score weight.
+pub fn check_bytecode_reputation(facts: &TokenFacts, known_malicious: &HashSet<String>) -> CheckResult {
+    let bytecode = match &facts.bytecode {
+        Some(b) => b,
+        None => return unknown_result(facts.missing_reason("bytecode")),
+    };
+
+    let is_known_bad = known_malicious.iter()
+        .any(|hash| hash.eq_ignore_ascii_case(&bytecode.code_hash));
+
+    CheckResult {
+        id: "bytecode_reputation".to_string(),
+        label: "Bytecode reputation".to_string(),
+        category: "contract".to_string(),
+        status: if is_known_bad { CheckStatus::Fail } else { CheckStatus::Pass },
+        severity: Severity::Critical,
+        value: json!(is_known_bad),
+        evidence: json!({
+            "source": "provider",
+            "code_hash": bytecode.code_hash,
+            "code_size": bytecode.code_size,
+            "known_malicious_hashes": known_malicious.len(),
+        }),
+        weight: 0,
+        score_component: if is_known_bad { Some(0) } else { Some(100) },
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason) -> CheckResult {
+    CheckResult {
+        id: "bytecode_reputation".to_string(),
+        label: "Bytecode reputation".to_string(),
+        category: "contract".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::Critical,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": "contract bytecode unavailable"
+        }),
+        weight: 0,
+        score_component: None,
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPLATE_HASH: &str = "0x4d741b6f1eb29cb2a9b9911c82f56fa8d73b04959d3d9d222895df6c0b28aa15";
+
+    fn facts_with_hash(code_hash: &str) -> TokenFacts {
+        TokenFacts {
+            bytecode: Some(BytecodeInfo {
+                code_hash: code_hash.to_string(),
+                code_size: 1024,
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn blocklist() -> HashSet<String> {
+        HashSet::from([TEMPLATE_HASH.to_uppercase().replacen("0X", "0x", 1)])
+    }
+
+    #[test]
+    fn test_matching_hash_fails_critical() {
+        let result = check_bytecode_reputation(&facts_with_hash(TEMPLATE_HASH), &blocklist());
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.severity, Severity::Critical);
+        assert_eq!(result.evidence["code_hash"], TEMPLATE_HASH);
+    }
+
+    #[test]
+    fn test_non_matching_hash_passes() {
+        let other = "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470";
+        let result = check_bytecode_reputation(&facts_with_hash(other), &blocklist());
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.evidence["code_hash"], other);
+    }
+
+    #[test]
+    fn test_bytecode_unavailable_unknown() {
+        let result = check_bytecode_reputation(&TokenFacts::default(), &blocklist());
+
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::DataMissing));
+    }
+}
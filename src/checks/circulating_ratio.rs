@@ -0,0 +1,106 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+/// Circulating below this share of total supply is flagged: the remainder
+/// (locked, vesting, treasury) can be unlocked into the market later
+pub const LOW_CIRCULATING_PCT: f64 = 50.0;
+
+pub fn check_circulating_ratio(facts: &TokenFacts) -> CheckResult {
+    let supply = match &facts.supply {
+        Some(s) => s,
+        None => return unknown_result(facts.missing_reason("supply"), "supply data unavailable"),
+    };
+
+    let (total, circulating) = match (supply.total_supply, supply.circulating_supply) {
+        (Some(total), Some(circulating)) if total > 0.0 => (total, circulating),
+        (Some(_), Some(_)) => return unknown_result(UnknownReason::DataMissing, "total supply is zero"),
+        _ => return unknown_result(UnknownReason::DataMissing, "circulating supply not reported"),
+    };
+
+    let circulating_pct = (circulating / total * 100.0).clamp(0.0, 100.0);
+    let is_healthy = circulating_pct >= LOW_CIRCULATING_PCT;
+
+    CheckResult {
+        id: "circulating_ratio".to_string(),
+        label: "Circulating supply ratio".to_string(),
+        category: "supply_control".to_string(),
+        status: if is_healthy { CheckStatus::Pass } else { CheckStatus::Fail },
+        severity: Severity::Low,
+        value: json!(circulating_pct),
+        evidence: json!({
+            "source": "provider",
+            "total_supply": total,
+            "circulating_supply": circulating,
+            "circulating_pct": circulating_pct,
+            "threshold_pct": LOW_CIRCULATING_PCT,
+        }),
+        weight: 5,
+        score_component: if is_healthy { Some(100) } else { Some(circulating_pct.round() as u8) },
+        confidence: confidence::PROVIDER_REPORTED,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason, reason: &str) -> CheckResult {
+    CheckResult {
+        id: "circulating_ratio".to_string(),
+        label: "Circulating supply ratio".to_string(),
+        category: "supply_control".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::Low,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": reason
+        }),
+        weight: 5,
+        score_component: None,
+        confidence: confidence::PROVIDER_REPORTED,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts_with_supply(total: f64, circulating: Option<f64>) -> TokenFacts {
+        TokenFacts {
+            supply: Some(SupplyInfo {
+                total_supply_raw: None,
+                total_supply: Some(total),
+                circulating_supply: circulating,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_fully_circulating_pass() {
+        let result = check_circulating_ratio(&facts_with_supply(1_000_000.0, Some(1_000_000.0)));
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.score_component, Some(100));
+        assert_eq!(result.value, json!(100.0));
+    }
+
+    #[test]
+    fn test_ten_percent_circulating_warn() {
+        let result = check_circulating_ratio(&facts_with_supply(1_000_000.0, Some(100_000.0)));
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.severity, Severity::Low);
+        assert_eq!(result.score_component, Some(10));
+        assert_eq!(result.evidence["circulating_pct"], 10.0);
+    }
+
+    #[test]
+    fn test_circulating_not_reported_unknown() {
+        let result = check_circulating_ratio(&facts_with_supply(1_000_000.0, None));
+
+        assert_eq!(result.status, CheckStatus::Unknown);
+    }
+}
@@ -0,0 +1,56 @@
+// src/checks/confidence.rs
+//
+// How far a check's underlying data can be trusted, from 0.0 to 1.0.
+// Reported as `CheckResult.confidence`; scoring profiles may scale check
+// weights by it.
+
+/// On-chain account state decoded directly (mint account, bytecode)
+pub const PARSED_ACCOUNT: f64 = 1.0;
+
+/// Answer of a contract getter (`owner()`, ...): authoritative, but the
+/// contract decides what it returns
+pub const CONTRACT_CALL: f64 = 0.9;
+
+/// Figure reported by the provider, or scanned from a bounded window of
+/// transaction history
+pub const PROVIDER_REPORTED: f64 = 0.8;
+
+/// Distribution estimates (largest-accounts lists, third-party holder
+/// APIs) and getter-name heuristics
+pub const ESTIMATE: f64 = 0.6;
+
+/// Inferred from other activity because the fact itself was unavailable
+pub const INFERRED: f64 = 0.4;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::{check_holder_concentration, check_mint_authority_disabled};
+    use crate::types::*;
+
+    #[test]
+    fn test_estimated_holders_less_confident_than_parsed_authority() {
+        let facts = TokenFacts {
+            authorities: Some(AuthorityInfo {
+                mint_authority: None,
+                mint_mutable: Some(false),
+                ..Default::default()
+            }),
+            holders: Some(HolderInfo {
+                top1_pct: Some(5.0),
+                top5_pct: Some(15.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![],
+            }),
+            ..Default::default()
+        };
+
+        let authority = check_mint_authority_disabled(&facts);
+        let holders = check_holder_concentration(&facts);
+
+        assert_eq!(authority.confidence, PARSED_ACCOUNT);
+        assert_eq!(holders.confidence, ESTIMATE);
+        assert!(authority.confidence > holders.confidence);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+/// Flags Token-2022 mints whose default-account-state extension starts new
+/// token accounts frozen: holders cannot transfer until the freeze
+/// authority thaws them, which amounts to a trading lock
+pub fn check_default_state_unfrozen(facts: &TokenFacts) -> CheckResult {
+    let metadata = match &facts.metadata {
+        Some(m) => m,
+        None => return unknown_result(facts.missing_reason("metadata"), "metadata unavailable"),
+    };
+    if metadata.standard != TokenStandard::SplToken2022 {
+        return unknown_result(UnknownReason::NotApplicable, "default account state is a Token-2022 extension");
+    }
+    let state = match &metadata.default_account_state {
+        Some(state) => state,
+        None => return unknown_result(UnknownReason::DataMissing, "default account state unavailable"),
+    };
+
+    let is_frozen = *state == AccountState::Frozen;
+
+    CheckResult {
+        id: "default_state_unfrozen".to_string(),
+        label: "Default account state unfrozen".to_string(),
+        category: "interface".to_string(),
+        status: if is_frozen { CheckStatus::Fail } else { CheckStatus::Pass },
+        severity: Severity::High,
+        value: json!(!is_frozen),
+        evidence: json!({
+            "source": "provider",
+            "default_account_state": state,
+        }),
+        weight: 10,
+        score_component: if is_frozen { Some(0) } else { Some(100) },
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason, reason: &str) -> CheckResult {
+    CheckResult {
+        id: "default_state_unfrozen".to_string(),
+        label: "Default account state unfrozen".to_string(),
+        category: "interface".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::High,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": reason
+        }),
+        weight: 10,
+        score_component: None,
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts(standard: TokenStandard, state: Option<AccountState>) -> TokenFacts {
+        TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(6),
+                standard,
+                default_account_state: state,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_frozen_default_fails() {
+        let result = check_default_state_unfrozen(&facts(TokenStandard::SplToken2022, Some(AccountState::Frozen)));
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.severity, Severity::High);
+        assert_eq!(result.evidence["default_account_state"], "frozen");
+    }
+
+    #[test]
+    fn test_initialized_default_passes() {
+        let result = check_default_state_unfrozen(&facts(TokenStandard::SplToken2022, Some(AccountState::Initialized)));
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.evidence["default_account_state"], "initialized");
+    }
+
+    #[test]
+    fn test_classic_spl_token_not_applicable() {
+        let result = check_default_state_unfrozen(&facts(TokenStandard::SplToken, None));
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::NotApplicable));
+    }
+
+    #[test]
+    fn test_undetected_state_unknown() {
+        let result = check_default_state_unfrozen(&facts(TokenStandard::SplToken2022, None));
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::DataMissing));
+    }
+}
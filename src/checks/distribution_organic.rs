@@ -0,0 +1,222 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Fewest wallets the heuristic will judge
+const MIN_WALLETS: usize = 5;
+/// Wallets sharing one exact balance that read as a scripted airdrop
+const IDENTICAL_CLUSTER_MIN: usize = 3;
+/// Share of wallets holding a whole multiple of `ROUND_UNIT` tokens
+const ROUND_SHARE_MAX: f64 = 0.6;
+/// Whole-token multiple that counts as a round balance
+const ROUND_UNIT: u128 = 1_000;
+/// Balances in a constant-step progression that read as sequential
+const SEQUENTIAL_RUN_MIN: usize = 4;
+
+/// Flags top-holder balances that look machine-distributed: clusters of
+/// identical balances, mostly round amounts, or an arithmetic progression.
+/// Heuristic; each signal that fired is named in evidence. Pools and burn
+/// addresses are not distribution wallets and are left out.
+pub fn check_distribution_organic(facts: &TokenFacts) -> CheckResult {
+    let holders = match &facts.holders {
+        Some(h) => h,
+        None => return unknown_result(facts.missing_reason("holders"), "holder data unavailable"),
+    };
+    if !facts.is_requested(HOLDER_LIST_FACT) {
+        return unknown_result(UnknownReason::NotRequested, HOLDER_LIST_NOT_REQUESTED);
+    }
+    let balances: Vec<u128> = holders.top_holders.iter()
+        .filter(|h| !matches!(h.kind, HolderKind::Burn | HolderKind::Lp))
+        .filter_map(|h| h.balance_raw.parse::<u128>().ok())
+        .filter(|&raw| raw > 0)
+        .collect();
+    if balances.len() < MIN_WALLETS {
+        return unknown_result(UnknownReason::DataMissing, "too few top-holder balances to judge");
+    }
+
+    let (cluster_balance, cluster_size) = largest_identical_cluster(&balances);
+    // Round amounts need decimals to tell whole tokens from dust
+    let round_share = facts.metadata.as_ref()
+        .and_then(|m| m.decimals)
+        .map(|decimals| round_share(&balances, decimals));
+    let sequential_run = longest_sequential_run(&balances);
+
+    let mut signals = Vec::new();
+    if cluster_size >= IDENTICAL_CLUSTER_MIN {
+        signals.push("identical_balances");
+    }
+    if round_share.is_some_and(|share| share >= ROUND_SHARE_MAX) {
+        signals.push("round_balances");
+    }
+    if sequential_run >= SEQUENTIAL_RUN_MIN {
+        signals.push("sequential_balances");
+    }
+    let organic = signals.is_empty();
+
+    CheckResult {
+        id: "distribution_organic".to_string(),
+        label: "Organic distribution".to_string(),
+        category: "distribution".to_string(),
+        status: if organic { CheckStatus::Pass } else { CheckStatus::Fail },
+        severity: Severity::Low,
+        value: json!(organic),
+        evidence: json!({
+            "source": "provider",
+            "method": "top-holder balance patterns",
+            "wallets_considered": balances.len(),
+            "signals": signals,
+            "largest_identical_cluster": {
+                "balance_raw": cluster_balance.to_string(),
+                "wallets": cluster_size,
+            },
+            "round_share": round_share,
+            "longest_sequential_run": sequential_run,
+        }),
+        weight: 5,
+        score_component: if organic { Some(100) } else { Some(0) },
+        confidence: confidence::ESTIMATE,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+/// Most common exact balance and how many wallets hold it
+fn largest_identical_cluster(balances: &[u128]) -> (u128, usize) {
+    let mut counts: HashMap<u128, usize> = HashMap::new();
+    for &balance in balances {
+        *counts.entry(balance).or_default() += 1;
+    }
+    counts.into_iter()
+        .max_by_key(|&(balance, count)| (count, balance))
+        .unwrap_or_default()
+}
+
+/// Fraction of balances that are whole multiples of `ROUND_UNIT` tokens
+fn round_share(balances: &[u128], decimals: u8) -> f64 {
+    let unit = 10u128.checked_pow(decimals as u32)
+        .and_then(|one_token| one_token.checked_mul(ROUND_UNIT));
+    let Some(unit) = unit else {
+        return 0.0;
+    };
+    let round = balances.iter().filter(|&&raw| raw % unit == 0).count();
+    round as f64 / balances.len() as f64
+}
+
+/// Longest run of distinct sorted balances with a constant non-zero step
+fn longest_sequential_run(balances: &[u128]) -> usize {
+    let mut sorted = balances.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let mut longest = sorted.len().min(1);
+    let mut run = longest;
+    let mut step = None;
+    for pair in sorted.windows(2) {
+        let diff = pair[1] - pair[0];
+        if step == Some(diff) {
+            run += 1;
+        } else {
+            step = Some(diff);
+            run = 2;
+        }
+        longest = longest.max(run);
+    }
+    longest
+}
+
+fn unknown_result(unknown_reason: UnknownReason, reason: &str) -> CheckResult {
+    CheckResult {
+        id: "distribution_organic".to_string(),
+        label: "Organic distribution".to_string(),
+        category: "distribution".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::Low,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": reason
+        }),
+        weight: 5,
+        score_component: None,
+        confidence: confidence::ESTIMATE,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts_with_balances(balances: &[&str]) -> TokenFacts {
+        TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(6),
+                ..Default::default()
+            }),
+            holders: Some(HolderInfo {
+                top1_pct: None,
+                top5_pct: None,
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: balances.iter()
+                    .enumerate()
+                    .map(|(i, raw)| HolderBalance {
+                        address: format!("wallet{}", i),
+                        balance_raw: raw.to_string(),
+                        balance: None,
+                        pct_of_supply: None,
+                        kind: HolderKind::Wallet,
+                        label: None,
+                    })
+                    .collect(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_identical_balance_cluster_flagged() {
+        let result = check_distribution_organic(&facts_with_balances(&[
+            "98213377102", "5000123456", "5000123456", "5000123456", "5000123456", "731852004",
+        ]));
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.severity, Severity::Low);
+        assert_eq!(result.evidence["signals"], json!(["identical_balances"]));
+        assert_eq!(result.evidence["largest_identical_cluster"]["wallets"], 4);
+    }
+
+    #[test]
+    fn test_round_and_sequential_balances_flagged() {
+        // 10k, 20k, 30k, 40k, 50k whole tokens at 6 decimals
+        let result = check_distribution_organic(&facts_with_balances(&[
+            "10000000000", "20000000000", "30000000000", "40000000000", "50000000000",
+        ]));
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.evidence["signals"], json!(["round_balances", "sequential_balances"]));
+        assert_eq!(result.evidence["round_share"], 1.0);
+        assert_eq!(result.evidence["longest_sequential_run"], 5);
+    }
+
+    #[test]
+    fn test_varied_balances_pass() {
+        let result = check_distribution_organic(&facts_with_balances(&[
+            "98213377102", "41778120093", "12500318877", "7331002541", "2210984410", "731852004",
+        ]));
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.score_component, Some(100));
+        assert_eq!(result.evidence["signals"], json!([]));
+    }
+
+    #[test]
+    fn test_too_few_wallets_unknown() {
+        let result = check_distribution_organic(&facts_with_balances(&["100", "100"]));
+
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::DataMissing));
+    }
+}
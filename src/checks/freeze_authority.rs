@@ -1,31 +1,45 @@
 use crate::types::*;
+use super::confidence;
 use serde_json::json;
 
 pub fn check_freeze_authority_disabled(facts: &TokenFacts) -> CheckResult {
     let authorities = match &facts.authorities {
         Some(auth) => auth,
-        None => return unknown_result(),
+        None => return unknown_result(facts.missing_reason("authorities")),
     };
     
     let is_disabled = authorities.freeze_authority.is_none();
+    let multisig = authorities.freeze_authority_multisig.as_ref()
+        .filter(|_| !is_disabled);
+    let threshold_protected = multisig.is_some_and(|m| m.is_threshold_protected());
     
     CheckResult {
         id: "freeze_authority_disabled".to_string(),
         label: "Freeze authority disabled".to_string(),
         category: "supply_control".to_string(),
         status: if is_disabled { CheckStatus::Pass } else { CheckStatus::Fail },
-        severity: Severity::High,
+        severity: if threshold_protected { Severity::Medium } else { Severity::High },
         value: json!(is_disabled),
         evidence: json!({
             "source": "provider",
             "freeze_authority": authorities.freeze_authority,
+            "m_of_n": multisig.map(|m| m.m_of_n()),
         }),
         weight: 20,
-        score_component: if is_disabled { Some(100) } else { Some(0) },
+        score_component: if is_disabled {
+            Some(100)
+        } else if threshold_protected {
+            Some(25)
+        } else {
+            Some(0)
+        },
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: None,
+        learn_more_url: None,
     }
 }
 
-fn unknown_result() -> CheckResult {
+fn unknown_result(unknown_reason: UnknownReason) -> CheckResult {
     CheckResult {
         id: "freeze_authority_disabled".to_string(),
         label: "Freeze authority disabled".to_string(),
@@ -39,6 +53,9 @@ fn unknown_result() -> CheckResult {
         }),
         weight: 20,
         score_component: None,
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
     }
 }
 
@@ -54,11 +71,13 @@ mod tests {
                 freeze_authority: None,
                 owner: None,
                 mint_mutable: Some(false),
+                ..Default::default()
             }),
             metadata: None,
             supply: None,
             holders: None,
             creation: None,
+            ..Default::default()
         };
         
         let result = check_freeze_authority_disabled(&facts);
@@ -76,11 +95,13 @@ mod tests {
                 freeze_authority: Some("SomeKey123".to_string()),
                 owner: None,
                 mint_mutable: Some(false),
+                ..Default::default()
             }),
             metadata: None,
             supply: None,
             holders: None,
             creation: None,
+            ..Default::default()
         };
         
         let result = check_freeze_authority_disabled(&facts);
@@ -88,4 +109,24 @@ mod tests {
         assert!(matches!(result.status, CheckStatus::Fail));
         assert_eq!(result.score_component, Some(0));
     }
+
+    #[test]
+    fn test_freeze_authority_multisig_severity() {
+        let multisig = |m: u8, n: u8| TokenFacts {
+            authorities: Some(AuthorityInfo {
+                freeze_authority: Some("MultisigKey".to_string()),
+                freeze_authority_multisig: Some(MultisigInfo { m, n }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let protected = check_freeze_authority_disabled(&multisig(3, 5));
+        assert_eq!(protected.status, CheckStatus::Fail);
+        assert_eq!(protected.severity, Severity::Medium);
+        assert_eq!(protected.evidence["m_of_n"], "3-of-5");
+
+        let unprotected = check_freeze_authority_disabled(&multisig(1, 1));
+        assert_eq!(unprotected.severity, Severity::High);
+    }
 }
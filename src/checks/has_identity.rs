@@ -0,0 +1,125 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+pub fn check_has_identity(facts: &TokenFacts) -> CheckResult {
+    check_has_identity_with_severity(facts, Severity::Low)
+}
+
+/// A legitimate project almost always names its token. Fails when the
+/// metadata carries neither a name nor a symbol; `Unknown` when metadata
+/// could not be fetched, or the provider does not resolve names at all.
+pub fn check_has_identity_with_severity(facts: &TokenFacts, severity: Severity) -> CheckResult {
+    let metadata = match &facts.metadata {
+        Some(m) => m,
+        None => return unknown_result(facts.missing_reason("metadata"), severity, "metadata unavailable"),
+    };
+    let present = |field: &Option<String>| field.as_deref().map(str::trim).filter(|s| !s.is_empty()).map(str::to_string);
+    let name = present(&metadata.name);
+    let symbol = present(&metadata.symbol);
+    let has_identity = name.is_some() || symbol.is_some();
+
+    // Absent from a provider that never reports names says nothing
+    if !has_identity && facts.unsupported.iter().any(|f| f == "identity") {
+        return unknown_result(UnknownReason::Unsupported, severity, "provider does not resolve token names");
+    }
+
+    CheckResult {
+        id: "has_identity".to_string(),
+        label: "Token has a name or symbol".to_string(),
+        category: "interface".to_string(),
+        status: if has_identity { CheckStatus::Pass } else { CheckStatus::Fail },
+        severity,
+        value: json!(has_identity),
+        evidence: json!({
+            "source": "provider",
+            "name": name,
+            "symbol": symbol,
+            "image_uri": metadata.image_uri,
+        }),
+        weight: 5,
+        score_component: if has_identity { Some(100) } else { Some(0) },
+        confidence: confidence::CONTRACT_CALL,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason, severity: Severity, reason: &str) -> CheckResult {
+    CheckResult {
+        id: "has_identity".to_string(),
+        label: "Token has a name or symbol".to_string(),
+        category: "interface".to_string(),
+        status: CheckStatus::Unknown,
+        severity,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": reason
+        }),
+        weight: 5,
+        score_component: None,
+        confidence: confidence::CONTRACT_CALL,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts(name: Option<&str>, symbol: Option<&str>) -> TokenFacts {
+        TokenFacts {
+            metadata: Some(Metadata {
+                name: name.map(str::to_string),
+                symbol: symbol.map(str::to_string),
+                decimals: Some(18),
+                standard: TokenStandard::Erc20,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_named_token_passes() {
+        let result = check_has_identity(&facts(Some("USD Coin"), Some("USDC")));
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.evidence["symbol"], "USDC");
+
+        let symbol_only = check_has_identity(&facts(None, Some("ABC")));
+        assert_eq!(symbol_only.status, CheckStatus::Pass);
+    }
+
+    #[test]
+    fn test_nameless_token_fails_at_configured_severity() {
+        let result = check_has_identity(&facts(None, Some("  ")));
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.severity, Severity::Low);
+
+        let medium = check_has_identity_with_severity(&facts(None, None), Severity::Medium);
+        assert_eq!(medium.status, CheckStatus::Fail);
+        assert_eq!(medium.severity, Severity::Medium);
+    }
+
+    #[test]
+    fn test_failed_metadata_fetch_is_unknown() {
+        let facts = TokenFacts {
+            fetch_errors: vec!["metadata".to_string()],
+            ..Default::default()
+        };
+        let result = check_has_identity(&facts);
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::ProviderError));
+    }
+
+    #[test]
+    fn test_unresolved_names_are_unknown() {
+        let mut facts = facts(None, None);
+        facts.unsupported = vec!["identity".to_string()];
+        let result = check_has_identity(&facts);
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::Unsupported));
+    }
+}
@@ -1,20 +1,75 @@
 use crate::types::*;
+use super::confidence;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 
+/// Slack for float rounding when comparing provider percentages
+const PCT_TOLERANCE: f64 = 1e-6;
+
+/// Supply that holder shares are measured against
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SupplyBasis {
+    /// Total supply, as providers report shares
+    #[default]
+    Total,
+    /// Circulating supply: locked or treasury supply is left out, so the
+    /// same balances read as more concentrated
+    Circulating,
+}
+
 pub fn check_holder_concentration(facts: &TokenFacts) -> CheckResult {
+    check_holder_concentration_with_basis(facts, SupplyBasis::Total)
+}
+
+/// `check_holder_concentration` scoring shares against `basis`. Falls back
+/// to total supply when circulating supply is not reported. Evidence
+/// carries the shares against both.
+pub fn check_holder_concentration_with_basis(facts: &TokenFacts, basis: SupplyBasis) -> CheckResult {
     let holders = match &facts.holders {
         Some(h) => h,
-        None => return unknown_result(),
+        None => return unknown_result(facts.missing_reason("holders"), "holder data unavailable"),
     };
     
     let (top1_pct, top5_pct) = match (holders.top1_pct, holders.top5_pct) {
         (Some(t1), Some(t5)) => (t1, t5),
-        _ => return unknown_result(),
+        _ => return unknown_result(UnknownReason::DataMissing, "holder data unavailable"),
+    };
+
+    // Exchange wallets and pools hold other people's tokens, not a whale's:
+    // re-rank without them when their shares are known
+    let excluded: Vec<&HolderBalance> = holders.top_holders.iter()
+        .filter(|h| is_pooled_or_custodial(h))
+        .collect();
+    let reranked = if excluded.is_empty() {
+        None
+    } else {
+        shares_without_excluded(&holders.top_holders)
+    };
+    // Holders count as excluded only when the re-ranked shares are scored
+    let excluded = if reranked.is_some() { excluded } else { Vec::new() };
+    let (top1_pct, top5_pct) = reranked.unwrap_or((top1_pct, top5_pct));
+
+    // Implausible shares would still score; refuse to grade them
+    if let Err(problem) = validate_percentages(top1_pct, top5_pct) {
+        return unknown_result(UnknownReason::ProviderError, &problem);
+    }
+
+    // Provider shares are of total supply; rescale them to circulating
+    let circulating_shares = circulating_scale(facts)
+        .map(|scale| ((top1_pct * scale).min(100.0), (top5_pct * scale).min(100.0)));
+    let (basis, top1_pct, top5_pct, total_shares) = match (basis, circulating_shares) {
+        (SupplyBasis::Circulating, Some((c1, c5))) => {
+            (SupplyBasis::Circulating, c1, c5, (top1_pct, top5_pct))
+        }
+        _ => (SupplyBasis::Total, top1_pct, top5_pct, (top1_pct, top5_pct)),
     };
     
     let score1 = score_top1(top1_pct);
     let score5 = score_top5(top5_pct);
-    let combined = ((score1 + score5) / 2.0).round() as u8;
+    // Few holders in total means little decentralization, whatever the top-N shares
+    let count_factor = holders.holder_count.map(holder_count_factor).unwrap_or(1.0);
+    let combined = ((score1 + score5) / 2.0 * count_factor).round() as u8;
     
     let status = if combined >= 50 {
         CheckStatus::Pass
@@ -39,20 +94,84 @@ pub fn check_holder_concentration(facts: &TokenFacts) -> CheckResult {
         value: json!({
             "top1_pct": top1_pct,
             "top5_pct": top5_pct,
+            "holder_count": holders.holder_count,
             "sub_scores": {
                 "top1": score1,
-                "top5": score5
+                "top5": score5,
+                "holder_count_factor": count_factor
             }
         }),
         evidence: json!({
             "source": "provider",
             "top1_pct": top1_pct,
             "top5_pct": top5_pct,
+            "holder_count": holders.holder_count,
+            "excluded_holders": excluded.iter()
+                .map(|h| json!({
+                    "address": h.address,
+                    "label": h.label,
+                    "pct_of_supply": h.pct_of_supply,
+                }))
+                .collect::<Vec<_>>(),
+            "basis": basis,
+            "top1_pct_of_total": total_shares.0,
+            "top5_pct_of_total": total_shares.1,
+            "top1_pct_of_circulating": circulating_shares.map(|(c1, _)| c1),
+            "top5_pct_of_circulating": circulating_shares.map(|(_, c5)| c5),
             "method": "supply-weighted holder distribution"
         }),
         weight: 20,
         score_component: Some(combined),
+        confidence: confidence::ESTIMATE,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+/// Centralized-exchange wallets and liquidity pools
+fn is_pooled_or_custodial(holder: &HolderBalance) -> bool {
+    holder.kind == HolderKind::Lp
+        || holder.label.as_ref().is_some_and(|l| matches!(l.kind, LabelKind::Cex | LabelKind::DexPool))
+}
+
+/// Top-1 and top-5 shares of the remaining holders; None unless every
+/// remaining holder's share is known and at least five remain. A shorter
+/// list would understate the top 5 with holders ranked below it unseen.
+fn shares_without_excluded(top_holders: &[HolderBalance]) -> Option<(f64, f64)> {
+    let mut shares = top_holders.iter()
+        .filter(|h| !is_pooled_or_custodial(h))
+        .map(|h| h.pct_of_supply)
+        .collect::<Option<Vec<f64>>>()?;
+    if shares.len() < 5 {
+        return None;
+    }
+    shares.sort_by(|a, b| b.total_cmp(a));
+    let top1 = shares.first().copied().unwrap_or(0.0);
+    let top5 = shares.iter().take(5).sum();
+    Some((top1, top5))
+}
+
+/// Factor turning a share of total supply into a share of circulating
+fn circulating_scale(facts: &TokenFacts) -> Option<f64> {
+    let supply = facts.supply.as_ref()?;
+    let (total, circulating) = (supply.total_supply?, supply.circulating_supply?);
+    (total > 0.0 && circulating > 0.0 && circulating <= total).then(|| total / circulating)
+}
+
+/// Both shares must be within 0..=100 and the top 5 cannot hold less than the top 1
+fn validate_percentages(top1_pct: f64, top5_pct: f64) -> Result<(), String> {
+    for (name, pct) in [("top1_pct", top1_pct), ("top5_pct", top5_pct)] {
+        if !(0.0..=100.0 + PCT_TOLERANCE).contains(&pct) {
+            return Err(format!("implausible {} from provider: {}", name, pct));
+        }
+    }
+    if top5_pct + PCT_TOLERANCE < top1_pct {
+        return Err(format!(
+            "implausible holder shares from provider: top5_pct {} < top1_pct {}",
+            top5_pct, top1_pct
+        ));
     }
+    Ok(())
 }
 
 fn score_top1(pct: f64) -> f64 {
@@ -83,7 +202,14 @@ fn score_top5(pct: f64) -> f64 {
     }
 }
 
-fn lerp(x: f64, x0: f64, x1: f64, y0: f64, y1: f64) -> f64 {
+/// Score multiplier for the total holder count: 0.5 at 50 holders or fewer,
+/// rising log-linearly to 1.0 at 1,000+
+fn holder_count_factor(count: u64) -> f64 {
+    let log_count = (count.max(1) as f64).log10();
+    lerp(log_count, 50_f64.log10(), 1_000_f64.log10(), 0.5, 1.0)
+}
+
+pub(crate) fn lerp(x: f64, x0: f64, x1: f64, y0: f64, y1: f64) -> f64 {
     if x <= x0 {
         return y0;
     }
@@ -93,7 +219,7 @@ fn lerp(x: f64, x0: f64, x1: f64, y0: f64, y1: f64) -> f64 {
     y0 + (x - x0) * (y1 - y0) / (x1 - x0)
 }
 
-fn unknown_result() -> CheckResult {
+fn unknown_result(unknown_reason: UnknownReason, reason: &str) -> CheckResult {
     CheckResult {
         id: "holder_concentration".to_string(),
         label: "Holder concentration".to_string(),
@@ -103,10 +229,13 @@ fn unknown_result() -> CheckResult {
         value: json!(null),
         evidence: json!({
             "source": "provider",
-            "error": "holder data unavailable"
+            "error": reason
         }),
         weight: 20,
         score_component: None,
+        confidence: confidence::ESTIMATE,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
     }
 }
 
@@ -120,12 +249,15 @@ mod tests {
             holders: Some(HolderInfo {
                 top1_pct: Some(8.5),
                 top5_pct: Some(28.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
                 top_holders: vec![],
             }),
             metadata: None,
             supply: None,
             authorities: None,
             creation: None,
+            ..Default::default()
         };
         
         let result = check_holder_concentration(&facts);
@@ -140,12 +272,15 @@ mod tests {
             holders: Some(HolderInfo {
                 top1_pct: Some(62.0),
                 top5_pct: Some(88.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
                 top_holders: vec![],
             }),
             metadata: None,
             supply: None,
             authorities: None,
             creation: None,
+            ..Default::default()
         };
         
         let result = check_holder_concentration(&facts);
@@ -154,4 +289,179 @@ mod tests {
         assert!(matches!(result.severity, Severity::High));
         assert!(result.score_component.unwrap() < 30);
     }
+
+    fn facts_with_count(holder_count: Option<u64>) -> TokenFacts {
+        TokenFacts {
+            holders: Some(HolderInfo {
+                top1_pct: Some(8.0),
+                top5_pct: Some(25.0),
+                holder_count,
+                unlisted_balance_raw: None,
+                top_holders: vec![],
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_low_holder_count_drags_score() {
+        let few = check_holder_concentration(&facts_with_count(Some(50)));
+        let many = check_holder_concentration(&facts_with_count(Some(50_000)));
+        let uncounted = check_holder_concentration(&facts_with_count(None));
+
+        assert_eq!(many.score_component, Some(100));
+        assert_eq!(few.score_component, Some(50));
+        assert_eq!(few.value["holder_count"], 50);
+        assert_eq!(few.evidence["holder_count"], 50);
+
+        // Without a count the score is unchanged
+        assert_eq!(uncounted.score_component, Some(100));
+    }
+    
+    fn facts_with_shares(top1_pct: f64, top5_pct: f64) -> TokenFacts {
+        TokenFacts {
+            holders: Some(HolderInfo {
+                top1_pct: Some(top1_pct),
+                top5_pct: Some(top5_pct),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![],
+            }),
+            ..Default::default()
+        }
+    }
+    
+    #[test]
+    fn test_top5_below_top1_unknown() {
+        let result = check_holder_concentration(&facts_with_shares(40.0, 25.0));
+        
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::ProviderError));
+        assert!(result.evidence["error"].as_str().unwrap().contains("top5_pct 25 < top1_pct 40"));
+    }
+    
+    #[test]
+    fn test_over_100_pct_unknown() {
+        let result = check_holder_concentration(&facts_with_shares(20.0, 150.0));
+        
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.score_component, None);
+        assert!(result.evidence["error"].as_str().unwrap().contains("top5_pct"));
+    }
+    
+    #[test]
+    fn test_negative_pct_unknown() {
+        let result = check_holder_concentration(&facts_with_shares(-5.0, 30.0));
+        
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert!(result.evidence["error"].as_str().unwrap().contains("top1_pct"));
+    }
+
+    #[test]
+    fn test_circulating_basis_scores_lower() {
+        let facts = TokenFacts {
+            supply: Some(SupplyInfo {
+                total_supply_raw: None,
+                total_supply: Some(1_000_000.0),
+                circulating_supply: Some(500_000.0),
+            }),
+            ..facts_with_shares(15.0, 40.0)
+        };
+
+        let total = check_holder_concentration_with_basis(&facts, SupplyBasis::Total);
+        let circulating = check_holder_concentration_with_basis(&facts, SupplyBasis::Circulating);
+
+        assert!(circulating.score_component.unwrap() < total.score_component.unwrap());
+        assert_eq!(total.evidence["basis"], "total");
+        assert_eq!(circulating.evidence["basis"], "circulating");
+        // Both readings are reported whichever was scored
+        for result in [&total, &circulating] {
+            assert_eq!(result.evidence["top1_pct_of_total"], 15.0);
+            assert_eq!(result.evidence["top1_pct_of_circulating"], 30.0);
+        }
+        assert_eq!(circulating.evidence["top1_pct"], 30.0);
+    }
+
+    #[test]
+    fn test_circulating_basis_without_circulating_supply_uses_total() {
+        let result = check_holder_concentration_with_basis(&facts_with_shares(15.0, 40.0), SupplyBasis::Circulating);
+
+        assert_eq!(result.evidence["basis"], "total");
+        assert!(result.evidence["top1_pct_of_circulating"].is_null());
+        assert_eq!(result.score_component, check_holder_concentration(&facts_with_shares(15.0, 40.0)).score_component);
+    }
+
+    #[test]
+    fn test_labeled_cex_holder_excluded_from_concentration() {
+        let holder = |address: &str, pct: f64, label: Option<LabelKind>| HolderBalance {
+            address: address.to_string(),
+            balance_raw: "0".to_string(),
+            balance: None,
+            pct_of_supply: Some(pct),
+            kind: HolderKind::Unclassified,
+            label: label.map(|kind| AddressLabel { kind, name: Some("Binance".to_string()) }),
+        };
+        let facts = |cex_label: Option<LabelKind>| TokenFacts {
+            holders: Some(HolderInfo {
+                top1_pct: Some(40.0),
+                top5_pct: Some(60.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![
+                    holder("exchange", 40.0, cex_label),
+                    holder("a", 8.0, None),
+                    holder("b", 5.0, None),
+                    holder("c", 4.0, None),
+                    holder("d", 2.0, None),
+                    holder("e", 1.0, None),
+                ],
+            }),
+            ..Default::default()
+        };
+
+        let unlabeled = check_holder_concentration(&facts(None));
+        let labeled = check_holder_concentration(&facts(Some(LabelKind::Cex)));
+
+        assert_eq!(unlabeled.status, CheckStatus::Fail);
+        assert_eq!(labeled.status, CheckStatus::Pass);
+        assert_eq!(labeled.evidence["top1_pct"], 8.0);
+        assert_eq!(labeled.evidence["top5_pct"], 20.0);
+        assert_eq!(labeled.evidence["excluded_holders"][0]["address"], "exchange");
+        assert_eq!(labeled.evidence["excluded_holders"][0]["label"]["kind"], "cex");
+        assert_eq!(unlabeled.evidence["excluded_holders"], json!([]));
+    }
+
+    #[test]
+    fn test_exclusion_not_reported_when_too_few_remain() {
+        let holder = |address: &str, pct: f64, label: Option<LabelKind>| HolderBalance {
+            address: address.to_string(),
+            balance_raw: "0".to_string(),
+            balance: None,
+            pct_of_supply: Some(pct),
+            kind: HolderKind::Unclassified,
+            label: label.map(|kind| AddressLabel { kind, name: None }),
+        };
+        let facts = TokenFacts {
+            holders: Some(HolderInfo {
+                top1_pct: Some(40.0),
+                top5_pct: Some(60.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![
+                    holder("exchange", 40.0, Some(LabelKind::Cex)),
+                    holder("a", 8.0, None),
+                    holder("b", 5.0, None),
+                ],
+            }),
+            ..Default::default()
+        };
+
+        let result = check_holder_concentration(&facts);
+
+        // Too few holders left to re-rank: the provider's figures are
+        // scored, exchange included, and the evidence says so
+        assert_eq!(result.evidence["top1_pct"], 40.0);
+        assert_eq!(result.evidence["top5_pct"], 60.0);
+        assert_eq!(result.evidence["excluded_holders"], json!([]));
+    }
 }
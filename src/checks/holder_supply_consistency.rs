@@ -0,0 +1,199 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+/// Largest gap between the holder totals and the supply, as a percent of
+/// supply, still read as consistent
+pub const MAX_DIVERGENCE_PCT: f64 = 5.0;
+
+/// The listed holder balances plus the unlisted remainder should add up to
+/// the total supply. A material gap means the holder data is truncated or
+/// stale, so holder-based results are less trustworthy. Mostly
+/// informational; see `discount_divergent_holders`.
+pub fn check_holder_supply_consistency(facts: &TokenFacts) -> CheckResult {
+    let holders = match &facts.holders {
+        Some(h) => h,
+        None => return unknown_result(facts.missing_reason("holders"), "holder data unavailable"),
+    };
+    if !facts.is_requested(HOLDER_LIST_FACT) {
+        return unknown_result(UnknownReason::NotRequested, HOLDER_LIST_NOT_REQUESTED);
+    }
+    if holders.top_holders.is_empty() {
+        return unknown_result(UnknownReason::DataMissing, "holder list unavailable");
+    }
+    let unlisted = match holders.unlisted_balance_raw.as_deref().and_then(|raw| raw.parse::<u128>().ok()) {
+        Some(raw) => raw,
+        None => return unknown_result(UnknownReason::DataMissing, "balance outside the listed holders unavailable"),
+    };
+    let supply = match &facts.supply {
+        Some(supply) => supply,
+        None => return unknown_result(facts.missing_reason("supply"), "total supply unavailable"),
+    };
+    let total_supply = match supply.raw_quantity().filter(|&raw| raw > 0) {
+        Some(raw) => raw,
+        None => return unknown_result(UnknownReason::DataMissing, "raw total supply unavailable"),
+    };
+
+    // A skipped balance would understate the listed total and read as a gap
+    let listed: u128 = match holders.top_holders.iter()
+        .map(|h| h.balance_raw.parse::<u128>().ok())
+        .collect::<Option<Vec<u128>>>()
+    {
+        Some(balances) => balances.into_iter().fold(0, u128::saturating_add),
+        None => return unknown_result(UnknownReason::ProviderError, "unreadable holder balance"),
+    };
+    let accounted = listed.saturating_add(unlisted);
+    let divergence_pct = accounted.abs_diff(total_supply) as f64 / total_supply as f64 * 100.0;
+    let consistent = divergence_pct <= MAX_DIVERGENCE_PCT;
+
+    CheckResult {
+        id: "holder_supply_consistency".to_string(),
+        label: "Holder totals match supply".to_string(),
+        category: "distribution".to_string(),
+        status: if consistent { CheckStatus::Pass } else { CheckStatus::Fail },
+        severity: Severity::Low,
+        value: json!(consistent),
+        evidence: json!({
+            "source": "provider",
+            "method": "listed + unlisted holder balances vs total supply",
+            "total_supply_raw": total_supply.to_string(),
+            "listed_balance_raw": listed.to_string(),
+            "unlisted_balance_raw": unlisted.to_string(),
+            "listed_holders": holders.top_holders.len(),
+            "divergence_pct": divergence_pct,
+            "max_divergence_pct": MAX_DIVERGENCE_PCT,
+        }),
+        weight: 3,
+        score_component: if consistent { Some(100) } else { Some(0) },
+        confidence: confidence::PROVIDER_REPORTED,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+/// Lower `holder_concentration`'s confidence to `confidence::INFERRED`
+/// when `holder_supply_consistency` failed: its shares were computed from
+/// holder data that does not add up.
+pub fn discount_divergent_holders(checks: &mut [CheckResult]) {
+    let divergent = checks.iter()
+        .any(|c| c.id == "holder_supply_consistency" && c.status == CheckStatus::Fail);
+    if !divergent {
+        return;
+    }
+    for check in checks.iter_mut().filter(|c| c.id == "holder_concentration") {
+        check.confidence = check.confidence.min(confidence::INFERRED);
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason, reason: &str) -> CheckResult {
+    CheckResult {
+        id: "holder_supply_consistency".to_string(),
+        label: "Holder totals match supply".to_string(),
+        category: "distribution".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::Low,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": reason
+        }),
+        weight: 3,
+        score_component: None,
+        confidence: confidence::PROVIDER_REPORTED,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::check_holder_concentration;
+
+    fn facts(balances: &[&str], unlisted: &str, total_supply: &str) -> TokenFacts {
+        TokenFacts {
+            supply: Some(SupplyInfo {
+                total_supply_raw: Some(total_supply.to_string()),
+                total_supply: None,
+                circulating_supply: None,
+            }),
+            holders: Some(HolderInfo {
+                top1_pct: Some(40.0),
+                top5_pct: Some(60.0),
+                holder_count: None,
+                unlisted_balance_raw: Some(unlisted.to_string()),
+                top_holders: balances.iter()
+                    .enumerate()
+                    .map(|(i, raw)| HolderBalance {
+                        address: format!("wallet{}", i),
+                        balance_raw: raw.to_string(),
+                        balance: None,
+                        pct_of_supply: None,
+                        kind: HolderKind::Wallet,
+                        label: None,
+                    })
+                    .collect(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_consistent_totals_pass() {
+        let facts = facts(&["400000", "150000", "50000"], "400000", "1000000");
+        let result = check_holder_supply_consistency(&facts);
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.severity, Severity::Low);
+        assert_eq!(result.evidence["divergence_pct"], 0.0);
+
+        let mut checks = vec![check_holder_concentration(&facts), result];
+        let before = checks[0].confidence;
+        discount_divergent_holders(&mut checks);
+        assert_eq!(checks[0].confidence, before);
+    }
+
+    #[test]
+    fn test_short_holder_list_fails_and_discounts_concentration() {
+        // A truncated list: a third of the supply is nowhere to be seen
+        let facts = facts(&["400000", "150000", "50000"], "70000", "1000000");
+        let result = check_holder_supply_consistency(&facts);
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.evidence["listed_balance_raw"], "600000");
+        assert!((result.evidence["divergence_pct"].as_f64().unwrap() - 33.0).abs() < 1e-9);
+
+        let mut checks = vec![check_holder_concentration(&facts), result];
+        discount_divergent_holders(&mut checks);
+        assert_eq!(checks[0].confidence, confidence::INFERRED);
+    }
+
+    #[test]
+    fn test_hex_total_supply_is_read() {
+        // EVM providers keep totalSupply() as the node sent it
+        let facts = facts(&["400000", "150000", "50000"], "400000", "0xf4240");
+        let result = check_holder_supply_consistency(&facts);
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.evidence["total_supply_raw"], "1000000");
+    }
+
+    #[test]
+    fn test_unreadable_balance_is_unknown() {
+        let facts = facts(&["400000", "n/a", "50000"], "70000", "1000000");
+        let result = check_holder_supply_consistency(&facts);
+
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::ProviderError));
+    }
+
+    #[test]
+    fn test_unknown_without_unlisted_balance() {
+        let mut facts = facts(&["400000"], "0", "1000000");
+        facts.holders.as_mut().unwrap().unlisted_balance_raw = None;
+
+        let result = check_holder_supply_consistency(&facts);
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::DataMissing));
+    }
+}
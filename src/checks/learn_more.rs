@@ -0,0 +1,47 @@
+// src/checks/learn_more.rs
+//
+// Reference pages explaining what each check looks at, attached to results
+// as `learn_more_url` for "why does this matter?" links. Checks without an
+// authoritative page (the distribution heuristics) carry none.
+
+const SPL_TOKEN: &str = "https://spl.solana.com/token";
+const TOKEN_2022: &str = "https://spl.solana.com/token-2022";
+const TOKEN_2022_EXTENSIONS: &str = "https://spl.solana.com/token-2022/extensions";
+const SOLANA_ACCOUNTS: &str = "https://solana.com/docs/core/accounts";
+const METAPLEX_TOKEN_METADATA: &str = "https://developers.metaplex.com/token-metadata";
+const ERC20: &str = "https://eips.ethereum.org/EIPS/eip-20";
+const OZ_OWNABLE: &str = "https://docs.openzeppelin.com/contracts/5.x/api/access#Ownable";
+const OZ_ERC20_SUPPLY: &str = "https://docs.openzeppelin.com/contracts/5.x/erc20-supply";
+const SAFE: &str = "https://docs.safe.global/";
+
+/// Reference page for `check_id` on canonical `chain`
+pub fn learn_more_url(check_id: &str, chain: &str) -> Option<&'static str> {
+    let solana = chain == "solana";
+    let url = match check_id {
+        "mint_authority_disabled" | "freeze_authority_disabled" | "authority_separation"
+            | "mint_initialized" => SPL_TOKEN,
+        "default_state_unfrozen" => TOKEN_2022_EXTENSIONS,
+        "rent_exempt" => SOLANA_ACCOUNTS,
+        "ownership_renounced" => OZ_OWNABLE,
+        "supply_fixed" => OZ_ERC20_SUPPLY,
+        "standard_sanity" => if solana { TOKEN_2022 } else { ERC20 },
+        "has_identity" => if solana { METAPLEX_TOKEN_METADATA } else { ERC20 },
+        "unit_spoofing" => if solana { SPL_TOKEN } else { ERC20 },
+        "no_recent_inflation" => if solana { SPL_TOKEN } else { OZ_ERC20_SUPPLY },
+        "authority_is_program" => if solana { SPL_TOKEN } else { SAFE },
+        _ => return None,
+    };
+    Some(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_follows_chain() {
+        assert_eq!(learn_more_url("has_identity", "solana"), Some(METAPLEX_TOKEN_METADATA));
+        assert_eq!(learn_more_url("has_identity", "base"), Some(ERC20));
+        assert_eq!(learn_more_url("holder_concentration", "solana"), None);
+    }
+}
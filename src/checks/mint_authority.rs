@@ -1,31 +1,45 @@
 use crate::types::*;
+use super::confidence;
 use serde_json::json;
 
 pub fn check_mint_authority_disabled(facts: &TokenFacts) -> CheckResult {
     let authorities = match &facts.authorities {
         Some(auth) => auth,
-        None => return unknown_result(),
+        None => return unknown_result(facts.missing_reason("authorities")),
     };
     
     let is_disabled = authorities.mint_authority.is_none();
+    let multisig = authorities.mint_authority_multisig.as_ref()
+        .filter(|_| !is_disabled);
+    let threshold_protected = multisig.is_some_and(|m| m.is_threshold_protected());
     
     CheckResult {
         id: "mint_authority_disabled".to_string(),
         label: "Mint authority disabled".to_string(),
         category: "supply_control".to_string(),
         status: if is_disabled { CheckStatus::Pass } else { CheckStatus::Fail },
-        severity: Severity::Critical,
+        severity: if threshold_protected { Severity::High } else { Severity::Critical },
         value: json!(is_disabled),
         evidence: json!({
             "source": "provider",
             "mint_authority": authorities.mint_authority,
+            "m_of_n": multisig.map(|m| m.m_of_n()),
         }),
         weight: 25,
-        score_component: if is_disabled { Some(100) } else { Some(0) },
+        score_component: if is_disabled {
+            Some(100)
+        } else if threshold_protected {
+            Some(25)
+        } else {
+            Some(0)
+        },
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: None,
+        learn_more_url: None,
     }
 }
 
-fn unknown_result() -> CheckResult {
+fn unknown_result(unknown_reason: UnknownReason) -> CheckResult {
     CheckResult {
         id: "mint_authority_disabled".to_string(),
         label: "Mint authority disabled".to_string(),
@@ -39,6 +53,9 @@ fn unknown_result() -> CheckResult {
         }),
         weight: 25,
         score_component: None,
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
     }
 }
 
@@ -54,11 +71,13 @@ mod tests {
                 freeze_authority: None,
                 owner: None,
                 mint_mutable: Some(false),
+                ..Default::default()
             }),
             metadata: None,
             supply: None,
             holders: None,
             creation: None,
+            ..Default::default()
         };
         
         let result = check_mint_authority_disabled(&facts);
@@ -76,11 +95,13 @@ mod tests {
                 freeze_authority: None,
                 owner: None,
                 mint_mutable: Some(true),
+                ..Default::default()
             }),
             metadata: None,
             supply: None,
             holders: None,
             creation: None,
+            ..Default::default()
         };
         
         let result = check_mint_authority_disabled(&facts);
@@ -98,6 +119,7 @@ mod tests {
             supply: None,
             holders: None,
             creation: None,
+            ..Default::default()
         };
         
         let result = check_mint_authority_disabled(&facts);
@@ -105,4 +127,25 @@ mod tests {
         assert!(matches!(result.status, CheckStatus::Unknown));
         assert_eq!(result.score_component, None);
     }
+
+    #[test]
+    fn test_mint_authority_multisig_severity() {
+        let multisig = |m: u8, n: u8| TokenFacts {
+            authorities: Some(AuthorityInfo {
+                mint_authority: Some("MultisigKey".to_string()),
+                mint_authority_multisig: Some(MultisigInfo { m, n }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let protected = check_mint_authority_disabled(&multisig(3, 5));
+        assert_eq!(protected.status, CheckStatus::Fail);
+        assert_eq!(protected.severity, Severity::High);
+        assert_eq!(protected.evidence["m_of_n"], "3-of-5");
+
+        let unprotected = check_mint_authority_disabled(&multisig(2, 10));
+        assert_eq!(unprotected.severity, Severity::Critical);
+        assert_eq!(unprotected.evidence["m_of_n"], "2-of-10");
+    }
 }
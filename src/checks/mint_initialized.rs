@@ -0,0 +1,95 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+pub fn check_mint_initialized(facts: &TokenFacts) -> CheckResult {
+    let metadata = match &facts.metadata {
+        Some(m) => m,
+        None => return unknown_result(facts.missing_reason("metadata"), "metadata unavailable"),
+    };
+
+    let is_initialized = match metadata.is_initialized {
+        Some(flag) => flag,
+        None => return unknown_result(UnknownReason::DataMissing, "mint initialization flag unavailable"),
+    };
+
+    CheckResult {
+        id: "mint_initialized".to_string(),
+        label: "Mint initialized".to_string(),
+        category: "interface".to_string(),
+        status: if is_initialized { CheckStatus::Pass } else { CheckStatus::Fail },
+        severity: Severity::High,
+        value: json!(is_initialized),
+        evidence: json!({
+            "source": "provider",
+            "is_initialized": is_initialized,
+        }),
+        weight: 10,
+        score_component: if is_initialized { Some(100) } else { Some(0) },
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason, reason: &str) -> CheckResult {
+    CheckResult {
+        id: "mint_initialized".to_string(),
+        label: "Mint initialized".to_string(),
+        category: "interface".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::High,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": reason
+        }),
+        weight: 10,
+        score_component: None,
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts_with_flag(is_initialized: Option<bool>) -> TokenFacts {
+        TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                is_initialized,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_initialized_mint_pass() {
+        let result = check_mint_initialized(&facts_with_flag(Some(true)));
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.score_component, Some(100));
+    }
+
+    #[test]
+    fn test_uninitialized_mint_fail() {
+        let result = check_mint_initialized(&facts_with_flag(Some(false)));
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.severity, Severity::High);
+        assert_eq!(result.score_component, Some(0));
+    }
+
+    #[test]
+    fn test_missing_flag_unknown() {
+        let result = check_mint_initialized(&facts_with_flag(None));
+
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.score_component, None);
+    }
+}
@@ -1,16 +1,62 @@
 // src/checks/mod.rs
 
+pub mod confidence;
 pub mod mint_authority;
 pub mod holder_concentration;
 pub mod freeze_authority;
 pub mod ownership;
 pub mod token_age;
 pub mod standard_sanity;
+pub mod tax_destination;
+pub mod mint_initialized;
+pub mod authority_stability;
+pub mod residual_supply;
+pub mod bytecode_reputation;
+pub mod circulating_ratio;
+pub mod authority_separation;
+pub mod supply_magnitude;
+pub mod unit_spoofing;
+pub mod recent_inflation;
+pub mod default_account_state;
+pub mod owner_top_holder;
+pub mod supply_fixed;
+pub mod distribution_organic;
+pub mod rent_exempt;
+pub mod holder_supply_consistency;
+pub mod authority_is_program;
+pub mod has_identity;
+pub mod learn_more;
+pub mod registry;
 
 // Re-export check functions
 pub use mint_authority::check_mint_authority_disabled;
-pub use holder_concentration::check_holder_concentration;
+pub use holder_concentration::{
+    check_holder_concentration, check_holder_concentration_with_basis, SupplyBasis,
+};
 pub use freeze_authority::check_freeze_authority_disabled;
-pub use ownership::check_ownership_renounced;
-pub use token_age::check_token_age;
-pub use standard_sanity::check_standard_sanity;
+pub use ownership::{check_ownership_renounced, check_ownership_renounced_with_burn};
+pub use token_age::{
+    check_token_age, check_token_age_with_policy, estimate_creation, rebase_creation,
+    AgeBandPolicy, DefaultAgeBandPolicy,
+};
+pub use standard_sanity::{check_standard_sanity, check_standard_sanity_with_policy};
+pub use tax_destination::check_tax_destination;
+pub use mint_initialized::check_mint_initialized;
+pub use authority_stability::check_authority_stability;
+pub use residual_supply::{check_residual_supply, compute_residual, ResidualSupply};
+pub use bytecode_reputation::check_bytecode_reputation;
+pub use circulating_ratio::check_circulating_ratio;
+pub use authority_separation::check_authority_separation;
+pub use supply_magnitude::{check_supply_magnitude, check_supply_magnitude_with_bounds, SupplyBounds};
+pub use unit_spoofing::check_unit_spoofing;
+pub use recent_inflation::check_no_recent_inflation;
+pub use default_account_state::check_default_state_unfrozen;
+pub use owner_top_holder::check_owner_is_top_holder;
+pub use supply_fixed::check_supply_fixed_evm;
+pub use distribution_organic::check_distribution_organic;
+pub use rent_exempt::check_rent_exempt;
+pub use holder_supply_consistency::{check_holder_supply_consistency, discount_divergent_holders};
+pub use authority_is_program::check_authority_is_program;
+pub use has_identity::{check_has_identity, check_has_identity_with_severity};
+pub use learn_more::learn_more_url;
+pub use registry::{CheckRegistry, CustomCheck, BUILTIN_CHECK_IDS};
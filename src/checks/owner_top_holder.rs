@@ -0,0 +1,160 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+/// Flags a token whose largest holder is also its owner or mint authority:
+/// the key that controls the contract already holds the biggest position,
+/// so concentration and control risk compound
+pub fn check_owner_is_top_holder(facts: &TokenFacts) -> CheckResult {
+    let authorities = match &facts.authorities {
+        Some(auth) => auth,
+        None => return unknown_result(facts.missing_reason("authorities"), "authority data unavailable"),
+    };
+    let holders = match &facts.holders {
+        Some(h) => h,
+        None => return unknown_result(facts.missing_reason("holders"), "holder data unavailable"),
+    };
+    if !facts.is_requested(HOLDER_LIST_FACT) {
+        return unknown_result(UnknownReason::NotRequested, HOLDER_LIST_NOT_REQUESTED);
+    }
+    // Burned supply is nobody's position
+    let top_holder = match holders.top_holders.iter().find(|h| h.kind != HolderKind::Burn) {
+        Some(holder) => holder,
+        None => return unknown_result(UnknownReason::DataMissing, "top holder addresses unavailable"),
+    };
+    // A token account's address is never the owner's; without its owner
+    // there is nothing to compare
+    if top_holder.kind == HolderKind::TokenAccount {
+        return unknown_result(UnknownReason::DataMissing, "top holder is a token account with unresolved owner");
+    }
+
+    let controller = [("owner", &authorities.owner), ("mint_authority", &authorities.mint_authority)]
+        .into_iter()
+        .find_map(|(role, address)| {
+            address.as_deref()
+                .filter(|address| same_address(address, &top_holder.address))
+                .map(|_| role)
+        });
+    let is_match = controller.is_some();
+
+    CheckResult {
+        id: "owner_is_top_holder".to_string(),
+        label: "Owner is not the top holder".to_string(),
+        category: "distribution".to_string(),
+        status: if is_match { CheckStatus::Fail } else { CheckStatus::Pass },
+        severity: Severity::High,
+        value: json!(is_match),
+        evidence: json!({
+            "source": "provider",
+            "top_holder": top_holder.address,
+            "top_holder_pct": top_holder.pct_of_supply,
+            "owner": authorities.owner,
+            "mint_authority": authorities.mint_authority,
+            "matched_role": controller,
+        }),
+        weight: 10,
+        score_component: if is_match { Some(0) } else { Some(100) },
+        confidence: confidence::ESTIMATE,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+/// EVM addresses compare case-insensitively (checksum casing); Solana
+/// base58 addresses are case-sensitive
+fn same_address(a: &str, b: &str) -> bool {
+    let (a, b) = (a.trim(), b.trim());
+    if a.starts_with("0x") && b.starts_with("0x") {
+        a.eq_ignore_ascii_case(b)
+    } else {
+        a == b
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason, reason: &str) -> CheckResult {
+    CheckResult {
+        id: "owner_is_top_holder".to_string(),
+        label: "Owner is not the top holder".to_string(),
+        category: "distribution".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::High,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": reason
+        }),
+        weight: 10,
+        score_component: None,
+        confidence: confidence::ESTIMATE,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts_with(owner: Option<&str>, top_holder: Option<&str>) -> TokenFacts {
+        TokenFacts {
+            authorities: Some(AuthorityInfo {
+                owner: owner.map(|s| s.to_string()),
+                ..Default::default()
+            }),
+            holders: top_holder.map(|address| HolderInfo {
+                top1_pct: Some(40.0),
+                top5_pct: Some(60.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![HolderBalance {
+                    address: address.to_string(),
+                    balance_raw: "400".to_string(),
+                    balance: Some(400.0),
+                    pct_of_supply: Some(40.0),
+                    kind: HolderKind::Wallet,
+                    label: None,
+                }],
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_owner_is_top_holder_flagged() {
+        // Checksum casing differs from the holder listing
+        let result = check_owner_is_top_holder(&facts_with(Some("0xAbCd00"), Some("0xabcd00")));
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.severity, Severity::High);
+        assert_eq!(result.evidence["matched_role"], "owner");
+    }
+
+    #[test]
+    fn test_owner_differs_from_top_holder_passes() {
+        let result = check_owner_is_top_holder(&facts_with(Some("0xowner"), Some("0xwhale")));
+        assert_eq!(result.status, CheckStatus::Pass);
+    }
+
+    #[test]
+    fn test_mint_authority_is_top_holder_flagged() {
+        let mut facts = facts_with(None, Some("DevKey"));
+        facts.authorities.as_mut().unwrap().mint_authority = Some("DevKey".to_string());
+        let result = check_owner_is_top_holder(&facts);
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.evidence["matched_role"], "mint_authority");
+    }
+
+    #[test]
+    fn test_unresolved_token_account_unknown() {
+        let mut facts = facts_with(Some("DevKey"), Some("TokenAccount1"));
+        facts.holders.as_mut().unwrap().top_holders[0].kind = HolderKind::TokenAccount;
+        let result = check_owner_is_top_holder(&facts);
+        assert_eq!(result.status, CheckStatus::Unknown);
+    }
+
+    #[test]
+    fn test_missing_holders_unknown() {
+        let result = check_owner_is_top_holder(&facts_with(Some("0xowner"), None));
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::DataMissing));
+    }
+}
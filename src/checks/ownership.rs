@@ -1,7 +1,17 @@
 use crate::types::*;
+use super::confidence;
+use crate::burn::BurnAddresses;
 use serde_json::json;
 
 pub fn check_ownership_renounced(facts: &TokenFacts) -> CheckResult {
+    check_ownership_renounced_with_burn(facts, &BurnAddresses::default())
+}
+
+/// `check_ownership_renounced` treating ownership moved to any of `burn`'s
+/// EVM addresses as renounced
+pub fn check_ownership_renounced_with_burn(facts: &TokenFacts, burn: &BurnAddresses) -> CheckResult {
+    let is_renounced_owner = |owner: &str| burn.is_burn("evm", owner);
+
     let authorities = match &facts.authorities {
         Some(auth) => auth,
         None => {
@@ -15,20 +25,32 @@ pub fn check_ownership_renounced(facts: &TokenFacts) -> CheckResult {
                 value: json!(null),
                 weight: 20,
                 evidence: json!({"reason": "No authority data available"}),
+                confidence: confidence::CONTRACT_CALL,
+                unknown_reason: Some(facts.missing_reason("authorities")),
+                learn_more_url: None,
             };
         }
     };
 
     let owner = &authorities.owner;
+    let is_renounced = owner.as_deref().map(is_renounced_owner).unwrap_or(true);
+
+    // Two-step ownership: a pending owner can still accept after renouncement
+    let pending_owner = authorities.pending_owner.as_ref()
+        .filter(|p| !is_renounced_owner(p));
+    let reclaimable = is_renounced && pending_owner.is_some();
     
-    let (status, score) = if owner.is_none() {
+    let (status, score) = if reclaimable {
+        (CheckStatus::Fail, Some(50))
+    } else if is_renounced {
         (CheckStatus::Pass, Some(100))
     } else {
         (CheckStatus::Fail, Some(0))
     };
 
-    // CRITICAL: Always Critical severity because ownership control is fundamental
-    let severity = Severity::Critical;
+    // CRITICAL: Critical severity because ownership control is fundamental.
+    // A reclaimable renouncement is a warning, not an active owner.
+    let severity = if reclaimable { Severity::High } else { Severity::Critical };
 
     CheckResult {
         id: "ownership_renounced".to_string(),
@@ -41,8 +63,17 @@ pub fn check_ownership_renounced(facts: &TokenFacts) -> CheckResult {
         weight: 20,
         evidence: json!({
             "owner": owner,
-            "is_renounced": owner.is_none(),
+            "is_renounced": is_renounced,
+            "pending_owner": pending_owner,
+            "reclaim_risk": if reclaimable {
+                Some("Ownership is renounced but a pending owner can still accept the transfer and reclaim control.")
+            } else {
+                None
+            },
         }),
+        confidence: confidence::CONTRACT_CALL,
+        unknown_reason: None,
+        learn_more_url: None,
     }
 }
 
@@ -60,9 +91,11 @@ mod tests {
                 freeze_authority: None,
                 owner: None,
                 mint_mutable: Some(false),
+                ..Default::default()
             }),
             holders: None,
             creation: None,
+            ..Default::default()
         };
 
         let result = check_ownership_renounced(&facts);
@@ -80,9 +113,11 @@ mod tests {
                 freeze_authority: None,
                 owner: None,
                 mint_mutable: Some(false),
+                ..Default::default()
             }),
             holders: None,
             creation: None,
+            ..Default::default()
         };
 
         let result = check_ownership_renounced(&facts);
@@ -99,9 +134,11 @@ mod tests {
                 freeze_authority: None,
                 owner: Some("0x1234567890123456789012345678901234567890".to_string()),
                 mint_mutable: Some(true),
+                ..Default::default()
             }),
             holders: None,
             creation: None,
+            ..Default::default()
         };
 
         let result = check_ownership_renounced(&facts);
@@ -109,4 +146,43 @@ mod tests {
         assert_eq!(result.score_component, Some(0));
         assert_eq!(result.severity, Severity::Critical);
     }
+
+    #[test]
+    fn test_renounced_without_pending_owner_pass() {
+        let facts = TokenFacts {
+            authorities: Some(AuthorityInfo {
+                owner: None,
+                pending_owner: None,
+                mint_mutable: Some(false),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let result = check_ownership_renounced(&facts);
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.score_component, Some(100));
+        assert!(result.evidence["reclaim_risk"].is_null());
+    }
+
+    #[test]
+    fn test_renounced_with_pending_owner_warns() {
+        let pending = "0x1234567890123456789012345678901234567890";
+        let facts = TokenFacts {
+            authorities: Some(AuthorityInfo {
+                owner: None,
+                pending_owner: Some(pending.to_string()),
+                mint_mutable: Some(false),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let result = check_ownership_renounced(&facts);
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.severity, Severity::High);
+        assert_eq!(result.score_component, Some(50));
+        assert_eq!(result.evidence["pending_owner"], pending);
+        assert!(result.evidence["reclaim_risk"].as_str().unwrap().contains("reclaim"));
+    }
 }
@@ -0,0 +1,116 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+/// Supply growth under this fraction is treated as rounding, not minting
+pub const INFLATION_TOLERANCE: f64 = 0.001;
+
+/// Compares current supply with supply at the requested baseline slot/block.
+/// Growth means tokens were minted since then; a decrease (burns) passes.
+pub fn check_no_recent_inflation(facts: &TokenFacts) -> CheckResult {
+    let historical = match &facts.historical_supply {
+        Some(h) => h,
+        None => return unknown_result(facts.missing_reason("historical_supply")),
+    };
+    let current = match facts.supply.as_ref().and_then(|s| s.total_supply) {
+        Some(current) => current,
+        None => return unknown_result(facts.missing_reason("supply")),
+    };
+    let baseline = match historical.supply.total_supply {
+        Some(baseline) => baseline,
+        None => return unknown_result(UnknownReason::DataMissing),
+    };
+
+    let growth_pct = if baseline > 0.0 {
+        (current - baseline) / baseline * 100.0
+    } else if current > 0.0 {
+        100.0
+    } else {
+        0.0
+    };
+    let inflated = growth_pct > INFLATION_TOLERANCE * 100.0;
+
+    CheckResult {
+        id: "no_recent_inflation".to_string(),
+        label: "No recent inflation".to_string(),
+        category: "supply_control".to_string(),
+        status: if inflated { CheckStatus::Fail } else { CheckStatus::Pass },
+        severity: Severity::High,
+        value: json!(growth_pct),
+        evidence: json!({
+            "source": "provider",
+            "baseline_at": historical.at,
+            "baseline_supply": baseline,
+            "current_supply": current,
+            "growth_pct": growth_pct,
+        }),
+        weight: 15,
+        score_component: if inflated { Some(0) } else { Some(100) },
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason) -> CheckResult {
+    CheckResult {
+        id: "no_recent_inflation".to_string(),
+        label: "No recent inflation".to_string(),
+        category: "supply_control".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::High,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": "current or baseline supply unavailable"
+        }),
+        weight: 15,
+        score_component: None,
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn supply(total: f64) -> SupplyInfo {
+        SupplyInfo {
+            total_supply_raw: None,
+            total_supply: Some(total),
+            circulating_supply: None,
+        }
+    }
+
+    fn facts(current: f64, baseline: f64) -> TokenFacts {
+        TokenFacts {
+            supply: Some(supply(current)),
+            historical_supply: Some(HistoricalSupply { at: 100, supply: supply(baseline) }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_minted_since_baseline_fails() {
+        let result = check_no_recent_inflation(&facts(1_500_000.0, 1_000_000.0));
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.evidence["growth_pct"], 50.0);
+        assert_eq!(result.evidence["baseline_at"], 100);
+    }
+
+    #[test]
+    fn test_unchanged_or_burned_passes() {
+        assert_eq!(check_no_recent_inflation(&facts(1_000_000.0, 1_000_000.0)).status, CheckStatus::Pass);
+        assert_eq!(check_no_recent_inflation(&facts(900_000.0, 1_000_000.0)).status, CheckStatus::Pass);
+    }
+
+    #[test]
+    fn test_no_baseline_unknown() {
+        let result = check_no_recent_inflation(&TokenFacts::default());
+
+        assert_eq!(result.status, CheckStatus::Unknown);
+    }
+}
@@ -0,0 +1,173 @@
+// src/checks/registry.rs
+//
+// Checks supplied by library users at runtime (a proprietary risk model,
+// ...), run after the built-in checks of the chains they were registered
+// for. Their results are scored and explained like any other check.
+
+use crate::api::chain::normalize_chain;
+use crate::scoring::MAX_CHECK_WEIGHT;
+use crate::types::{CheckResult, CheckStatus, TokenFacts, UnknownReason};
+use std::sync::Arc;
+
+/// Ids of the built-in checks, which custom checks may not take over
+pub const BUILTIN_CHECK_IDS: [&str; 24] = [
+    "authority_is_program",
+    "authority_separation",
+    "authority_stability",
+    "bytecode_reputation",
+    "circulating_ratio",
+    "default_state_unfrozen",
+    "distribution_organic",
+    "freeze_authority_disabled",
+    "has_identity",
+    "holder_concentration",
+    "holder_supply_consistency",
+    "mint_authority_disabled",
+    "mint_initialized",
+    "no_recent_inflation",
+    "owner_is_top_holder",
+    "ownership_renounced",
+    "rent_exempt",
+    "residual_supply",
+    "standard_sanity",
+    "supply_fixed",
+    "supply_magnitude",
+    "tax_destination",
+    "token_age",
+    "unit_spoofing",
+];
+
+/// A custom check: facts and canonical chain in, result out
+pub type CustomCheck = dyn Fn(&TokenFacts, &str) -> CheckResult + Send + Sync;
+
+struct RegisteredCheck {
+    id: String,
+    /// Canonical chains; empty for every chain
+    chains: Vec<String>,
+    check: Box<CustomCheck>,
+}
+
+/// Custom checks by id, in registration order. Empty by default.
+#[derive(Clone, Default)]
+pub struct CheckRegistry {
+    checks: Vec<Arc<RegisteredCheck>>,
+}
+
+impl std::fmt::Debug for CheckRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CheckRegistry").field("ids", &self.ids()).finish()
+    }
+}
+
+impl CheckRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `check` for `chains` (aliases accepted; empty for every chain).
+    /// Its result is reported under `id`, whatever id it sets itself.
+    /// Registering an id again replaces the earlier check; a built-in
+    /// check's id is an error.
+    pub fn register(&mut self, id: &str, chains: &[&str], check: Box<CustomCheck>) -> Result<&mut Self, String> {
+        if BUILTIN_CHECK_IDS.contains(&id) {
+            return Err(format!("Check id '{}' is taken by a built-in check", id));
+        }
+        let chains = chains.iter()
+            .map(|chain| normalize_chain(chain).unwrap_or_else(|| chain.trim().to_ascii_lowercase()))
+            .collect();
+        self.checks.retain(|registered| registered.id != id);
+        self.checks.push(Arc::new(RegisteredCheck { id: id.to_string(), chains, check }));
+        Ok(self)
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.checks.iter().any(|registered| registered.id == id)
+    }
+
+    pub fn ids(&self) -> Vec<&str> {
+        self.checks.iter().map(|registered| registered.id.as_str()).collect()
+    }
+
+    /// Results of the checks registered for canonical `chain`. A result
+    /// weighing more than `MAX_CHECK_WEIGHT` is replaced with a weightless
+    /// `Unknown`.
+    pub fn run(&self, facts: &TokenFacts, chain: &str) -> Vec<CheckResult> {
+        self.checks.iter()
+            .filter(|registered| registered.chains.is_empty() || registered.chains.iter().any(|c| c == chain))
+            .map(|registered| {
+                let result = CheckResult {
+                    id: registered.id.clone(),
+                    ..(registered.check)(facts, chain)
+                };
+                if result.weight <= MAX_CHECK_WEIGHT {
+                    return result;
+                }
+                CheckResult {
+                    status: CheckStatus::Unknown,
+                    value: serde_json::Value::Null,
+                    evidence: serde_json::json!({
+                        "error": format!("weight {} exceeds the maximum of {}", result.weight, MAX_CHECK_WEIGHT)
+                    }),
+                    weight: 0,
+                    score_component: None,
+                    confidence: 0.0,
+                    unknown_reason: Some(UnknownReason::InvalidResult),
+                    ..result
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::check_mint_authority_disabled;
+
+    #[test]
+    fn test_checks_run_for_registered_chains_only() {
+        let mut registry = CheckRegistry::new();
+        registry
+            .register("solana_only", &["sol"], Box::new(|facts, _| check_mint_authority_disabled(facts))).unwrap()
+            .register("everywhere", &[], Box::new(|facts, _| check_mint_authority_disabled(facts))).unwrap();
+
+        let facts = TokenFacts::default();
+        let ids = |chain| registry.run(&facts, chain).into_iter().map(|c| c.id).collect::<Vec<_>>();
+        assert_eq!(ids("solana"), vec!["solana_only", "everywhere"]);
+        assert_eq!(ids("base"), vec!["everywhere"]);
+    }
+
+    #[test]
+    fn test_reregistering_replaces() {
+        let mut registry = CheckRegistry::new();
+        registry.register("model", &[], Box::new(|facts, _| check_mint_authority_disabled(facts))).unwrap();
+        registry.register("model", &[], Box::new(|facts, _| check_mint_authority_disabled(facts))).unwrap();
+
+        assert_eq!(registry.ids(), vec!["model"]);
+        assert!(registry.contains("model"));
+    }
+
+    #[test]
+    fn test_builtin_ids_rejected() {
+        let mut registry = CheckRegistry::new();
+        let error = registry
+            .register("mint_authority_disabled", &[], Box::new(|facts, _| check_mint_authority_disabled(facts)))
+            .unwrap_err();
+        assert!(error.contains("built-in"));
+        assert!(registry.ids().is_empty());
+    }
+
+    #[test]
+    fn test_overweight_result_is_unknown() {
+        let mut registry = CheckRegistry::new();
+        registry.register("heavy", &[], Box::new(|facts, _| CheckResult {
+            weight: 250,
+            ..check_mint_authority_disabled(facts)
+        })).unwrap();
+
+        let result = registry.run(&TokenFacts::default(), "solana").remove(0);
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::InvalidResult));
+        assert_eq!(result.weight, 0);
+    }
+}
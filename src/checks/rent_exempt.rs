@@ -0,0 +1,102 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+/// A mint account funded below the rent-exempt minimum for its size can
+/// be closed and its lamports reclaimed. Unusual rather than outright
+/// dangerous, hence Low.
+pub fn check_rent_exempt(facts: &TokenFacts) -> CheckResult {
+    let rent = match &facts.rent {
+        Some(rent) => rent,
+        None => return unknown_result(facts.missing_reason("rent"), "mint account rent status unavailable"),
+    };
+    let (rent_exempt, minimum) = match (rent.rent_exempt, rent.rent_exempt_minimum) {
+        (Some(rent_exempt), Some(minimum)) => (rent_exempt, minimum),
+        _ => return unknown_result(UnknownReason::DataMissing, "rent-exempt minimum unavailable"),
+    };
+
+    CheckResult {
+        id: "rent_exempt".to_string(),
+        label: "Mint rent-exempt".to_string(),
+        category: "interface".to_string(),
+        status: if rent_exempt { CheckStatus::Pass } else { CheckStatus::Fail },
+        severity: Severity::Low,
+        value: json!(rent_exempt),
+        evidence: json!({
+            "source": "provider",
+            "lamports": rent.lamports,
+            "data_len": rent.data_len,
+            "rent_exempt_minimum": minimum,
+            "shortfall_lamports": minimum.saturating_sub(rent.lamports),
+        }),
+        weight: 5,
+        score_component: if rent_exempt { Some(100) } else { Some(0) },
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason, reason: &str) -> CheckResult {
+    CheckResult {
+        id: "rent_exempt".to_string(),
+        label: "Mint rent-exempt".to_string(),
+        category: "interface".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::Low,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": reason
+        }),
+        weight: 5,
+        score_component: None,
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rent-exempt minimum of an 82-byte mint at the default rent rate
+    const MINT_MINIMUM: u64 = 1_461_600;
+
+    fn facts(rent: RentInfo) -> TokenFacts {
+        TokenFacts {
+            rent: Some(rent),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_exempt_mint_passes() {
+        let result = check_rent_exempt(&facts(RentInfo::new(MINT_MINIMUM, 82, Some(MINT_MINIMUM))));
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.evidence["shortfall_lamports"], 0);
+    }
+
+    #[test]
+    fn test_underfunded_mint_fails_low() {
+        let result = check_rent_exempt(&facts(RentInfo::new(890_880, 82, Some(MINT_MINIMUM))));
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.severity, Severity::Low);
+        assert_eq!(result.evidence["lamports"], 890_880);
+        assert_eq!(result.evidence["rent_exempt_minimum"], MINT_MINIMUM);
+        assert_eq!(result.evidence["shortfall_lamports"], MINT_MINIMUM - 890_880);
+    }
+
+    #[test]
+    fn test_unknown_without_minimum_or_account() {
+        let result = check_rent_exempt(&facts(RentInfo::new(890_880, 82, None)));
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::DataMissing));
+
+        let result = check_rent_exempt(&TokenFacts::default());
+        assert_eq!(result.status, CheckStatus::Unknown);
+    }
+}
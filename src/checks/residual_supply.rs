@@ -0,0 +1,212 @@
+use crate::types::*;
+use super::confidence;
+use super::holder_concentration::lerp;
+use serde::Serialize;
+use serde_json::json;
+
+/// Largest non-LP, non-burn holders counted as "top holders"
+pub const RESIDUAL_TOP_N: usize = 5;
+
+/// Supply split between known accounts and the long tail
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ResidualSupply {
+    pub lp_pct: f64,
+    pub burn_pct: f64,
+    pub top_n_pct: f64,
+    pub residual_pct: f64,
+    /// Some listed holders were unclassified, so LP/burn shares may be undercounted
+    pub approximate: bool,
+}
+
+/// `residual_pct = 100 - (lp_pct + burn_pct + top_n_pct)` over the listed
+/// holders. None when there are no holders or a share is missing.
+pub fn compute_residual(holders: &HolderInfo) -> Option<ResidualSupply> {
+    if holders.top_holders.is_empty() {
+        return None;
+    }
+
+    let mut lp_pct = 0.0;
+    let mut burn_pct = 0.0;
+    let mut top_n_pct = 0.0;
+    let mut top_n_seen = 0;
+    let mut approximate = false;
+
+    let mut ranked: Vec<&HolderBalance> = holders.top_holders.iter().collect();
+    ranked.sort_by(|a, b| {
+        b.pct_of_supply.partial_cmp(&a.pct_of_supply).unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    for holder in ranked {
+        let pct = holder.pct_of_supply?;
+        match holder.kind {
+            HolderKind::Lp => lp_pct += pct,
+            HolderKind::Burn => burn_pct += pct,
+            HolderKind::Wallet | HolderKind::TokenAccount | HolderKind::Unclassified => {
+                if holder.kind != HolderKind::Wallet {
+                    approximate = true;
+                }
+                if top_n_seen < RESIDUAL_TOP_N {
+                    top_n_pct += pct;
+                    top_n_seen += 1;
+                }
+            }
+        }
+    }
+
+    let residual_pct = (100.0 - (lp_pct + burn_pct + top_n_pct)).max(0.0);
+
+    Some(ResidualSupply {
+        lp_pct,
+        burn_pct,
+        top_n_pct,
+        residual_pct,
+        approximate,
+    })
+}
+
+pub fn check_residual_supply(facts: &TokenFacts) -> CheckResult {
+    let holders = match &facts.holders {
+        Some(h) => h,
+        None => return unknown_result(facts.missing_reason("holders"), "holder data unavailable"),
+    };
+    if !facts.is_requested(HOLDER_LIST_FACT) {
+        return unknown_result(UnknownReason::NotRequested, HOLDER_LIST_NOT_REQUESTED);
+    }
+
+    let residual = match compute_residual(holders) {
+        Some(r) => r,
+        None => return unknown_result(UnknownReason::DataMissing, "holder balances unavailable"),
+    };
+
+    // A wide long tail is healthy; under 10% left outside known accounts is not
+    let score = lerp(residual.residual_pct, 10.0, 50.0, 0.0, 100.0).round() as u8;
+
+    CheckResult {
+        id: "residual_supply".to_string(),
+        label: "Residual supply".to_string(),
+        category: "distribution".to_string(),
+        status: if score >= 50 { CheckStatus::Pass } else { CheckStatus::Fail },
+        severity: Severity::Low,
+        value: json!(residual.residual_pct),
+        evidence: json!({
+            "source": "provider",
+            "lp_pct": residual.lp_pct,
+            "burn_pct": residual.burn_pct,
+            "top_n": RESIDUAL_TOP_N,
+            "top_n_pct": residual.top_n_pct,
+            "residual_pct": residual.residual_pct,
+            "approximate": residual.approximate,
+        }),
+        weight: 10,
+        score_component: Some(score),
+        confidence: confidence::ESTIMATE,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason, reason: &str) -> CheckResult {
+    CheckResult {
+        id: "residual_supply".to_string(),
+        label: "Residual supply".to_string(),
+        category: "distribution".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::Low,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": reason
+        }),
+        weight: 10,
+        score_component: None,
+        confidence: confidence::ESTIMATE,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holder(address: &str, pct: f64, kind: HolderKind) -> HolderBalance {
+        HolderBalance {
+            address: address.to_string(),
+            balance_raw: "0".to_string(),
+            balance: None,
+            pct_of_supply: Some(pct),
+            kind,
+            label: None,
+        }
+    }
+
+    #[test]
+    fn test_residual_for_mixed_distribution() {
+        let holders = HolderInfo {
+            top1_pct: Some(30.0),
+            top5_pct: Some(61.0),
+            holder_count: None,
+            unlisted_balance_raw: None,
+            top_holders: vec![
+                holder("pool", 30.0, HolderKind::Lp),
+                holder("dead", 15.0, HolderKind::Burn),
+                holder("w1", 6.0, HolderKind::Wallet),
+                holder("w2", 5.0, HolderKind::Wallet),
+                holder("w3", 3.0, HolderKind::Wallet),
+                holder("w4", 2.0, HolderKind::Wallet),
+                holder("w5", 1.5, HolderKind::Wallet),
+                holder("w6", 1.0, HolderKind::Wallet),
+            ],
+        };
+
+        let residual = compute_residual(&holders).unwrap();
+
+        assert_eq!(residual.lp_pct, 30.0);
+        assert_eq!(residual.burn_pct, 15.0);
+        // w6 falls outside the top 5 and counts toward the residual
+        assert_eq!(residual.top_n_pct, 17.5);
+        assert_eq!(residual.residual_pct, 37.5);
+        assert!(!residual.approximate);
+    }
+
+    #[test]
+    fn test_unclassified_holders_mark_approximate() {
+        let facts = TokenFacts {
+            holders: Some(HolderInfo {
+                top1_pct: Some(5.0),
+                top5_pct: Some(12.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![
+                    holder("a", 5.0, HolderKind::Unclassified),
+                    holder("b", 4.0, HolderKind::Wallet),
+                    holder("c", 3.0, HolderKind::Wallet),
+                ],
+            }),
+            ..Default::default()
+        };
+
+        let result = check_residual_supply(&facts);
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.score_component, Some(100));
+        assert_eq!(result.evidence["residual_pct"], 88.0);
+        assert_eq!(result.evidence["approximate"], true);
+    }
+
+    #[test]
+    fn test_no_holder_list_unknown() {
+        let facts = TokenFacts {
+            holders: Some(HolderInfo {
+                top1_pct: Some(5.0),
+                top5_pct: Some(12.0),
+                holder_count: None,
+                unlisted_balance_raw: None,
+                top_holders: vec![],
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(check_residual_supply(&facts).status, CheckStatus::Unknown);
+    }
+}
@@ -1,23 +1,48 @@
 use crate::types::*;
+use super::confidence;
 use serde_json::json;
 
 pub fn check_standard_sanity(facts: &TokenFacts, chain: &str) -> CheckResult {
+    check_standard_sanity_with_policy(facts, chain, false)
+}
+
+/// `check_standard_sanity` where `strict_token_2022` downgrades Token-2022
+/// from a pass to a warning
+pub fn check_standard_sanity_with_policy(
+    facts: &TokenFacts,
+    chain: &str,
+    strict_token_2022: bool,
+) -> CheckResult {
     let metadata = match &facts.metadata {
         Some(m) => m,
-        None => return unknown_result(),
+        None => return unknown_result(facts.missing_reason("metadata")),
     };
     
     let (is_standard, severity) = match chain {
         "solana" => check_solana_standard(&metadata.standard),
-        "base" | "evm" => check_evm_standard(&metadata.standard, &metadata.decimals),
-        _ => (false, Severity::Medium),
+        "base" | "evm" | "ethereum" => check_evm_standard(&metadata.standard, &metadata.decimals),
+        // No standard is defined for this chain
+        _ => return unknown_result(UnknownReason::NotApplicable),
     };
     
+    // Strict policy: Token-2022 is recognized but flagged as a warning
+    let token_2022_warning = is_standard
+        && strict_token_2022
+        && matches!(metadata.standard, TokenStandard::SplToken2022);
+
+    let (status, severity, score_component) = if token_2022_warning {
+        (CheckStatus::Fail, Severity::Low, Some(50))
+    } else if is_standard {
+        (CheckStatus::Pass, severity, Some(100))
+    } else {
+        (CheckStatus::Fail, severity, Some(0))
+    };
+
     CheckResult {
         id: "standard_sanity".to_string(),
         label: "Standard sanity".to_string(),
         category: "interface".to_string(),
-        status: if is_standard { CheckStatus::Pass } else { CheckStatus::Fail },
+        status,
         severity,
         value: json!({
             "standard": format!("{:?}", metadata.standard),
@@ -27,9 +52,13 @@ pub fn check_standard_sanity(facts: &TokenFacts, chain: &str) -> CheckResult {
             "source": "provider",
             "standard": format!("{:?}", metadata.standard),
             "decimals": metadata.decimals,
+            "token_2022_policy": if strict_token_2022 { "strict" } else { "default" },
         }),
         weight: 10,
-        score_component: if is_standard { Some(100) } else { Some(0) },
+        score_component,
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: None,
+        learn_more_url: None,
     }
 }
 
@@ -49,7 +78,7 @@ fn check_evm_standard(standard: &TokenStandard, decimals: &Option<u8>) -> (bool,
     }
 }
 
-fn unknown_result() -> CheckResult {
+fn unknown_result(unknown_reason: UnknownReason) -> CheckResult {
     CheckResult {
         id: "standard_sanity".to_string(),
         label: "Standard sanity".to_string(),
@@ -63,6 +92,9 @@ fn unknown_result() -> CheckResult {
         }),
         weight: 10,
         score_component: None,
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
     }
 }
 
@@ -78,11 +110,13 @@ mod tests {
                 symbol: Some("TEST".to_string()),
                 decimals: Some(9),
                 standard: TokenStandard::SplToken,
+                ..Default::default()
             }),
             supply: None,
             authorities: None,
             holders: None,
             creation: None,
+            ..Default::default()
         };
         
         let result = check_standard_sanity(&facts, "solana");
@@ -99,11 +133,13 @@ mod tests {
                 symbol: Some("TEST".to_string()),
                 decimals: Some(18),
                 standard: TokenStandard::Erc20,
+                ..Default::default()
             }),
             supply: None,
             authorities: None,
             holders: None,
             creation: None,
+            ..Default::default()
         };
         
         let result = check_standard_sanity(&facts, "evm");
@@ -120,11 +156,13 @@ mod tests {
                 symbol: Some("TEST".to_string()),
                 decimals: None,
                 standard: TokenStandard::Unknown,
+                ..Default::default()
             }),
             supply: None,
             authorities: None,
             holders: None,
             creation: None,
+            ..Default::default()
         };
         
         let result = check_standard_sanity(&facts, "solana");
@@ -133,4 +171,56 @@ mod tests {
         assert_eq!(result.score_component, Some(0));
         assert!(matches!(result.severity, Severity::High));
     }
+
+    fn token_2022_facts() -> TokenFacts {
+        TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(9),
+                standard: TokenStandard::SplToken2022,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_token_2022_default_policy_pass() {
+        let result = check_standard_sanity_with_policy(&token_2022_facts(), "solana", false);
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.score_component, Some(100));
+        assert_eq!(result.evidence["token_2022_policy"], "default");
+    }
+
+    #[test]
+    fn test_token_2022_strict_policy_warning() {
+        let result = check_standard_sanity_with_policy(&token_2022_facts(), "solana", true);
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.severity, Severity::Low);
+        assert_eq!(result.score_component, Some(50));
+        assert_eq!(result.evidence["token_2022_policy"], "strict");
+    }
+
+    #[test]
+    fn test_unknown_reason_missing_fact_vs_wrong_chain() {
+        let missing = check_standard_sanity(&TokenFacts::default(), "solana");
+        assert_eq!(missing.status, CheckStatus::Unknown);
+        assert_eq!(missing.unknown_reason, Some(UnknownReason::DataMissing));
+
+        let failed_fetch = TokenFacts {
+            fetch_errors: vec!["metadata".to_string()],
+            ..Default::default()
+        };
+        let result = check_standard_sanity(&failed_fetch, "solana");
+        assert_eq!(result.unknown_reason, Some(UnknownReason::ProviderError));
+
+        let wrong_chain = check_standard_sanity(&token_2022_facts(), "dogechain");
+        assert_eq!(wrong_chain.status, CheckStatus::Unknown);
+        assert_eq!(wrong_chain.unknown_reason, Some(UnknownReason::NotApplicable));
+
+        // Known results carry no reason
+        let known = check_standard_sanity(&token_2022_facts(), "solana");
+        assert_eq!(known.unknown_reason, None);
+    }
 }
@@ -0,0 +1,135 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+/// EVM fixed supply: no one can mint. A simulated `mint` call decides
+/// whether the function exists; the bytecode scan in `mint_mutable` (and
+/// the owner) decides who can call it. Both are best effort.
+pub fn check_supply_fixed_evm(facts: &TokenFacts) -> CheckResult {
+    let simulation = match &facts.mint_simulation {
+        Some(simulation) => simulation,
+        None => return unknown_result(facts.missing_reason("mint_simulation"), "mint simulation unavailable"),
+    };
+    let authorities = facts.authorities.as_ref();
+    let owner = authorities.and_then(|a| a.owner.as_deref());
+    let scanned_mutable = authorities.and_then(|a| a.mint_mutable);
+
+    let (fixed, method) = match simulation.outcome {
+        // The two probes disagree; neither outranks the other
+        MintCallOutcome::UnknownSelector if scanned_mutable == Some(true) => {
+            return unknown_result(
+                UnknownReason::InvalidResult,
+                "simulation found no mint but the bytecode has a mint selector",
+            )
+        }
+        MintCallOutcome::UnknownSelector => (true, "simulation"),
+        MintCallOutcome::Succeeded => (false, "simulation"),
+        // `mint` exists; fixed only if no one holds the right to call it
+        MintCallOutcome::Unauthorized => {
+            (owner.is_none() && scanned_mutable != Some(true), "simulation")
+        }
+        MintCallOutcome::Inconclusive => match scanned_mutable {
+            Some(mutable) => (!mutable, "bytecode"),
+            None => return unknown_result(
+                facts.missing_reason("authorities"),
+                "mint simulation inconclusive and mintability unavailable",
+            ),
+        },
+    };
+
+    CheckResult {
+        id: "supply_fixed".to_string(),
+        label: "Supply fixed".to_string(),
+        category: "supply_control".to_string(),
+        status: if fixed { CheckStatus::Pass } else { CheckStatus::Fail },
+        severity: Severity::Critical,
+        value: json!(fixed),
+        evidence: json!({
+            "source": "provider",
+            "method": method,
+            "best_effort": true,
+            "outcome": simulation.outcome,
+            "revert_reason": simulation.revert_reason,
+            "caller": simulation.caller,
+            "owner": owner,
+        }),
+        weight: 20,
+        score_component: if fixed { Some(100) } else { Some(0) },
+        confidence: confidence::ESTIMATE,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason, reason: &str) -> CheckResult {
+    CheckResult {
+        id: "supply_fixed".to_string(),
+        label: "Supply fixed".to_string(),
+        category: "supply_control".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::Critical,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": reason
+        }),
+        weight: 20,
+        score_component: None,
+        confidence: confidence::ESTIMATE,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts(outcome: MintCallOutcome, owner: Option<&str>, mint_mutable: Option<bool>) -> TokenFacts {
+        TokenFacts {
+            authorities: Some(AuthorityInfo {
+                owner: owner.map(str::to_string),
+                mint_mutable,
+                ..Default::default()
+            }),
+            mint_simulation: Some(MintSimulation {
+                outcome,
+                revert_reason: None,
+                caller: "0x0000000000000000000000000000000000000000".to_string(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_missing_mint_function_passes() {
+        let result = check_supply_fixed_evm(&facts(MintCallOutcome::UnknownSelector, Some("0xowner"), Some(false)));
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.evidence["method"], "simulation");
+    }
+
+    #[test]
+    fn test_missing_mint_contradicting_bytecode_is_unknown() {
+        let result = check_supply_fixed_evm(&facts(MintCallOutcome::UnknownSelector, Some("0xowner"), Some(true)));
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::InvalidResult));
+        assert!(result.evidence["error"].as_str().unwrap().contains("bytecode has a mint selector"));
+    }
+
+    #[test]
+    fn test_guarded_mint_with_owner_fails() {
+        let result = check_supply_fixed_evm(&facts(MintCallOutcome::Unauthorized, Some("0xowner"), Some(false)));
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.evidence["outcome"], "unauthorized");
+    }
+
+    #[test]
+    fn test_inconclusive_falls_back_to_bytecode_scan() {
+        let result = check_supply_fixed_evm(&facts(MintCallOutcome::Inconclusive, None, Some(false)));
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.evidence["method"], "bytecode");
+
+        let result = check_supply_fixed_evm(&facts(MintCallOutcome::Inconclusive, None, None));
+        assert_eq!(result.status, CheckStatus::Unknown);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+/// Broad sane range for the human-readable total supply. Outside it the
+/// token is usually broken (1 unit at 18 decimals) or built to confuse
+/// (10^30 tokens).
+#[derive(Clone, Debug, PartialEq)]
+pub struct SupplyBounds {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Default for SupplyBounds {
+    fn default() -> Self {
+        Self { min: 1.0, max: 1e15 }
+    }
+}
+
+pub fn check_supply_magnitude(facts: &TokenFacts) -> CheckResult {
+    check_supply_magnitude_with_bounds(facts, &SupplyBounds::default())
+}
+
+/// Dust supplies warn at `Medium` (the token is unusable as described),
+/// astronomical ones at `Low`. Scaled from the raw supply with the token's
+/// own decimals: a provider's `total_supply` may assume 18.
+pub fn check_supply_magnitude_with_bounds(facts: &TokenFacts, bounds: &SupplyBounds) -> CheckResult {
+    let decimals = match facts.metadata.as_ref().map(|m| m.decimals) {
+        Some(Some(decimals)) => decimals,
+        Some(None) => return unknown_result(UnknownReason::DataMissing),
+        None => return unknown_result(facts.missing_reason("metadata")),
+    };
+    let supply = match &facts.supply {
+        Some(s) => s,
+        None => return unknown_result(facts.missing_reason("supply")),
+    };
+    let total = match supply.raw_units() {
+        Some(raw) => raw / 10f64.powi(decimals as i32),
+        None => return unknown_result(UnknownReason::DataMissing),
+    };
+
+    let (status, severity, finding) = if total < bounds.min {
+        (CheckStatus::Fail, Severity::Medium, "below_min")
+    } else if total > bounds.max {
+        (CheckStatus::Fail, Severity::Low, "above_max")
+    } else {
+        (CheckStatus::Pass, Severity::Low, "in_range")
+    };
+
+    CheckResult {
+        id: "supply_magnitude".to_string(),
+        label: "Supply magnitude".to_string(),
+        category: "supply_control".to_string(),
+        score_component: if status == CheckStatus::Pass { Some(100) } else { Some(50) },
+        status,
+        severity,
+        value: json!(total),
+        evidence: json!({
+            "source": "provider",
+            "total_supply": total,
+            "decimals": decimals,
+            "min": bounds.min,
+            "max": bounds.max,
+            "finding": finding,
+        }),
+        weight: 5,
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason) -> CheckResult {
+    CheckResult {
+        id: "supply_magnitude".to_string(),
+        label: "Supply magnitude".to_string(),
+        category: "supply_control".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::Low,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": "raw supply or decimals unavailable"
+        }),
+        weight: 5,
+        score_component: None,
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts_with_raw(raw: &str, decimals: Option<u8>) -> TokenFacts {
+        TokenFacts {
+            metadata: Some(Metadata { decimals, ..Default::default() }),
+            supply: Some(SupplyInfo {
+                total_supply_raw: Some(raw.to_string()),
+                // Deliberately wrong, as a provider assuming 18 decimals reports it
+                total_supply: Some(raw.parse::<f64>().unwrap() / 1e18),
+                circulating_supply: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_dust_supply_warns_medium() {
+        // One raw unit of an 18-decimal token
+        let result = check_supply_magnitude(&facts_with_raw("1", Some(18)));
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.severity, Severity::Medium);
+        assert_eq!(result.evidence["finding"], "below_min");
+        assert_eq!(result.evidence["min"], 1.0);
+    }
+
+    #[test]
+    fn test_astronomical_supply_warns_low() {
+        let result = check_supply_magnitude(&facts_with_raw("1000000000000000000000000000000", Some(0)));
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.severity, Severity::Low);
+        assert_eq!(result.evidence["finding"], "above_max");
+        assert_eq!(result.evidence["max"], 1e15);
+    }
+
+    #[test]
+    fn test_ordinary_supply_passes() {
+        let result = check_supply_magnitude(&facts_with_raw("1000000000000000000", Some(18)));
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.score_component, Some(100));
+    }
+
+    #[test]
+    fn test_scaled_by_token_decimals() {
+        // 10^9 tokens at 6 decimals; read at 18 they would be dust
+        let result = check_supply_magnitude(&facts_with_raw("1000000000000000", Some(6)));
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.value, json!(1e9));
+    }
+
+    #[test]
+    fn test_missing_decimals_unknown() {
+        let result = check_supply_magnitude(&facts_with_raw("1000000", None));
+
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::DataMissing));
+    }
+
+    #[test]
+    fn test_custom_bounds() {
+        let bounds = SupplyBounds { min: 1.0, max: 1e6 };
+        let result = check_supply_magnitude_with_bounds(&facts_with_raw("1000000000", Some(0)), &bounds);
+
+        assert_eq!(result.status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn test_no_supply_unknown() {
+        let result = check_supply_magnitude(&TokenFacts::default());
+
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.unknown_reason, Some(UnknownReason::DataMissing));
+    }
+}
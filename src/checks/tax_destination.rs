@@ -0,0 +1,141 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+pub fn check_tax_destination(facts: &TokenFacts) -> CheckResult {
+    let tax = match &facts.tax {
+        Some(t) => t,
+        None => return unknown_result(facts.missing_reason("tax"), "tax data unavailable"),
+    };
+
+    let recipient = match &tax.tax_recipient {
+        Some(r) => r,
+        None => return unknown_result(UnknownReason::DataMissing, "tax recipient undetectable"),
+    };
+
+    let is_contract = match tax.recipient_is_contract {
+        Some(c) => c,
+        None => return unknown_result(UnknownReason::DataMissing, "tax recipient type undetectable"),
+    };
+
+    // Tax routed to the owner is the deployer pocketing every transfer fee
+    let recipient_is_owner = facts.authorities.as_ref()
+        .and_then(|a| a.owner.as_ref())
+        .map(|owner| owner.eq_ignore_ascii_case(recipient))
+        .unwrap_or(false);
+
+    // A bare wallet (EOA) collecting all tax is a single point of extraction
+    let routed_to_wallet = !is_contract;
+
+    CheckResult {
+        id: "tax_destination".to_string(),
+        label: "Tax destination".to_string(),
+        category: "fees".to_string(),
+        status: if routed_to_wallet { CheckStatus::Fail } else { CheckStatus::Pass },
+        severity: Severity::Medium,
+        value: json!({
+            "tax_recipient": recipient,
+            "recipient_type": if is_contract { "contract" } else { "eoa" },
+        }),
+        evidence: json!({
+            "source": "provider",
+            "tax_recipient": recipient,
+            "recipient_is_contract": is_contract,
+            "recipient_is_owner": recipient_is_owner,
+            "detection_method": tax.detection_method,
+        }),
+        weight: 10,
+        score_component: if routed_to_wallet { Some(0) } else { Some(100) },
+        confidence: confidence::ESTIMATE,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason, reason: &str) -> CheckResult {
+    CheckResult {
+        id: "tax_destination".to_string(),
+        label: "Tax destination".to_string(),
+        category: "fees".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::Medium,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": reason
+        }),
+        weight: 10,
+        score_component: None,
+        confidence: confidence::ESTIMATE,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tax_routed_to_deployer_fail() {
+        let deployer = "0x1234567890123456789012345678901234567890";
+        let facts = TokenFacts {
+            authorities: Some(AuthorityInfo {
+                mint_authority: None,
+                freeze_authority: None,
+                owner: Some(deployer.to_string()),
+                mint_mutable: Some(true),
+                ..Default::default()
+            }),
+            tax: Some(TaxInfo {
+                tax_recipient: Some(deployer.to_string()),
+                recipient_is_contract: Some(false),
+                detection_method: Some("taxWallet()".to_string()),
+            }),
+            ..Default::default()
+        };
+
+        let result = check_tax_destination(&facts);
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.score_component, Some(0));
+        assert_eq!(result.evidence["tax_recipient"], deployer);
+        assert_eq!(result.evidence["recipient_is_owner"], true);
+    }
+
+    #[test]
+    fn test_tax_routed_to_liquidity_pass() {
+        let pair = "0xabcdef0123456789abcdef0123456789abcdef01";
+        let facts = TokenFacts {
+            tax: Some(TaxInfo {
+                tax_recipient: Some(pair.to_string()),
+                recipient_is_contract: Some(true),
+                detection_method: Some("feeWallet()".to_string()),
+            }),
+            ..Default::default()
+        };
+
+        let result = check_tax_destination(&facts);
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.score_component, Some(100));
+        assert_eq!(result.evidence["tax_recipient"], pair);
+    }
+
+    #[test]
+    fn test_tax_undetectable_unknown() {
+        let facts = TokenFacts {
+            tax: Some(TaxInfo {
+                tax_recipient: None,
+                recipient_is_contract: None,
+                detection_method: None,
+            }),
+            ..Default::default()
+        };
+
+        let result = check_tax_destination(&facts);
+
+        assert_eq!(result.status, CheckStatus::Unknown);
+        assert_eq!(result.score_component, None);
+    }
+}
@@ -1,18 +1,63 @@
 use crate::types::*;
+use super::confidence;
 use serde_json::json;
 
+/// Score points withheld when the age is only an estimate
+pub const ESTIMATED_AGE_PENALTY: u8 = 10;
+
+/// Maps a token's age to a band and the band to a score. Products define
+/// "new" differently; the default is `DefaultAgeBandPolicy`.
+pub trait AgeBandPolicy: std::fmt::Debug + Send + Sync {
+    fn band_for(&self, age_seconds: u64) -> AgeBand;
+
+    fn score_for(&self, band: &AgeBand) -> Option<u8> {
+        match band {
+            AgeBand::GreaterThan7d => Some(100),
+            AgeBand::Day1To7 => Some(70),
+            AgeBand::LessThan24h => Some(40),
+            AgeBand::Unknown => None,
+        }
+    }
+}
+
+/// Under a day, under a week, older
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultAgeBandPolicy;
+
+impl AgeBandPolicy for DefaultAgeBandPolicy {
+    fn band_for(&self, age_seconds: u64) -> AgeBand {
+        age_band_for(age_seconds)
+    }
+}
+
 pub fn check_token_age(facts: &TokenFacts) -> CheckResult {
+    check_token_age_with_policy(facts, &DefaultAgeBandPolicy)
+}
+
+/// `check_token_age` banding and scoring with `policy`
+pub fn check_token_age_with_policy(facts: &TokenFacts, policy: &dyn AgeBandPolicy) -> CheckResult {
     let creation = match &facts.creation {
         Some(c) => c,
-        None => return unknown_result(),
+        None => return unknown_result(facts.missing_reason("creation")),
     };
-    
-    let (score, value) = match creation.age_band {
-        AgeBand::GreaterThan7d => (100, "stabilizing"),
-        AgeBand::Day1To7 => (70, "early"),
-        AgeBand::LessThan24h => (40, "extremely_fragile"),
-        AgeBand::Unknown => return unknown_result(),
+
+    // Re-band from the raw age; fall back to the provider's band without one
+    let age_band = match creation.age_seconds {
+        Some(age_seconds) => policy.band_for(age_seconds),
+        None => creation.age_band.clone(),
+    };
+
+    let score = match policy.score_for(&age_band) {
+        Some(score) => score,
+        None => return unknown_result(UnknownReason::DataMissing),
+    };
+    let value = match age_band {
+        AgeBand::GreaterThan7d => "stabilizing",
+        AgeBand::Day1To7 => "early",
+        AgeBand::LessThan24h => "extremely_fragile",
+        AgeBand::Unknown => "unknown",
     };
+    let score = if creation.estimated { score.saturating_sub(ESTIMATED_AGE_PENALTY) } else { score };
     
     CheckResult {
         id: "token_age".to_string(),
@@ -21,7 +66,7 @@ pub fn check_token_age(facts: &TokenFacts) -> CheckResult {
         status: CheckStatus::Pass,
         severity: Severity::Low,
         value: json!({
-            "age_band": format!("{:?}", creation.age_band),
+            "age_band": format!("{:?}", age_band),
             "age_seconds": creation.age_seconds,
             "interpretation": value,
         }),
@@ -29,13 +74,17 @@ pub fn check_token_age(facts: &TokenFacts) -> CheckResult {
             "source": "provider",
             "created_at": creation.created_at,
             "age_seconds": creation.age_seconds,
+            "estimated": creation.estimated,
         }),
         weight: 10,
         score_component: Some(score),
+        confidence: if creation.estimated { confidence::INFERRED } else { confidence::PROVIDER_REPORTED },
+        unknown_reason: None,
+        learn_more_url: None,
     }
 }
 
-fn unknown_result() -> CheckResult {
+fn unknown_result(unknown_reason: UnknownReason) -> CheckResult {
     CheckResult {
         id: "token_age".to_string(),
         label: "Token age".to_string(),
@@ -49,9 +98,82 @@ fn unknown_result() -> CheckResult {
         }),
         weight: 10,
         score_component: None,
+        confidence: confidence::PROVIDER_REPORTED,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
     }
 }
 
+/// Age band for a token `age_seconds` old
+pub fn age_band_for(age_seconds: u64) -> AgeBand {
+    if age_seconds < 24 * 60 * 60 {
+        AgeBand::LessThan24h
+    } else if age_seconds < 7 * 24 * 60 * 60 {
+        AgeBand::Day1To7
+    } else {
+        AgeBand::GreaterThan7d
+    }
+}
+
+/// Recompute `age_seconds`/`age_band` from `created_at` relative to
+/// `reference` (unix seconds). Left untouched when `created_at` is absent
+/// or unparseable. A reference before creation counts as age zero.
+pub fn rebase_creation(creation: &mut CreationInfo, reference: u64) {
+    let created = match creation.created_at.as_deref().and_then(parse_utc_timestamp) {
+        Some(t) => t,
+        None => return,
+    };
+
+    let age_seconds = reference.saturating_sub(created);
+    creation.age_seconds = Some(age_seconds);
+    creation.age_band = age_band_for(age_seconds);
+}
+
+/// Fallback when creation time is unavailable: the oldest already-fetched
+/// authority change bounds the token's age from below. None without any
+/// timestamped change.
+pub fn estimate_creation(history: &[AuthorityChange], reference: u64) -> Option<CreationInfo> {
+    let oldest = history.iter().filter_map(|c| c.block_time).min()?;
+    let age_seconds = reference.saturating_sub(u64::try_from(oldest).ok()?);
+
+    Some(CreationInfo {
+        created_at: None,
+        age_seconds: Some(age_seconds),
+        age_band: age_band_for(age_seconds),
+        estimated: true,
+    })
+}
+
+/// Parse `YYYY-MM-DDTHH:MM:SS[.fff]Z` into unix seconds
+pub fn parse_utc_timestamp(s: &str) -> Option<u64> {
+    let s = s.strip_suffix('Z')?;
+    let (date, time) = s.split_once('T')?;
+    let time = time.split('.').next()?;
+
+    let mut date_parts = date.splitn(3, '-').map(|p| p.parse::<i64>().ok());
+    let (year, month, day) = (date_parts.next()??, date_parts.next()??, date_parts.next()??);
+    let mut time_parts = time.splitn(3, ':').map(|p| p.parse::<i64>().ok());
+    let (hour, minute, second) = (time_parts.next()??, time_parts.next()??, time_parts.next()??);
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day)
+        || hour > 23 || minute > 59 || second > 60
+    {
+        return None;
+    }
+
+    // Days since the unix epoch for a proleptic Gregorian date
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    let secs = days * 86_400 + hour * 3_600 + minute * 60 + second;
+    u64::try_from(secs).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,11 +185,13 @@ mod tests {
                 created_at: Some("2026-01-20T00:00:00Z".to_string()),
                 age_seconds: Some(864000),
                 age_band: AgeBand::GreaterThan7d,
+                estimated: false,
             }),
             metadata: None,
             supply: None,
             authorities: None,
             holders: None,
+            ..Default::default()
         };
         
         let result = check_token_age(&facts);
@@ -83,11 +207,13 @@ mod tests {
                 created_at: Some("2026-01-27T00:00:00Z".to_string()),
                 age_seconds: Some(259200),
                 age_band: AgeBand::Day1To7,
+                estimated: false,
             }),
             metadata: None,
             supply: None,
             authorities: None,
             holders: None,
+            ..Default::default()
         };
         
         let result = check_token_age(&facts);
@@ -103,11 +229,13 @@ mod tests {
                 created_at: Some("2026-01-31T10:00:00Z".to_string()),
                 age_seconds: Some(3600),
                 age_band: AgeBand::LessThan24h,
+                estimated: false,
             }),
             metadata: None,
             supply: None,
             authorities: None,
             holders: None,
+            ..Default::default()
         };
         
         let result = check_token_age(&facts);
@@ -115,4 +243,95 @@ mod tests {
         assert!(matches!(result.status, CheckStatus::Pass));
         assert_eq!(result.score_component, Some(40));
     }
+
+    #[test]
+    fn test_parse_utc_timestamp() {
+        assert_eq!(parse_utc_timestamp("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(parse_utc_timestamp("2026-01-20T00:00:00Z"), Some(1_768_867_200));
+        assert_eq!(parse_utc_timestamp("2024-02-29T12:30:15.250Z"), Some(1_709_209_815));
+        assert_eq!(parse_utc_timestamp("2026-01-20"), None);
+        assert_eq!(parse_utc_timestamp("2026-13-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn test_rebase_creation_against_reference() {
+        let mut creation = CreationInfo {
+            created_at: Some("2026-01-20T00:00:00Z".to_string()),
+            age_seconds: None,
+            age_band: AgeBand::Unknown,
+            estimated: false,
+        };
+
+        rebase_creation(&mut creation, 1_768_867_200 + 3 * 86_400);
+
+        assert_eq!(creation.age_seconds, Some(3 * 86_400));
+        assert!(matches!(creation.age_band, AgeBand::Day1To7));
+    }
+    
+    #[test]
+    fn test_age_estimated_from_authority_history() {
+        let reference = 1_700_000_000;
+        let change = |block_time: Option<i64>| AuthorityChange {
+            authority_type: "mintTokens".to_string(),
+            new_authority: None,
+            signature: None,
+            block_time,
+            age_seconds: None,
+        };
+        let history = vec![
+            change(Some(reference as i64 - 3_600)),
+            change(Some(reference as i64 - 10 * 24 * 3_600)),
+            change(None),
+        ];
+
+        let facts = TokenFacts {
+            creation: estimate_creation(&history, reference),
+            ..Default::default()
+        };
+        let result = check_token_age(&facts);
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.value["age_band"], "GreaterThan7d");
+        assert_eq!(result.evidence["estimated"], true);
+        assert_eq!(result.score_component, Some(100 - ESTIMATED_AGE_PENALTY));
+        assert!(estimate_creation(&[change(None)], reference).is_none());
+    }
+    
+    /// Institutional horizon: anything under a week is brand new
+    #[derive(Debug)]
+    struct MonthsPolicy;
+
+    impl AgeBandPolicy for MonthsPolicy {
+        fn band_for(&self, age_seconds: u64) -> AgeBand {
+            let day = 24 * 60 * 60;
+            if age_seconds < 7 * day {
+                AgeBand::LessThan24h
+            } else if age_seconds < 90 * day {
+                AgeBand::Day1To7
+            } else {
+                AgeBand::GreaterThan7d
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_policy_rebands_two_day_old_token() {
+        let facts = TokenFacts {
+            creation: Some(CreationInfo {
+                created_at: None,
+                age_seconds: Some(2 * 24 * 60 * 60),
+                age_band: AgeBand::Day1To7,
+                estimated: false,
+            }),
+            ..Default::default()
+        };
+
+        let default = check_token_age(&facts);
+        let custom = check_token_age_with_policy(&facts, &MonthsPolicy);
+
+        assert_eq!(default.value["age_band"], "Day1To7");
+        assert_eq!(default.score_component, Some(70));
+        assert_eq!(custom.value["age_band"], "LessThan24h");
+        assert_eq!(custom.score_component, Some(40));
+    }
 }
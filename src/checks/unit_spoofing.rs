@@ -0,0 +1,143 @@
+use crate::types::*;
+use super::confidence;
+use serde_json::json;
+
+/// Zero-decimal tokens above this many units are suspicious: whole units
+/// are then indivisible, yet the supply is sized like a currency
+pub const ZERO_DECIMALS_MAX_SUPPLY: f64 = 1e6;
+
+/// Decimals at or below this, combined with a supply above
+/// `LOW_DECIMALS_MAX_SUPPLY`, inflate the displayed supply
+pub const LOW_DECIMALS: u8 = 2;
+pub const LOW_DECIMALS_MAX_SUPPLY: f64 = 1e12;
+
+/// Flags decimals/raw-supply combinations used to display misleading
+/// supply figures (e.g. `decimals: 0` with trillions of raw units)
+pub fn check_unit_spoofing(facts: &TokenFacts) -> CheckResult {
+    let decimals = match facts.metadata.as_ref().map(|m| m.decimals) {
+        Some(Some(decimals)) => decimals,
+        Some(None) => return unknown_result(UnknownReason::DataMissing),
+        None => return unknown_result(facts.missing_reason("metadata")),
+    };
+    let supply = match &facts.supply {
+        Some(s) => s,
+        None => return unknown_result(facts.missing_reason("supply")),
+    };
+    let raw = match supply.raw_units() {
+        Some(raw) => raw,
+        None => return unknown_result(UnknownReason::DataMissing),
+    };
+    let human = raw / 10f64.powi(decimals as i32);
+
+    let mut reasons = Vec::new();
+    if decimals == 0 && human > ZERO_DECIMALS_MAX_SUPPLY {
+        reasons.push(format!(
+            "zero decimals with a supply above {} units",
+            ZERO_DECIMALS_MAX_SUPPLY
+        ));
+    }
+    if decimals <= LOW_DECIMALS && human > LOW_DECIMALS_MAX_SUPPLY {
+        reasons.push(format!(
+            "{} decimals with a displayed supply above {}",
+            decimals, LOW_DECIMALS_MAX_SUPPLY
+        ));
+    }
+    let is_suspicious = !reasons.is_empty();
+
+    CheckResult {
+        id: "unit_spoofing".to_string(),
+        label: "Decimals / supply consistency".to_string(),
+        category: "supply_control".to_string(),
+        status: if is_suspicious { CheckStatus::Fail } else { CheckStatus::Pass },
+        severity: Severity::Medium,
+        value: json!(is_suspicious),
+        evidence: json!({
+            "source": "provider",
+            "decimals": decimals,
+            "raw_supply": supply.total_supply_raw,
+            "display_supply": human,
+            "reasons": reasons,
+        }),
+        weight: 5,
+        score_component: if is_suspicious { Some(25) } else { Some(100) },
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: None,
+        learn_more_url: None,
+    }
+}
+
+fn unknown_result(unknown_reason: UnknownReason) -> CheckResult {
+    CheckResult {
+        id: "unit_spoofing".to_string(),
+        label: "Decimals / supply consistency".to_string(),
+        category: "supply_control".to_string(),
+        status: CheckStatus::Unknown,
+        severity: Severity::Medium,
+        value: json!(null),
+        evidence: json!({
+            "source": "provider",
+            "error": "decimals or raw supply unavailable"
+        }),
+        weight: 5,
+        score_component: None,
+        confidence: confidence::PARSED_ACCOUNT,
+        unknown_reason: Some(unknown_reason),
+        learn_more_url: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts(decimals: u8, raw_supply: &str) -> TokenFacts {
+        TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(decimals),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            supply: Some(SupplyInfo {
+                total_supply_raw: Some(raw_supply.to_string()),
+                total_supply: None,
+                circulating_supply: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_zero_decimals_huge_supply_flagged() {
+        let result = check_unit_spoofing(&facts(0, "5000000000000000"));
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.evidence["reasons"].as_array().unwrap().len(), 2);
+        assert_eq!(result.evidence["display_supply"], 5e15);
+    }
+
+    #[test]
+    fn test_nine_decimals_normal_supply_passes() {
+        // One billion tokens at 9 decimals
+        let result = check_unit_spoofing(&facts(9, "1000000000000000000"));
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.evidence["display_supply"], 1e9);
+        assert!(result.evidence["reasons"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_hex_raw_supply_parsed() {
+        // 0xde0b6b3a7640000 = 10^18 at 18 decimals: one token
+        let result = check_unit_spoofing(&facts(18, "0xde0b6b3a7640000"));
+
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.evidence["display_supply"], 1.0);
+    }
+
+    #[test]
+    fn test_missing_decimals_unknown() {
+        let result = check_unit_spoofing(&TokenFacts::default());
+
+        assert_eq!(result.status, CheckStatus::Unknown);
+    }
+}
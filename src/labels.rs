@@ -0,0 +1,90 @@
+// src/labels.rs
+//
+// Known roles of holder addresses (exchange wallets, pools, ...), so
+// concentration checks can tell custodial or pooled balances from whales.
+
+use crate::api::chain::{normalize_address, normalize_chain};
+use crate::types::{AddressLabel, LabelKind};
+use std::collections::HashMap;
+
+/// Binance hot wallet (Solana)
+pub const SOLANA_BINANCE_HOT: &str = "5tzFkiKscXHK5ZXCGbXZxdw7gTjjD1mBwuoFbhUvuAi9";
+/// Coinbase hot wallet (Solana)
+pub const SOLANA_COINBASE_HOT: &str = "H8sMJSCQxfKiFTCfDR3DUMLPwcRbM61LGFJ8N4dK3WjS";
+/// Binance 14 hot wallet (Ethereum)
+pub const ETHEREUM_BINANCE_14: &str = "0x28c6c06298d514db089934071355e5743bf21d60";
+/// Coinbase 10 hot wallet (Ethereum)
+pub const ETHEREUM_COINBASE_10: &str = "0xa9d1e08c7793af67e9d92fe308d5697fb81d3e43";
+
+/// Address labels per canonical chain. `default()` holds a small set of
+/// well-known exchange wallets; operators extend it with `with_label`.
+/// Labels a provider returns from `fetch_labels` take precedence.
+#[derive(Clone, Debug)]
+pub struct AddressLabels {
+    by_chain: HashMap<String, HashMap<String, AddressLabel>>,
+}
+
+impl AddressLabels {
+    /// No labels at all
+    pub fn empty() -> Self {
+        Self {
+            by_chain: HashMap::new(),
+        }
+    }
+
+    /// Label `address` on `chain` (aliases accepted)
+    pub fn with_label(mut self, chain: &str, address: &str, kind: LabelKind, name: &str) -> Self {
+        let chain = canonical(chain);
+        let address = normalize_address(&chain, address);
+        let label = AddressLabel { kind, name: Some(name.to_string()) };
+        self.by_chain.entry(chain).or_default().insert(address, label);
+        self
+    }
+
+    pub fn get(&self, chain: &str, address: &str) -> Option<&AddressLabel> {
+        let chain = canonical(chain);
+        let address = normalize_address(&chain, address);
+        self.by_chain.get(&chain).and_then(|labels| labels.get(&address))
+    }
+}
+
+impl Default for AddressLabels {
+    fn default() -> Self {
+        Self::empty()
+            .with_label("solana", SOLANA_BINANCE_HOT, LabelKind::Cex, "Binance")
+            .with_label("solana", SOLANA_COINBASE_HOT, LabelKind::Cex, "Coinbase")
+            .with_label("ethereum", ETHEREUM_BINANCE_14, LabelKind::Cex, "Binance 14")
+            .with_label("ethereum", ETHEREUM_COINBASE_10, LabelKind::Cex, "Coinbase 10")
+    }
+}
+
+fn canonical(chain: &str) -> String {
+    normalize_chain(chain).unwrap_or_else(|| chain.trim().to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_well_known_exchange_wallets() {
+        let labels = AddressLabels::default();
+
+        let binance = labels.get("eth", "0x28C6c06298d514Db089934071355E5743bf21d60").unwrap();
+        assert_eq!(binance.kind, LabelKind::Cex);
+        assert_eq!(binance.name.as_deref(), Some("Binance 14"));
+        assert!(labels.get("solana", SOLANA_COINBASE_HOT).is_some());
+
+        // Labels are per chain
+        assert!(labels.get("base", ETHEREUM_BINANCE_14).is_none());
+    }
+
+    #[test]
+    fn test_configured_label() {
+        let pool = "0x1111111111111111111111111111111111111111";
+        let labels = AddressLabels::default().with_label("base", pool, LabelKind::DexPool, "Aerodrome");
+
+        assert_eq!(labels.get("base", pool).unwrap().kind, LabelKind::DexPool);
+        assert!(labels.get("ethereum", pool).is_none());
+    }
+}
@@ -7,10 +7,13 @@ pub mod scoring;
 pub mod api;
 pub mod cache;
 pub mod server;
+pub mod queue;
+pub mod burn;
+pub mod labels;
 
 // Re-export commonly used types
 pub use types::*;
 pub use providers::TokenProvider;
-pub use scoring::{aggregate_score, ScoreResult};
-pub use api::{analyze, AnalyzeRequest, AnalyzeResponse};
+pub use scoring::{aggregate_score, aggregate_score_with_profile, ScoreResult, ScoringProfile};
+pub use api::{analyze, analyze_with_config, AnalyzeConfig, AnalyzeRequest, AnalyzeResponse};
 pub use cache::SimpleCache;
@@ -1,33 +1,248 @@
 use async_trait::async_trait;
 use crate::types::*;
-use super::{TokenProvider, ProviderError};
+use crate::burn::BurnAddresses;
+use super::{quantity, trace, ProviderCapabilities, TokenProvider, ProviderError};
 use serde::Deserialize;
 use serde_json::json;
+use sha3::{Digest, Keccak256};
+use std::time::Instant;
 
 pub struct AlchemyProvider {
-    api_key: String,
     rpc_url: String,
+    /// Canonical chain the endpoint serves ("evm" for a custom endpoint)
+    chain: String,
+    /// Addresses read back as "no address" (renounced owner, no recipient)
+    burn_addresses: BurnAddresses,
+    // Shared connection pool, reused across requests
+    client: reqwest::Client,
 }
 
 impl AlchemyProvider {
     pub fn new(api_key: String, chain: &str) -> Self {
-        let rpc_url = match chain {
-            "base" => format!("https://base-mainnet.g.alchemy.com/v2/{}", api_key),
-            "ethereum" => format!("https://eth-mainnet.g.alchemy.com/v2/{}", api_key),
-            _ => format!("https://base-mainnet.g.alchemy.com/v2/{}", api_key),
+        let (rpc_url, chain) = match chain {
+            "ethereum" => (format!("https://eth-mainnet.g.alchemy.com/v2/{}", api_key), "ethereum"),
+            _ => (format!("https://base-mainnet.g.alchemy.com/v2/{}", api_key), "base"),
         };
         
         Self {
-            api_key,
             rpc_url,
+            chain: chain.to_string(),
+            burn_addresses: BurnAddresses::default(),
+            client: reqwest::Client::new(),
         }
     }
 
+    /// Provider against an explicit JSON-RPC endpoint (self-hosted node,
+    /// local test server)
+    pub fn with_rpc_url(rpc_url: impl Into<String>) -> Self {
+        Self {
+            rpc_url: rpc_url.into(),
+            chain: "evm".to_string(),
+            burn_addresses: BurnAddresses::default(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Canonical chain a custom endpoint serves, for chain-specific burn addresses
+    pub fn with_chain(mut self, chain: &str) -> Self {
+        self.chain = chain.to_string();
+        self
+    }
+
+    /// Burn addresses to read as "no address"; the well-known ones by default
+    pub fn with_burn_addresses(mut self, burn_addresses: BurnAddresses) -> Self {
+        self.burn_addresses = burn_addresses;
+        self
+    }
+
+    /// ERC20 `totalSupply()` (0x18160ddd) at a block tag ("latest", "0x...")
+    async fn total_supply_at(&self, address: &str, block_tag: &str) -> Result<SupplyInfo, ProviderError> {
+        let supply: serde_json::Value = self.rpc_call(
+            "eth_call",
+            json!([
+                {
+                    "to": address,
+                    "data": "0x18160ddd"
+                },
+                block_tag
+            ])
+        ).await?;
+
+        // "0x": no totalSupply() at all, not a zero supply
+        if supply.as_str().is_some_and(is_empty_word) {
+            return Err(ProviderError::NotFound);
+        }
+
+        // Normally a hex string; some nodes answer with a number
+        let total_supply_raw = quantity::quantity_string(&supply).ok_or(ProviderError::InvalidResponse)?;
+
+        // Assume 18 decimals for now (standard ERC20)
+        let total_supply = quantity::parse_quantity(&supply).map(|raw| raw as f64 / 1e18);
+
+        Ok(SupplyInfo {
+            total_supply_raw: Some(total_supply_raw),
+            total_supply,
+            circulating_supply: None,
+        })
+    }
+
+    /// ERC20 metadata as of a block tag
+    async fn metadata_at(&self, address: &str, block_tag: &str) -> Result<Metadata, ProviderError> {
+        // ERC20 decimals() function signature: 0x313ce567
+        let decimals_data = "0x313ce567";
+
+        // Call decimals(); a revert is a hint this is no ERC20, any other
+        // failure (network, rate limit, pruned state) is not
+        let decimals_result: String = self.rpc_call(
+            "eth_call",
+            json!([
+                {
+                    "to": address,
+                    "data": decimals_data
+                },
+                block_tag
+            ])
+        ).await.or_else(revert_as_empty)?;
+
+        let decimals = parse_uint_word(&decimals_result).and_then(|d| u8::try_from(d).ok());
+
+        // Only ask ERC-165 when decimals() did not answer; genuine ERC20s
+        // cost no extra call
+        let supports_nft_interface = if decimals.is_none() {
+            self.supports_non_fungible_interface(address, block_tag).await
+        } else {
+            false
+        };
+
+        let features = if decimals.is_some() {
+            self.probe_features(address, block_tag).await
+        } else {
+            Vec::new()
+        };
+
+        // Optional in ERC20; a revert or "0x" leaves them unset
+        let (name, symbol) = if decimals.is_some() {
+            (self.string_getter(address, NAME_SELECTOR, block_tag).await,
+             self.string_getter(address, SYMBOL_SELECTOR, block_tag).await)
+        } else {
+            (None, None)
+        };
+
+        Ok(Metadata {
+            name,
+            symbol,
+            decimals,
+            standard: classify_standard(decimals.is_some(), supports_nft_interface),
+            is_initialized: None,
+            image_uri: None,
+            default_account_state: None,
+            features,
+        })
+    }
+
+    /// Owner, pending owner and mintability as of a block tag
+    async fn authorities_at(&self, address: &str, block_tag: &str) -> Result<AuthorityInfo, ProviderError> {
+        // ERC20 owner() function signature: 0x8da5cb5b
+        let owner_data = "0x8da5cb5b";
+
+        let owner_result: String = self.rpc_call(
+            "eth_call",
+            json!([
+                {
+                    "to": address,
+                    "data": owner_data
+                },
+                block_tag
+            ])
+        ).await.or_else(revert_as_empty)?;
+
+        let owner = parse_address_word(&owner_result, &self.chain, &self.burn_addresses);
+
+        // Ownable2Step pendingOwner() function signature: 0xe30c3978
+        // A pending owner can still accept ownership after owner() reads zero
+        let pending_owner_data = "0xe30c3978";
+
+        let pending_owner_result: String = self.rpc_call(
+            "eth_call",
+            json!([
+                {
+                    "to": address,
+                    "data": pending_owner_data
+                },
+                block_tag
+            ])
+        ).await.or_else(revert_as_empty)?;
+
+        let pending_owner = parse_address_word(&pending_owner_result, &self.chain, &self.burn_addresses);
+
+        // Mintable only if the code has a mint entry point and someone can
+        // still call it: the owner, or a holder of the minter role. A proxy's
+        // own code says nothing about its implementation's, and a minter
+        // probe that fails (no AccessControlEnumerable) leaves it open.
+        let code: Result<String, ProviderError> = self.rpc_call(
+            "eth_getCode",
+            json!([address, block_tag])
+        ).await;
+        let mint_mutable = match code {
+            Ok(code) if is_proxy_code(&code) => None,
+            Ok(code) if !has_mint_selector(&code) => Some(false),
+            Ok(_) if owner.is_some() => Some(true),
+            Ok(_) => self.has_minters(address, block_tag).await,
+            Err(_) => None,
+        };
+
+        // A contract owner (multisig, timelock, DAO) versus a bare key
+        let controller_kind = match &owner {
+            Some(owner) => self.rpc_call::<String>("eth_getCode", json!([owner, block_tag])).await
+                .ok()
+                .map(|code| controller_kind_from_code(&code)),
+            None => None,
+        };
+
+        Ok(AuthorityInfo {
+            mint_authority: None, // EVM doesn't use this concept
+            freeze_authority: None, // EVM doesn't use this concept
+            owner,
+            mint_mutable,
+            pending_owner,
+            mint_authority_multisig: None,
+            freeze_authority_multisig: None,
+            controller_kind,
+        })
+    }
+
     async fn rpc_call<T: for<'de> Deserialize<'de>>(
         &self,
         method: &str,
         params: serde_json::Value,
     ) -> Result<T, ProviderError> {
+        let started = Instant::now();
+
+        let text = match self.rpc_request(method, &params).await {
+            Ok(text) => text,
+            Err(e) => {
+                trace::record_call(method, &params, &format!("{:?}", e), started, None);
+                return Err(e);
+            }
+        };
+
+        let result = parse_rpc_response(&text);
+
+        let status = match &result {
+            Ok(_) => "ok".to_string(),
+            Err(e) => format!("{:?}", e),
+        };
+        let body = if trace::wants_bodies() { serde_json::from_str(&text).ok() } else { None };
+        trace::record_call(method, &params, &status, started, body);
+
+        result
+    }
+
+    async fn rpc_request(
+        &self,
+        method: &str,
+        params: &serde_json::Value,
+    ) -> Result<String, ProviderError> {
         let request_body = json!({
             "jsonrpc": "2.0",
             "id": 1,
@@ -35,14 +250,20 @@ impl AlchemyProvider {
             "params": params,
         });
 
-        let client = reqwest::Client::new();
-        let response = client
+        let response = self.client
             .post(&self.rpc_url)
             .json(&request_body)
             .timeout(std::time::Duration::from_secs(10))
             .send()
             .await
-            .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
+            .map_err(ProviderError::from_send)?;
+
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(ProviderError::RateLimited);
+        }
+        if matches!(response.status(), reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN) {
+            return Err(ProviderError::Unauthorized);
+        }
 
         if !response.status().is_success() {
             let status = response.status();
@@ -55,135 +276,407 @@ impl AlchemyProvider {
             .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
         
         eprintln!("RPC Response: {}", text);
-        
-        let rpc_response: RpcResponse<T> = serde_json::from_str(&text)
-            .map_err(|e| {
-                eprintln!("JSON Parse Error: {}", e);
-                ProviderError::InvalidResponse
-            })?;
 
-        rpc_response.result.ok_or(ProviderError::InvalidResponse)
+        Ok(text)
+    }
+
+    /// ERC-165 probe for ERC-721/1155; contracts without ERC-165 revert,
+    /// which reads as "no"
+    async fn supports_non_fungible_interface(&self, address: &str, block_tag: &str) -> bool {
+        for interface_id in NON_FUNGIBLE_INTERFACES {
+            let data = format!("{}{:0<64}", SUPPORTS_INTERFACE_SELECTOR, interface_id);
+            let result: Result<String, ProviderError> = self.rpc_call(
+                "eth_call",
+                json!([{ "to": address, "data": data }, block_tag]),
+            ).await;
+            if result.is_ok_and(|r| parse_bool_word(&r)) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Answer of a `string`- (or legacy `bytes32`-) returning getter
+    async fn string_getter(&self, address: &str, selector: &str, block_tag: &str) -> Option<String> {
+        let result: Result<String, ProviderError> = self.rpc_call(
+            "eth_call",
+            json!([{ "to": address, "data": selector }, block_tag]),
+        ).await;
+        result.ok().and_then(|r| parse_string_word(&r))
+    }
+
+    /// AccessControlEnumerable: whether any account holds MINTER_ROLE;
+    /// `None` when the contract does not answer `getRoleMemberCount`
+    async fn has_minters(&self, address: &str, block_tag: &str) -> Option<bool> {
+        let data = format!("{}{}", ROLE_MEMBER_COUNT_SELECTOR, MINTER_ROLE);
+        let result: Result<String, ProviderError> = self.rpc_call(
+            "eth_call",
+            json!([{ "to": address, "data": data }, block_tag]),
+        ).await;
+        result.ok()
+            .and_then(|count| parse_uint_word(&count))
+            .map(|count| count > 0)
+    }
+
+    /// Gasless-approval/transfer interfaces, detected by calling their view
+    /// functions. Best effort: a failed probe just leaves the feature out.
+    async fn probe_features(&self, address: &str, block_tag: &str) -> Vec<TokenFeature> {
+        let answers = |data: String| async move {
+            let result: Result<String, ProviderError> = self.rpc_call(
+                "eth_call",
+                json!([{ "to": address, "data": data }, block_tag]),
+            ).await;
+            result.ok().filter(|r| r.trim_start_matches("0x").len() >= 64)
+        };
+        let zero_word = "0".repeat(64);
+
+        let mut features = Vec::new();
+        // EIP-2612: a domain separator and per-owner nonces
+        let domain_separator = answers(DOMAIN_SEPARATOR_SELECTOR.to_string()).await;
+        if domain_separator.is_some_and(|word| !word.trim_start_matches("0x").trim_start_matches('0').is_empty())
+            && answers(format!("{}{}", NONCES_SELECTOR, zero_word)).await.is_some()
+        {
+            features.push(TokenFeature::Permit);
+        }
+        // EIP-3009: authorizationState(authorizer, nonce) is its view function
+        if answers(format!("{}{}{}", AUTHORIZATION_STATE_SELECTOR, zero_word, zero_word)).await.is_some() {
+            features.push(TokenFeature::TransferWithAuthorization);
+        }
+        features
     }
 }
 
+/// Mint entry points: `mint(address,uint256)`, `mint(uint256)`
+const MINT_SELECTORS: [&str; 2] = ["40c10f19", "a0712d68"];
+/// AccessControlEnumerable `getRoleMemberCount(bytes32)`
+const ROLE_MEMBER_COUNT_SELECTOR: &str = "0xca15c873";
+/// `keccak256("MINTER_ROLE")`, the OpenZeppelin minter role id
+const MINTER_ROLE: &str = "9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6";
+
+/// EIP-1167 minimal proxy runtime prefix
+const MINIMAL_PROXY_PREFIX: &str = "363d3d373d3d3d363d73";
+/// EIP-1967 implementation and beacon slots a proxy's code loads
+const PROXY_SLOTS: [&str; 2] = [
+    "360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc",
+    "a3f0ad74e5423aebfd80d3ef4346578335a9a72aeaee59ff6cb3582b35133d50",
+];
+
+const PUSH1: u8 = 0x60;
+const PUSH4: u8 = 0x63;
+const PUSH32: u8 = 0x7f;
+
+/// Opcode and immediate data of each instruction in `code`. Walking the
+/// instructions keeps matches on real operands: the same bytes inside
+/// another push's data, or straddling two instructions, are not compared.
+fn instructions(code: &[u8]) -> impl Iterator<Item = (u8, &[u8])> {
+    let mut pc = 0;
+    std::iter::from_fn(move || {
+        let opcode = *code.get(pc)?;
+        let width = if (PUSH1..=PUSH32).contains(&opcode) { (opcode - PUSH1 + 1) as usize } else { 0 };
+        let data = &code[(pc + 1).min(code.len())..(pc + 1 + width).min(code.len())];
+        pc += 1 + width;
+        Some((opcode, data))
+    })
+}
+
+/// Whether `code` pushes `operand` (hex) with `push`
+fn pushes(code: &[u8], push: u8, operand: &str) -> bool {
+    instructions(code).any(|(opcode, data)| opcode == push && hex::encode(data) == operand)
+}
+
+fn decode_code(code: &str) -> Vec<u8> {
+    hex::decode(code.trim_start_matches("0x")).unwrap_or_default()
+}
+
+/// Whether the code delegates to an implementation held elsewhere
+fn is_proxy_code(code: &str) -> bool {
+    let code = decode_code(code);
+    hex::encode(&code).starts_with(MINIMAL_PROXY_PREFIX)
+        || PROXY_SLOTS.iter().any(|slot| pushes(&code, PUSH32, slot))
+}
+
+/// Whether the function dispatcher compares against a mint selector
+/// (`PUSH4 <selector>`)
+fn has_mint_selector(code: &str) -> bool {
+    let code = decode_code(code);
+    MINT_SELECTORS.iter().any(|selector| pushes(&code, PUSH4, selector))
+}
+
+/// `mint(address,uint256)`, called with recipient 0x…01 and amount 1
+const MINT_CALL_SELECTOR: &str = "0x40c10f19";
+/// Unprivileged `from` of the simulated mint
+const SIMULATION_CALLER: &str = "0x0000000000000000000000000000000000000000";
+/// OpenZeppelin `OwnableUnauthorizedAccount(address)`,
+/// `AccessControlUnauthorizedAccount(address,bytes32)`
+const UNAUTHORIZED_ERRORS: [&str; 2] = ["118cdaa7", "e2517d3f"];
+/// Revert-message fragments of access-control guards. Specific phrases
+/// only: a reason that merely mentions "only" or "access" can be any check.
+const UNAUTHORIZED_HINTS: [&str; 8] = [
+    "caller is not", "not the owner", "unauthorized", "not authorized", "accesscontrol",
+    "missing role", "not minter", "permission denied",
+];
+
+/// Read a reverted `mint` call. Access-control errors mean the function
+/// exists; a bare revert with no data is what a dispatcher without a
+/// matching selector (and no fallback) produces, but also what a bare
+/// `require` does, so `simulate_mint` checks it against the bytecode.
+fn classify_mint_revert(error: &serde_json::Value) -> (MintCallOutcome, Option<String>) {
+    let message = error["message"].as_str().unwrap_or_default();
+    let reason = message.trim_start_matches("execution reverted").trim_start_matches(':').trim();
+    let data = error["data"].as_str().unwrap_or_default().trim_start_matches("0x").to_ascii_lowercase();
+    let revert_reason = (!reason.is_empty()).then(|| reason.to_string());
+
+    let outcome = if reason.is_empty() && data.is_empty() {
+        MintCallOutcome::UnknownSelector
+    } else if UNAUTHORIZED_ERRORS.iter().any(|selector| data.starts_with(selector))
+        || UNAUTHORIZED_HINTS.iter().any(|hint| reason.to_ascii_lowercase().contains(hint))
+    {
+        MintCallOutcome::Unauthorized
+    } else {
+        MintCallOutcome::Inconclusive
+    };
+    (outcome, revert_reason)
+}
+
+/// EIP-2612 `DOMAIN_SEPARATOR()`
+const DOMAIN_SEPARATOR_SELECTOR: &str = "0x3644e515";
+/// EIP-2612 `nonces(address)`
+const NONCES_SELECTOR: &str = "0x7ecebe00";
+/// EIP-3009 `authorizationState(address,bytes32)`
+const AUTHORIZATION_STATE_SELECTOR: &str = "0xe94a0102";
+
+fn parse_rpc_response<T: for<'de> Deserialize<'de>>(text: &str) -> Result<T, ProviderError> {
+    let rpc_response: RpcResponse<T> = serde_json::from_str(text)
+        .map_err(|e| {
+            eprintln!("JSON Parse Error: {}", e);
+            ProviderError::InvalidResponse
+        })?;
+
+    if let Some(error) = rpc_response.error {
+        eprintln!("RPC Error Body: {}", error);
+        if let Some(reason) = revert_message(&error) {
+            return Err(ProviderError::Reverted(reason));
+        }
+    }
+
+    rpc_response.result.ok_or(ProviderError::InvalidResponse)
+}
+
+/// A reverted getter is an absent one; any other failure (a pruned node's
+/// "missing trie node", a timeout) must not read as "no owner"
+fn revert_as_empty(error: ProviderError) -> Result<String, ProviderError> {
+    match error {
+        ProviderError::Reverted(_) => Ok("0x".to_string()),
+        e => Err(e),
+    }
+}
+
+/// Message of an `eth_call` error that is an EVM revert (code 3, or the
+/// "execution reverted" message nodes use without it); `None` for node-side
+/// failures such as "missing trie node"
+fn revert_message(error: &serde_json::Value) -> Option<String> {
+    let message = error["message"].as_str().unwrap_or("");
+    let reverted = error["code"].as_i64() == Some(3) || message.starts_with("execution reverted");
+    reverted.then(|| message.to_string())
+}
+
 #[derive(Debug, Deserialize)]
 struct RpcResponse<T> {
     result: Option<T>,
     error: Option<serde_json::Value>,
 }
 
+/// Getters commonly exposed by taxed tokens for the fee recipient, with their selectors
+const TAX_WALLET_GETTERS: [(&str, &str); 3] = [
+    ("0x2dc0562d", "taxWallet()"),
+    ("0x75f0a874", "marketingWallet()"),
+    ("0xf25f4b56", "feeWallet()"),
+];
+
+/// `eth_call` on a function the contract does not implement (and without
+/// a fallback that reverts) returns `"0x"`: the function is absent
+fn is_empty_word(result: &str) -> bool {
+    result.trim().trim_start_matches("0x").is_empty()
+}
+
+/// Unsigned integer from an `eth_call` result word; `None` for an absent
+/// function or a malformed word
+fn parse_uint_word(result: &str) -> Option<u128> {
+    let word = result.trim().trim_start_matches("0x");
+    if word.is_empty() || !word.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    // Leading zero padding would otherwise overflow a 32-byte word
+    match word.trim_start_matches('0') {
+        "" => Some(0),
+        digits => u128::from_str_radix(digits, 16).ok(),
+    }
+}
+
+/// ERC20 `name()` and `symbol()`
+const NAME_SELECTOR: &str = "0x06fdde03";
+const SYMBOL_SELECTOR: &str = "0x95d89b41";
+
+/// Text from an `eth_call` result: an ABI-encoded `string` (offset,
+/// length, bytes) or, for early tokens such as MKR, a zero-padded
+/// `bytes32`. `None` when absent, malformed, empty or not printable.
+fn parse_string_word(result: &str) -> Option<String> {
+    let bytes = hex::decode(result.trim().trim_start_matches("0x")).ok()?;
+    let text = if bytes.len() == 32 {
+        let end = bytes.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+        &bytes[..end]
+    } else {
+        let word = |at: usize| -> Option<usize> {
+            let word = bytes.get(at..at.checked_add(32)?)?;
+            // Offsets and lengths past 8 bytes cannot fit in a response
+            if word[..24].iter().any(|&b| b != 0) {
+                return None;
+            }
+            Some(u64::from_be_bytes(word[24..].try_into().ok()?) as usize)
+        };
+        let offset = word(0)?;
+        let len = word(offset)?;
+        let start = offset.checked_add(32)?;
+        bytes.get(start..start.checked_add(len)?)?
+    };
+    let text = String::from_utf8(text.to_vec()).ok()?;
+    let text = text.trim();
+    if text.is_empty() || text.chars().any(char::is_control) {
+        return None;
+    }
+    Some(text.to_string())
+}
+
+/// Extract an address from a 32-byte `eth_call` result word.
+/// Burn addresses of `chain` are treated as "no address".
+fn parse_address_word(result: &str, chain: &str, burn_addresses: &BurnAddresses) -> Option<String> {
+    // Extract address from result (last 40 chars)
+    let word = result.trim().trim_start_matches("0x");
+    if word.len() < 40 || !word.is_ascii() {
+        return None;
+    }
+    let addr = format!("0x{}", &word[word.len()-40..]).to_ascii_lowercase();
+
+    // Check if address is zero address or burn address
+    if burn_addresses.is_burn(chain, &addr) {
+        None
+    } else {
+        Some(addr)
+    }
+}
+
+/// Contract or EOA from an `eth_getCode` result. An EIP-7702 delegation
+/// designator (`0xef0100` + address) is still an EOA: its key signs.
+fn controller_kind_from_code(code: &str) -> ControllerKind {
+    let code = code.trim().trim_start_matches("0x");
+    let delegated = code.len() == 46 && code.starts_with("ef0100");
+    if code.trim_start_matches('0').is_empty() || delegated {
+        ControllerKind::Key
+    } else {
+        ControllerKind::Program
+    }
+}
+
+/// ERC-165 interface ids of non-fungible standards: ERC-721, ERC-1155
+const NON_FUNGIBLE_INTERFACES: [&str; 2] = ["80ac58cd", "d9b67a26"];
+
+/// ERC-165 supportsInterface(bytes4) function signature
+const SUPPORTS_INTERFACE_SELECTOR: &str = "0x01ffc9a7";
+
+/// `supportsInterface` returns an ABI-encoded bool
+fn parse_bool_word(result: &str) -> bool {
+    let word = result.trim_start_matches("0x");
+    !word.is_empty() && word.trim_start_matches('0') == "1"
+}
+
+/// ERC20 when `decimals()` answered; otherwise (reverted, or the contract
+/// advertises an NFT interface) not something this tool can analyze
+fn classify_standard(decimals_answered: bool, supports_nft_interface: bool) -> TokenStandard {
+    if decimals_answered && !supports_nft_interface {
+        TokenStandard::Erc20
+    } else {
+        TokenStandard::NonFungibleOrUnknown
+    }
+}
+
+/// `eth_getCode` returns "0x" for externally owned accounts
+fn has_code(code: &str) -> bool {
+    !code.trim_start_matches("0x").is_empty()
+}
+
 #[async_trait]
 impl TokenProvider for AlchemyProvider {
     fn provider_name(&self) -> &str {
         "alchemy"
     }
 
-    async fn fetch_metadata(&self, address: &str) -> Result<Metadata, ProviderError> {
-        // ERC20 decimals() function signature: 0x313ce567
-        let decimals_data = "0x313ce567";
+    fn capabilities(&self) -> ProviderCapabilities {
+        // No holder API or transaction history yet; rent is a Solana concept
+        ProviderCapabilities {
+            holders: false,
+            creation: false,
+            authority_history: false,
+            rent: false,
+            ..ProviderCapabilities::all()
+        }
+    }
 
-        // Call decimals()
-        let decimals_result: String = self.rpc_call(
-            "eth_call",
-            json!([
-                {
-                    "to": address,
-                    "data": decimals_data
-                },
-                "latest"
-            ])
-        ).await?;
+    async fn ping(&self) -> Result<(), ProviderError> {
+        self.rpc_call::<serde_json::Value>("eth_chainId", json!([])).await.map(|_| ())
+    }
 
-        let decimals = if decimals_result.len() > 2 {
-            u8::from_str_radix(&decimals_result[2..], 16).ok()
-        } else {
-            None
-        };
+    async fn fetch_metadata(&self, address: &str) -> Result<Metadata, ProviderError> {
+        self.metadata_at(address, "latest").await
+    }
 
-        Ok(Metadata {
-            name: None,
-            symbol: None,
-            decimals,
-            standard: TokenStandard::Erc20,
-        })
+    async fn fetch_metadata_at(&self, address: &str, block: u64) -> Result<Metadata, ProviderError> {
+        self.metadata_at(address, &format!("0x{:x}", block)).await
     }
 
     async fn fetch_supply(&self, address: &str) -> Result<SupplyInfo, ProviderError> {
-        // ERC20 totalSupply() function signature: 0x18160ddd
-        let total_supply_data = "0x18160ddd";
-
-        let supply_hex: String = self.rpc_call(
-            "eth_call",
-            json!([
-                {
-                    "to": address,
-                    "data": total_supply_data
-                },
-                "latest"
-            ])
-        ).await?;
-
-        let total_supply_raw = supply_hex.trim_start_matches("0x").to_string();
-        
-        // Convert hex to decimal
-        let total_supply = if let Ok(raw) = u128::from_str_radix(&total_supply_raw, 16) {
-            // Assume 18 decimals for now (standard ERC20)
-            Some(raw as f64 / 1e18)
-        } else {
-            None
-        };
+        self.total_supply_at(address, "latest").await
+    }
 
-        Ok(SupplyInfo {
-            total_supply_raw: Some(supply_hex),
-            total_supply,
-        })
+    async fn fetch_supply_at(&self, address: &str, block: u64) -> Result<SupplyInfo, ProviderError> {
+        // Needs an archive node for blocks past the pruning window
+        self.total_supply_at(address, &format!("0x{:x}", block)).await
     }
 
     async fn fetch_authorities(&self, address: &str) -> Result<AuthorityInfo, ProviderError> {
-        // ERC20 owner() function signature: 0x8da5cb5b
-        let owner_data = "0x8da5cb5b";
+        self.authorities_at(address, "latest").await
+    }
 
-        let owner_result: String = self.rpc_call(
-            "eth_call",
-            json!([
-                {
-                    "to": address,
-                    "data": owner_data
-                },
-                "latest"
-            ])
-        ).await.unwrap_or_else(|_| "0x".to_string());
-
-        // Extract address from result (last 40 chars)
-        let owner = if owner_result.len() >= 42 {
-            let addr = format!("0x{}", &owner_result[owner_result.len()-40..]);
-            
-            // Check if owner is zero address or burn address
-            if addr == "0x0000000000000000000000000000000000000000" 
-               || addr == "0x000000000000000000000000000000000000dead" {
-                None
-            } else {
-                Some(addr)
-            }
-        } else {
-            None
-        };
+    async fn fetch_authorities_at(&self, address: &str, block: u64) -> Result<AuthorityInfo, ProviderError> {
+        self.authorities_at(address, &format!("0x{:x}", block)).await
+    }
 
-        let mint_mutable = owner.is_some();
+    async fn fetch_bytecode(&self, address: &str) -> Result<BytecodeInfo, ProviderError> {
+        let code_hex: String = self.rpc_call(
+            "eth_getCode",
+            json!([address, "latest"])
+        ).await?;
 
-        Ok(AuthorityInfo {
-            mint_authority: None, // EVM doesn't use this concept
-            freeze_authority: None, // EVM doesn't use this concept
-            owner,
-            mint_mutable: Some(mint_mutable),
+        let code = hex::decode(code_hex.trim_start_matches("0x"))
+            .map_err(|_| ProviderError::InvalidResponse)?;
+
+        Ok(BytecodeInfo {
+            code_hash: format!("0x{}", hex::encode(Keccak256::digest(&code))),
+            code_size: code.len(),
         })
     }
 
-    async fn fetch_holders(&self, _address: &str, _limit: usize) -> Result<HolderInfo, ProviderError> {
+    async fn fetch_holders(
+        &self,
+        _address: &str,
+        _limit: usize,
+        _strategy: HolderStrategy,
+    ) -> Result<HolderInfo, ProviderError> {
         // Would require Alchemy's token holder API
         Ok(HolderInfo {
             top1_pct: None,
             top5_pct: None,
+            holder_count: None,
+            unlisted_balance_raw: None,
             top_holders: vec![],
         })
     }
@@ -194,6 +687,90 @@ impl TokenProvider for AlchemyProvider {
             created_at: None,
             age_seconds: None,
             age_band: AgeBand::Unknown,
+            estimated: false,
+        })
+    }
+
+    async fn fetch_tax_info(&self, address: &str) -> Result<TaxInfo, ProviderError> {
+        // Read the first configured tax-wallet getter the contract implements
+        for (selector, getter) in TAX_WALLET_GETTERS {
+            // A reverted or empty getter is not implemented; try the next
+            let result: String = self.rpc_call(
+                "eth_call",
+                json!([
+                    {
+                        "to": address,
+                        "data": selector
+                    },
+                    "latest"
+                ])
+            ).await.or_else(revert_as_empty)?;
+
+            if let Some(recipient) = parse_address_word(&result, &self.chain, &self.burn_addresses) {
+                // A contract recipient (pair, router, splitter) vs a bare wallet
+                let code: String = self.rpc_call(
+                    "eth_getCode",
+                    json!([recipient, "latest"])
+                ).await?;
+
+                return Ok(TaxInfo {
+                    tax_recipient: Some(recipient),
+                    recipient_is_contract: Some(has_code(&code)),
+                    detection_method: Some(getter.to_string()),
+                });
+            }
+        }
+
+        // No tax-wallet getter found: routing is undetectable
+        Ok(TaxInfo {
+            tax_recipient: None,
+            recipient_is_contract: None,
+            detection_method: None,
+        })
+    }
+
+    async fn simulate_mint(&self, address: &str) -> Result<MintSimulation, ProviderError> {
+        let data = format!("{}{:0>64}{:0>64}", MINT_CALL_SELECTOR, "1", "1");
+        let params = json!([{ "from": SIMULATION_CALLER, "to": address, "data": data }, "latest"]);
+        let started = Instant::now();
+
+        // The revert body is the answer here, so read it instead of going
+        // through `rpc_call`, which folds every RPC error into one variant
+        let text = match self.rpc_request("eth_call", &params).await {
+            Ok(text) => text,
+            Err(e) => {
+                trace::record_call("eth_call", &params, &format!("{:?}", e), started, None);
+                return Err(e);
+            }
+        };
+        let body: Option<serde_json::Value> = serde_json::from_str(&text).ok();
+        let reply: Option<RpcResponse<String>> = serde_json::from_str(&text).ok();
+        let status = if reply.as_ref().is_some_and(|r| r.error.is_some()) { "reverted" } else { "ok" };
+        trace::record_call("eth_call", &params, status, started, body.filter(|_| trace::wants_bodies()));
+
+        let (mut outcome, revert_reason) = match reply {
+            Some(RpcResponse { error: Some(error), .. }) => classify_mint_revert(&error),
+            Some(RpcResponse { result: Some(_), .. }) => (MintCallOutcome::Succeeded, None),
+            _ => return Err(ProviderError::InvalidResponse),
+        };
+
+        // A bare revert from code that does dispatch a mint selector (or
+        // hides its dispatcher behind a proxy) is a guard without a
+        // message, not a missing function
+        if outcome == MintCallOutcome::UnknownSelector {
+            let code: Result<String, ProviderError> = self.rpc_call(
+                "eth_getCode",
+                json!([address, "latest"]),
+            ).await;
+            if !code.is_ok_and(|code| !has_mint_selector(&code) && !is_proxy_code(&code)) {
+                outcome = MintCallOutcome::Inconclusive;
+            }
+        }
+
+        Ok(MintSimulation {
+            outcome,
+            revert_reason,
+            caller: SIMULATION_CALLER.to_string(),
         })
     }
 }
@@ -201,6 +778,420 @@ impl TokenProvider for AlchemyProvider {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::providers::rpc_harness::{rpc_error, rpc_result, serve_rpc};
+
+    #[test]
+    fn test_parse_address_word() {
+        let burn = BurnAddresses::default();
+        let parse = |word: &str| parse_address_word(word, "base", &burn);
+        let word = "0x000000000000000000000000abcdef0123456789abcdef0123456789abcdef01";
+        assert_eq!(parse(word), Some("0xabcdef0123456789abcdef0123456789abcdef01".to_string()));
+
+        let zero = format!("0x{}", "0".repeat(64));
+        assert_eq!(parse(&zero), None);
+        assert_eq!(parse("0x"), None);
+        assert_eq!(parse(""), None);
+
+        // Operator-configured burn addresses count too
+        let custom = BurnAddresses::default().with_address("base", "0xabcdef0123456789abcdef0123456789abcdef01");
+        assert_eq!(parse_address_word(word, "base", &custom), None);
+    }
+
+    #[test]
+    fn test_minter_role_matches_its_digest() {
+        assert_eq!(hex::encode(Keccak256::digest(b"MINTER_ROLE")), MINTER_ROLE);
+    }
+
+    #[test]
+    fn test_empty_result_decodes_as_absent() {
+        assert_eq!(parse_uint_word("0x"), None);
+        assert_eq!(parse_uint_word(""), None);
+        assert_eq!(parse_uint_word("0xzz"), None);
+        assert_eq!(parse_uint_word("0x0"), Some(0));
+        assert_eq!(parse_uint_word(&format!("0x{:0>64}", "12")), Some(18));
+        assert!(!parse_bool_word("0x"));
+        assert_eq!(controller_kind_from_code("0x"), ControllerKind::Key);
+        assert!(!has_code("0x"));
+    }
+
+    #[test]
+    fn test_parse_string_word() {
+        // ABI string "USD Coin"
+        let encoded = format!(
+            "0x{:0>64}{:0>64}{:0<64}",
+            "20", "8", hex::encode("USD Coin")
+        );
+        assert_eq!(parse_string_word(&encoded), Some("USD Coin".to_string()));
+
+        // Legacy bytes32 "MKR"
+        let bytes32 = format!("0x{:0<64}", hex::encode("MKR"));
+        assert_eq!(parse_string_word(&bytes32), Some("MKR".to_string()));
+
+        assert_eq!(parse_string_word("0x"), None);
+        assert_eq!(parse_string_word(&format!("0x{:0>64}", "12")), None);
+        // Length running past the data
+        assert_eq!(parse_string_word(&format!("0x{:0>64}{:0>64}", "20", "ff")), None);
+    }
+
+    #[tokio::test]
+    async fn test_empty_call_results_are_absent_not_zero() {
+        // A contract answering "0x" to every call implements none of them
+        let url = serve_rpc(|_| rpc_result(json!("0x"))).await;
+        let provider = AlchemyProvider::with_rpc_url(url);
+
+        let metadata = provider.fetch_metadata("0xempty").await.unwrap();
+        assert_eq!(metadata.decimals, None);
+        assert_eq!(metadata.standard, TokenStandard::NonFungibleOrUnknown);
+
+        assert!(matches!(provider.fetch_supply("0xempty").await, Err(ProviderError::NotFound)));
+
+        let authorities = provider.fetch_authorities("0xempty").await.unwrap();
+        assert_eq!(authorities.owner, None);
+        assert_eq!(authorities.pending_owner, None);
+        assert_eq!(authorities.controller_kind, None);
+
+        let tax = provider.fetch_tax_info("0xempty").await.unwrap();
+        assert_eq!(tax.tax_recipient, None);
+    }
+
+    #[test]
+    fn test_owner_code_classifies_controller() {
+        assert_eq!(controller_kind_from_code("0x"), ControllerKind::Key);
+        assert_eq!(controller_kind_from_code("0x0"), ControllerKind::Key);
+        let delegated = format!("0xef0100{}", "ab".repeat(20));
+        assert_eq!(controller_kind_from_code(&delegated), ControllerKind::Key);
+        assert_eq!(controller_kind_from_code("0x608060405234801561001057600080fd5b50"), ControllerKind::Program);
+    }
+
+    #[test]
+    fn test_erc721_classified_non_fungible() {
+        // ERC-721: decimals() reverts, supportsInterface(0x80ac58cd) is true
+        let supports = format!("0x{:0>64}", "1");
+        assert!(parse_bool_word(&supports));
+        assert_eq!(classify_standard(false, true), TokenStandard::NonFungibleOrUnknown);
+
+        // Non-token contract: decimals() reverts, no ERC-165
+        assert!(!parse_bool_word("0x"));
+        assert!(!parse_bool_word(&format!("0x{}", "0".repeat(64))));
+        assert_eq!(classify_standard(false, false), TokenStandard::NonFungibleOrUnknown);
+    }
+
+    #[test]
+    fn test_erc20_classified_erc20() {
+        assert_eq!(classify_standard(true, false), TokenStandard::Erc20);
+    }
+
+    #[tokio::test]
+    async fn test_erc721_detected_over_rpc() {
+        // decimals() reverts; supportsInterface answers true for ERC-721 only
+        let url = serve_rpc(|request| {
+            let data = request["params"][0]["data"].as_str().unwrap_or("").to_string();
+            if data == "0x313ce567" {
+                rpc_error(3, "execution reverted")
+            } else if data.starts_with(SUPPORTS_INTERFACE_SELECTOR) && data.contains("80ac58cd") {
+                rpc_result(json!(format!("0x{:0>64}", "1")))
+            } else {
+                rpc_result(json!(format!("0x{}", "0".repeat(64))))
+            }
+        }).await;
+
+        let metadata = AlchemyProvider::with_rpc_url(url).fetch_metadata("0xnft").await.unwrap();
+
+        assert_eq!(metadata.standard, TokenStandard::NonFungibleOrUnknown);
+    }
+
+    #[tokio::test]
+    async fn test_node_error_is_not_a_revert() {
+        // A pruned node failing decimals() says nothing about the contract
+        let url = serve_rpc(|_| rpc_error(-32000, "missing trie node")).await;
+
+        let err = AlchemyProvider::with_rpc_url(url).fetch_metadata("0xtoken").await.unwrap_err();
+
+        assert!(matches!(err, ProviderError::InvalidResponse), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_tax_getter_failure_is_an_error() {
+        let url = serve_rpc(|_| (axum::http::StatusCode::TOO_MANY_REQUESTS, String::new())).await;
+
+        let err = AlchemyProvider::with_rpc_url(url).fetch_tax_info("0xtoken").await.unwrap_err();
+
+        assert!(matches!(err, ProviderError::RateLimited), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_reverted_tax_getters_are_skipped() {
+        let url = serve_rpc(|_| rpc_error(3, "execution reverted")).await;
+
+        let tax = AlchemyProvider::with_rpc_url(url).fetch_tax_info("0xtoken").await.unwrap();
+
+        assert_eq!(tax.tax_recipient, None);
+        assert_eq!(tax.detection_method, None);
+    }
+
+    #[tokio::test]
+    async fn test_bytecode_hash_is_keccak256() {
+        let url = serve_rpc(|_| rpc_result(json!("0x"))).await;
+
+        let bytecode = AlchemyProvider::with_rpc_url(url).fetch_bytecode("0xtoken").await.unwrap();
+
+        assert_eq!(bytecode.code_size, 0);
+        assert_eq!(
+            bytecode.code_hash,
+            "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_erc20_decimals_over_rpc() {
+        let url = serve_rpc(|_| rpc_result(json!(format!("0x{:0>64}", "12")))).await;
+
+        let metadata = AlchemyProvider::with_rpc_url(url).fetch_metadata("0xtoken").await.unwrap();
+
+        assert_eq!(metadata.standard, TokenStandard::Erc20);
+        assert_eq!(metadata.decimals, Some(18));
+    }
+
+    #[tokio::test]
+    async fn test_permit_and_3009_detected() {
+        let url = serve_rpc(|request| {
+            let data = request["params"][0]["data"].as_str().unwrap_or_default();
+            if data.starts_with(DOMAIN_SEPARATOR_SELECTOR) {
+                rpc_result(json!(format!("0x{}", "ab".repeat(32))))
+            } else {
+                // decimals(), nonces() and authorizationState() answer a word
+                rpc_result(json!(format!("0x{:0>64}", "12")))
+            }
+        }).await;
+
+        let metadata = AlchemyProvider::with_rpc_url(url).fetch_metadata("0xusdc").await.unwrap();
+
+        assert_eq!(metadata.standard, TokenStandard::Erc20);
+        assert_eq!(metadata.features, vec![TokenFeature::Permit, TokenFeature::TransferWithAuthorization]);
+    }
+
+    #[tokio::test]
+    async fn test_plain_erc20_has_no_features() {
+        let url = serve_rpc(|request| {
+            let data = request["params"][0]["data"].as_str().unwrap_or_default();
+            if data.starts_with("0x313ce567") {
+                rpc_result(json!(format!("0x{:0>64}", "12")))
+            } else {
+                rpc_error(3, "execution reverted")
+            }
+        }).await;
+
+        let metadata = AlchemyProvider::with_rpc_url(url).fetch_metadata("0xplain").await.unwrap();
+
+        assert_eq!(metadata.standard, TokenStandard::Erc20);
+        assert!(metadata.features.is_empty());
+    }
+
+    /// Serves `owner()`, minter-role count and code for authority tests
+    async fn authority_provider(owned: bool, minters: u8, code: &'static str) -> AlchemyProvider {
+        let owner = if owned { "ab".repeat(20) } else { "0".to_string() };
+        let url = serve_rpc(move |request| {
+            if request["method"] == "eth_getCode" {
+                return rpc_result(json!(code));
+            }
+            let data = request["params"][0]["data"].as_str().unwrap_or_default();
+            if data == "0x8da5cb5b" {
+                rpc_result(json!(format!("0x{:0>64}", owner)))
+            } else if data.starts_with(ROLE_MEMBER_COUNT_SELECTOR) {
+                rpc_result(json!(format!("0x{:0>64x}", minters)))
+            } else {
+                rpc_error(3, "execution reverted")
+            }
+        }).await;
+        AlchemyProvider::with_rpc_url(url)
+    }
+
+    const MINTABLE_CODE: &str = "0x608060405234801561001057600080fd5b5063a9059cbb1461003057806340c10f1914610035";
+    const FIXED_CODE: &str = "0x608060405234801561001057600080fd5b5063a9059cbb1461003057806318160ddd14610035";
+
+    #[test]
+    fn test_selectors_match_only_push4_operands() {
+        assert!(has_mint_selector(MINTABLE_CODE));
+        assert!(!has_mint_selector(FIXED_CODE));
+
+        // "6340c10f19" inside a PUSH32 operand is data, not a dispatch
+        let embedded = format!("0x7f{:0<64}00", "6340c10f19");
+        assert!(!has_mint_selector(&embedded));
+        // Nor does it count when it straddles two instructions
+        assert!(!has_mint_selector("0x6163406140c10f19"));
+    }
+
+    #[test]
+    fn test_proxy_slot_must_be_pushed() {
+        let slot = PROXY_SLOTS[0];
+        assert!(is_proxy_code(&format!("0x7f{}54", slot)));
+        assert!(!is_proxy_code(&format!("0x00{}", slot)));
+    }
+
+    #[tokio::test]
+    async fn test_owned_mintable_token_is_mutable() {
+        let provider = authority_provider(true, 0, MINTABLE_CODE).await;
+        let authorities = provider.fetch_authorities("0xtoken").await.unwrap();
+        assert!(authorities.owner.is_some());
+        assert_eq!(authorities.mint_mutable, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_renounced_token_with_minter_role_is_mutable() {
+        let provider = authority_provider(false, 1, MINTABLE_CODE).await;
+        let authorities = provider.fetch_authorities("0xtoken").await.unwrap();
+        assert_eq!(authorities.owner, None);
+        assert_eq!(authorities.mint_mutable, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_fixed_supply_token_is_immutable() {
+        // Owned, but there is no mint function to call
+        let provider = authority_provider(true, 1, FIXED_CODE).await;
+        let authorities = provider.fetch_authorities("0xtoken").await.unwrap();
+        assert!(authorities.owner.is_some());
+        assert_eq!(authorities.mint_mutable, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_renounced_mintable_without_minters_is_immutable() {
+        let provider = authority_provider(false, 0, MINTABLE_CODE).await;
+        let authorities = provider.fetch_authorities("0xtoken").await.unwrap();
+        assert_eq!(authorities.mint_mutable, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_proxy_mintability_is_unknown() {
+        // EIP-1167 clone: no mint selector of its own
+        let provider = authority_provider(false, 0, "0x363d3d373d3d3d363d73bebebebebebebebebebebebebebebebebebebebe5af43d82803e903d91602b57fd5bf3").await;
+        let authorities = provider.fetch_authorities("0xtoken").await.unwrap();
+        assert_eq!(authorities.mint_mutable, None);
+    }
+
+    #[tokio::test]
+    async fn test_owner_read_failure_propagates() {
+        let url = serve_rpc(|_| rpc_error(-32000, "missing trie node")).await;
+
+        let err = AlchemyProvider::with_rpc_url(url).fetch_authorities_at("0xtoken", 1).await.unwrap_err();
+
+        assert!(matches!(err, ProviderError::InvalidResponse), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_failed_minter_probe_is_unknown() {
+        // Renounced, mintable, and no AccessControlEnumerable to ask
+        let url = serve_rpc(|request| {
+            if request["method"] == "eth_getCode" {
+                return rpc_result(json!(MINTABLE_CODE));
+            }
+            let data = request["params"][0]["data"].as_str().unwrap_or_default();
+            if data == "0x8da5cb5b" {
+                rpc_result(json!(format!("0x{:0>64}", 0)))
+            } else {
+                rpc_error(3, "execution reverted")
+            }
+        }).await;
+        let authorities = AlchemyProvider::with_rpc_url(url).fetch_authorities("0xtoken").await.unwrap();
+        assert_eq!(authorities.mint_mutable, None);
+    }
+
+    async fn simulate_mint_reply(reply: fn() -> crate::providers::rpc_harness::RpcReply) -> MintSimulation {
+        simulate_mint_with_code(reply, FIXED_CODE).await
+    }
+
+    async fn simulate_mint_with_code(
+        reply: fn() -> crate::providers::rpc_harness::RpcReply,
+        code: &'static str,
+    ) -> MintSimulation {
+        let url = serve_rpc(move |request| {
+            if request["method"] == "eth_getCode" {
+                return rpc_result(json!(code));
+            }
+            assert!(request["params"][0]["data"].as_str().unwrap().starts_with(MINT_CALL_SELECTOR));
+            reply()
+        }).await;
+        AlchemyProvider::with_rpc_url(url).simulate_mint("0xtoken").await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_guarded_mint_reverts_unauthorized() {
+        let simulation = simulate_mint_reply(|| {
+            rpc_error(3, "execution reverted: Ownable: caller is not the owner")
+        }).await;
+
+        assert_eq!(simulation.outcome, MintCallOutcome::Unauthorized);
+        assert_eq!(simulation.revert_reason.as_deref(), Some("Ownable: caller is not the owner"));
+        assert_eq!(simulation.caller, SIMULATION_CALLER);
+    }
+
+    #[tokio::test]
+    async fn test_missing_mint_reverts_without_data() {
+        let simulation = simulate_mint_reply(|| rpc_error(3, "execution reverted")).await;
+
+        assert_eq!(simulation.outcome, MintCallOutcome::UnknownSelector);
+        assert_eq!(simulation.revert_reason, None);
+    }
+
+    #[tokio::test]
+    async fn test_bare_revert_with_mint_selector_is_inconclusive() {
+        // `require(msg.sender == minter)` without a message
+        let simulation = simulate_mint_with_code(|| rpc_error(3, "execution reverted"), MINTABLE_CODE).await;
+
+        assert_eq!(simulation.outcome, MintCallOutcome::Inconclusive);
+    }
+
+    #[test]
+    fn test_loose_revert_reason_is_not_unauthorized() {
+        let error = json!({ "code": 3, "message": "execution reverted: only whole tokens; access window closed" });
+        assert_eq!(classify_mint_revert(&error).0, MintCallOutcome::Inconclusive);
+    }
+
+    #[test]
+    fn test_custom_error_reads_as_unauthorized() {
+        let error = json!({
+            "code": 3,
+            "message": "execution reverted",
+            "data": format!("0xe2517d3f{:0>64}", 0),
+        });
+        assert_eq!(classify_mint_revert(&error).0, MintCallOutcome::Unauthorized);
+    }
+
+    #[tokio::test]
+    async fn test_supply_as_hex_or_number() {
+        // 2e18 raw units: 2 tokens at 18 decimals
+        for reply in [json!("0x1bc16d674ec80000"), json!(2_000_000_000_000_000_000u64)] {
+            let url = serve_rpc(move |_| rpc_result(reply.clone())).await;
+
+            let supply = AlchemyProvider::with_rpc_url(url).fetch_supply("0xtoken").await.unwrap();
+
+            assert_eq!(supply.total_supply, Some(2.0));
+            assert_eq!(supply.raw_units(), Some(2e18));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_point_in_time_reads_use_block_tag() {
+        // Answers only calls made at block 100 (0x64)
+        let url = serve_rpc(|request| {
+            if request["params"][1] == "0x64" {
+                rpc_result(json!(format!("0x{:0>64}", "12")))
+            } else {
+                rpc_error(-32000, "wrong block")
+            }
+        }).await;
+        let provider = AlchemyProvider::with_rpc_url(url);
+
+        let metadata = provider.fetch_metadata_at("0xtoken", 100).await.unwrap();
+        assert_eq!(metadata.decimals, Some(18));
+        assert!(provider.fetch_supply_at("0xtoken", 100).await.is_ok());
+        assert!(provider.fetch_supply("0xtoken").await.is_err());
+    }
+
+    #[test]
+    fn test_has_code() {
+        assert!(!has_code("0x"));
+        assert!(has_code("0x6080604052"));
+    }
 
     #[tokio::test]
     #[ignore]
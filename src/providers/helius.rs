@@ -1,214 +1,102 @@
 use async_trait::async_trait;
 use crate::types::*;
-use super::{TokenProvider, ProviderError};
-use serde::{Deserialize, Serialize};
+use super::{ProviderCapabilities, TokenProvider, ProviderError};
+use super::solana_parse::{
+    account_rent_fields, controller_kind_from_account, metadata_from_account, multisig_from_account,
+    parse_mint_account,
+};
+use super::solana_rpc::{solana_capabilities, SolanaRpcClient};
 use serde_json::json;
 
 pub struct HeliusProvider {
-    api_key: String,
-    rpc_url: String,
+    rpc: SolanaRpcClient,
 }
 
 impl HeliusProvider {
     pub fn new(api_key: String) -> Self {
-        let rpc_url = format!("https://mainnet.helius-rpc.com/?api-key={}", api_key);
-        Self {
-            api_key,
-            rpc_url,
-        }
+        Self::with_rpc_url(format!("https://mainnet.helius-rpc.com/?api-key={}", api_key))
     }
 
-    async fn rpc_call<T: for<'de> Deserialize<'de>>(
-        &self,
-        method: &str,
-        params: serde_json::Value,
-    ) -> Result<T, ProviderError> {
-        let request_body = json!({
-            "jsonrpc": "2.0",
-            "id": 1,
-            "method": method,
-            "params": params,
-        });
-
-        let client = reqwest::Client::new();
-        let response = client
-            .post(&self.rpc_url)
-            .json(&request_body)
-            .timeout(std::time::Duration::from_secs(10))
-            .send()
-            .await
-            .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            eprintln!("RPC Error - Status: {}, Body: {}", status, body);
-            return Err(ProviderError::InvalidResponse);
+    /// Provider against an explicit JSON-RPC endpoint (self-hosted node,
+    /// local test server)
+    pub fn with_rpc_url(rpc_url: impl Into<String>) -> Self {
+        Self {
+            rpc: SolanaRpcClient::new(rpc_url),
         }
-
-        let text = response.text().await
-            .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
-        
-        eprintln!("RPC Response: {}", text);
-        
-        let rpc_response: RpcResponse<T> = serde_json::from_str(&text)
-            .map_err(|e| {
-                eprintln!("JSON Parse Error: {}", e);
-                ProviderError::InvalidResponse
-            })?;
-
-        rpc_response.result.ok_or(ProviderError::InvalidResponse)
     }
 }
 
-#[derive(Debug, Deserialize)]
-struct RpcResponse<T> {
-    result: Option<T>,
-    error: Option<serde_json::Value>,
-}
-
-#[derive(Debug, Deserialize)]
-struct AccountInfoResponse {
-    value: Option<AccountData>,
-}
-
-#[derive(Debug, Deserialize)]
-struct AccountData {
-    data: DataField,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(untagged)]
-enum DataField {
-    Parsed(ParsedData),
-    Raw(Vec<String>),
-}
-
-#[derive(Debug, Deserialize)]
-struct ParsedData {
-    parsed: ParsedInfo,
-}
-
-#[derive(Debug, Deserialize)]
-struct ParsedInfo {
-    info: MintInfo,
-    #[serde(rename = "type")]
-    account_type: String,
-}
-
-#[derive(Debug, Deserialize)]
-struct MintInfo {
-    decimals: u8,
-    supply: String,
-    #[serde(rename = "mintAuthority")]
-    mint_authority: Option<String>,
-    #[serde(rename = "freezeAuthority")]
-    freeze_authority: Option<String>,
-}
-
 #[async_trait]
 impl TokenProvider for HeliusProvider {
     fn provider_name(&self) -> &str {
         "helius"
     }
 
+    fn capabilities(&self) -> ProviderCapabilities {
+        // No token-account scan yet
+        ProviderCapabilities {
+            holders: false,
+            ..solana_capabilities()
+        }
+    }
+
+    async fn ping(&self) -> Result<(), ProviderError> {
+        self.rpc.call::<serde_json::Value>("getHealth", json!([])).await.map(|_| ())
+    }
+
     async fn fetch_metadata(&self, address: &str) -> Result<Metadata, ProviderError> {
         // For now, just get decimals from account info
         // Full metadata would require Metaplex metadata account
-        let account_info: AccountInfoResponse = self.rpc_call(
-            "getAccountInfo",
-            json!([
-                address,
-                {
-                    "encoding": "jsonParsed"
-                }
-            ])
-        ).await?;
-
-        let decimals = if let Some(account) = account_info.value {
-            if let DataField::Parsed(parsed) = account.data {
-                Some(parsed.parsed.info.decimals)
-            } else {
-                None
-            }
-        } else {
-            None
-        };
+        let account_info: serde_json::Value = self.rpc.account_info(address).await?;
 
-        Ok(Metadata {
-            name: None, // Would need Metaplex metadata
-            symbol: None, // Would need Metaplex metadata
-            decimals,
-            standard: TokenStandard::SplToken,
-        })
+        Ok(metadata_from_account(&account_info))
     }
 
     async fn fetch_supply(&self, address: &str) -> Result<SupplyInfo, ProviderError> {
-        let account_info: AccountInfoResponse = self.rpc_call(
-            "getAccountInfo",
-            json!([
-                address,
-                {
-                    "encoding": "jsonParsed"
-                }
-            ])
-        ).await?;
-
-        let account = account_info.value.ok_or(ProviderError::NotFound)?;
-        
-        let (supply_raw, decimals) = if let DataField::Parsed(parsed) = account.data {
-            let info = parsed.parsed.info;
-            (info.supply, info.decimals)
-        } else {
-            return Err(ProviderError::InvalidResponse);
-        };
+        let account_info: serde_json::Value = self.rpc.account_info(address).await?;
 
-        let total_supply = if let Ok(raw) = supply_raw.parse::<u64>() {
-            Some(raw as f64 / 10_f64.powi(decimals as i32))
-        } else {
-            None
-        };
-
-        Ok(SupplyInfo {
-            total_supply_raw: Some(supply_raw),
-            total_supply,
-        })
+        Ok(parse_mint_account(&account_info)?.supply())
     }
 
     async fn fetch_authorities(&self, address: &str) -> Result<AuthorityInfo, ProviderError> {
-        let account_info: AccountInfoResponse = self.rpc_call(
-            "getAccountInfo",
-            json!([
-                address,
-                {
-                    "encoding": "jsonParsed"
-                }
-            ])
-        ).await?;
-
-        let account = account_info.value.ok_or(ProviderError::NotFound)?;
-        
-        let info = if let DataField::Parsed(parsed) = account.data {
-            parsed.parsed.info
-        } else {
-            return Err(ProviderError::InvalidResponse);
-        };
+        let account_info: serde_json::Value = self.rpc.account_info(address).await?;
 
+        let info = parse_mint_account(&account_info)?;
         let mint_mutable = info.mint_authority.is_some();
 
+        let mint_authority_account = match &info.mint_authority {
+            Some(authority) => self.rpc.authority_account(authority).await,
+            None => None,
+        };
+        let freeze_authority_account = match &info.freeze_authority {
+            Some(authority) => self.rpc.authority_account(authority).await,
+            None => None,
+        };
+
         Ok(AuthorityInfo {
             mint_authority: info.mint_authority,
             freeze_authority: info.freeze_authority,
             owner: None,
             mint_mutable: Some(mint_mutable),
+            pending_owner: None,
+            mint_authority_multisig: mint_authority_account.as_ref().and_then(multisig_from_account),
+            freeze_authority_multisig: freeze_authority_account.as_ref().and_then(multisig_from_account),
+            controller_kind: mint_authority_account.as_ref().map(controller_kind_from_account),
         })
     }
 
-    async fn fetch_holders(&self, _address: &str, _limit: usize) -> Result<HolderInfo, ProviderError> {
+    async fn fetch_holders(
+        &self,
+        _address: &str,
+        _limit: usize,
+        _strategy: HolderStrategy,
+    ) -> Result<HolderInfo, ProviderError> {
         // Would require token accounts query
         Ok(HolderInfo {
             top1_pct: None,
             top5_pct: None,
+            holder_count: None,
+            unlisted_balance_raw: None,
             top_holders: vec![],
         })
     }
@@ -219,13 +107,138 @@ impl TokenProvider for HeliusProvider {
             created_at: None,
             age_seconds: None,
             age_band: AgeBand::Unknown,
+            estimated: false,
         })
     }
+
+    async fn fetch_rent_status(&self, address: &str) -> Result<RentInfo, ProviderError> {
+        let account_info: serde_json::Value = self.rpc.account_info(address).await?;
+        let (lamports, data_len) = account_rent_fields(&account_info)?;
+
+        let minimum = self.rpc.rent_exempt_minimum(data_len).await;
+        Ok(RentInfo::new(lamports, data_len, minimum))
+    }
+
+    async fn fetch_authority_history(&self, address: &str) -> Result<Vec<AuthorityChange>, ProviderError> {
+        self.rpc.authority_history(address).await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::providers::rpc_harness::{rpc_error, rpc_result, serve_rpc, RpcReply};
+    use crate::providers::solana_parse::tests::mint_account_blob;
+    use axum::http::StatusCode;
+
+    async fn provider_replying(reply: RpcReply) -> HeliusProvider {
+        let url = serve_rpc(move |_| reply.clone()).await;
+        HeliusProvider::with_rpc_url(url)
+    }
+
+    #[tokio::test]
+    async fn test_rpc_success_parsed() {
+        let account: serde_json::Value = serde_json::from_str(&mint_account_blob(true)).unwrap();
+        let url = serve_rpc(move |request| {
+            assert_eq!(request["method"], "getAccountInfo");
+            rpc_result(account.clone())
+        }).await;
+        let provider = HeliusProvider::with_rpc_url(url);
+
+        let metadata = provider.fetch_metadata("Mint").await.unwrap();
+        assert_eq!(metadata.decimals, Some(6));
+
+        let supply = provider.fetch_supply("Mint").await.unwrap();
+        assert_eq!(supply.total_supply, Some(1000.0));
+    }
+
+    #[tokio::test]
+    async fn test_rent_reuses_fetched_account() {
+        use crate::providers::{trace, TraceCollector};
+        use std::sync::{Arc, Mutex};
+
+        let account: serde_json::Value = serde_json::from_str(&mint_account_blob(true)).unwrap();
+        let methods = Arc::new(Mutex::new(Vec::new()));
+        let seen = methods.clone();
+        let url = serve_rpc(move |request| {
+            let method = request["method"].as_str().unwrap().to_string();
+            seen.lock().unwrap().push(method.clone());
+            match method.as_str() {
+                "getAccountInfo" => rpc_result(account.clone()),
+                _ => rpc_result(serde_json::json!(1_461_600)),
+            }
+        }).await;
+        let provider = HeliusProvider::with_rpc_url(url);
+
+        let analysis = || async {
+            provider.fetch_metadata("Mint").await.unwrap();
+            provider.fetch_rent_status("Mint").await.unwrap()
+        };
+        let rent = trace::with_trace(TraceCollector::new(false), analysis()).await;
+
+        assert_eq!(rent.rent_exempt_minimum, Some(1_461_600));
+        assert_eq!(*methods.lock().unwrap(), vec!["getAccountInfo", "getMinimumBalanceForRentExemption"]);
+
+        // The next analysis reads the account afresh; the rent minimum is
+        // a cluster constant and stays cached
+        trace::with_trace(TraceCollector::new(false), analysis()).await;
+        assert_eq!(
+            *methods.lock().unwrap(),
+            vec!["getAccountInfo", "getMinimumBalanceForRentExemption", "getAccountInfo"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rpc_error_body_is_invalid_response() {
+        let provider = provider_replying(rpc_error(-32602, "Invalid param: WrongSize")).await;
+
+        let err = provider.fetch_metadata("Mint").await.unwrap_err();
+        assert!(matches!(err, ProviderError::InvalidResponse), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_http_429_is_rate_limited() {
+        let provider = provider_replying(
+            (StatusCode::TOO_MANY_REQUESTS, "rate limit exceeded".to_string()),
+        ).await;
+
+        let err = provider.fetch_metadata("Mint").await.unwrap_err();
+        assert!(matches!(err, ProviderError::RateLimited), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_http_401_is_unauthorized() {
+        let provider = provider_replying((StatusCode::UNAUTHORIZED, "invalid api key".to_string())).await;
+
+        let err = provider.ping().await.unwrap_err();
+        assert!(matches!(err, ProviderError::Unauthorized), "{:?}", err);
+        assert!(err.is_fatal());
+    }
+
+    #[tokio::test]
+    async fn test_malformed_json_is_invalid_response() {
+        let provider = provider_replying((StatusCode::OK, r#"{"jsonrpc": "2.0", "result": "#.to_string())).await;
+
+        let err = provider.fetch_metadata("Mint").await.unwrap_err();
+        assert!(matches!(err, ProviderError::InvalidResponse), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_raw_account_data_branch() {
+        // Accounts the node cannot parse come back as [data, encoding]; a
+        // blob too short for a mint decodes to nothing
+        let provider = provider_replying(rpc_result(json!({
+            "context": { "slot": 1 },
+            "value": { "data": ["AQAAAA==", "base64"], "owner": "11111111111111111111111111111111" }
+        }))).await;
+
+        let metadata = provider.fetch_metadata("Mint").await.unwrap();
+        assert_eq!(metadata.decimals, None);
+        assert_eq!(metadata.is_initialized, None);
+
+        let err = provider.fetch_supply("Mint").await.unwrap_err();
+        assert!(matches!(err, ProviderError::InvalidResponse), "{:?}", err);
+    }
 
     #[tokio::test]
     #[ignore]
@@ -318,9 +331,10 @@ mod full_analysis_tests {
                 check.score_component
             );
         }
-        println!("\nExplanation: {}", response.explain.summary);
+        let explain = response.explain.as_ref().unwrap();
+        println!("\nExplanation: {}", explain.summary);
         println!("What to do:");
-        for item in &response.explain.interpretation.what_to_do {
+        for item in &explain.interpretation.what_to_do {
             println!("  - {}", item);
         }
         println!("\n=========================\n");
@@ -0,0 +1,233 @@
+// src/providers/holder_aggregator.rs
+//
+// Streaming top-N aggregation for holder lists.
+//
+// Memory bound: at most `max(max_holders, 5)` balances are retained at any time
+// (5 is the floor needed for top5_pct), plus two running totals. The long tail
+// is folded into the totals and discarded as it streams past, so a 50k-holder
+// token costs the same memory as a 10-holder one.
+
+use crate::types::*;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub struct HolderAggregator {
+    limit: usize,
+    retain: usize,
+    // Min-heap on balance: the smallest retained balance is evicted first
+    heap: BinaryHeap<Reverse<(u128, String)>>,
+    total_balance: u128,
+    holders_seen: u64,
+    peak_retained: usize,
+}
+
+impl HolderAggregator {
+    /// Lists up to `max_holders`; retains `max(max_holders, 5)` balances so
+    /// `top5_pct` stays exact when fewer than five are listed.
+    pub fn new(max_holders: usize) -> Self {
+        let retain = max_holders.max(5);
+        Self {
+            limit: max_holders,
+            retain,
+            heap: BinaryHeap::with_capacity(retain + 1),
+            total_balance: 0,
+            holders_seen: 0,
+            peak_retained: 0,
+        }
+    }
+
+    /// Feed one holder balance (raw units). Zero balances are skipped.
+    pub fn push(&mut self, address: String, balance_raw: u128) {
+        if balance_raw == 0 {
+            return;
+        }
+
+        self.total_balance = self.total_balance.saturating_add(balance_raw);
+        self.holders_seen += 1;
+
+        if self.heap.len() < self.retain {
+            self.heap.push(Reverse((balance_raw, address)));
+        } else if let Some(Reverse((smallest, _))) = self.heap.peek() {
+            if balance_raw > *smallest {
+                self.heap.pop();
+                self.heap.push(Reverse((balance_raw, address)));
+            }
+        }
+
+        self.peak_retained = self.peak_retained.max(self.heap.len());
+    }
+
+    /// Largest number of balances held at once (the memory bound in practice)
+    pub fn peak_retained(&self) -> usize {
+        self.peak_retained
+    }
+
+    pub fn holders_seen(&self) -> u64 {
+        self.holders_seen
+    }
+
+    /// Share of supply held by the retained top `max_holders`
+    pub fn top_n_pct(&self, total_supply_raw: Option<u128>) -> Option<f64> {
+        let sorted = self.sorted();
+        let top_n: u128 = sorted.iter().take(self.limit).map(|(b, _)| *b).sum();
+        pct(top_n, self.denominator(total_supply_raw))
+    }
+
+    /// Build `HolderInfo` from the retained balances.
+    /// Percentages use `total_supply_raw` when known, else the streamed total.
+    pub fn finish(self, decimals: u8, total_supply_raw: Option<u128>) -> HolderInfo {
+        let denominator = self.denominator(total_supply_raw);
+        let holders_seen = self.holders_seen;
+        let total_balance = self.total_balance;
+        let (mut info, listed) = self.summarize(decimals, denominator, HolderKind::Unclassified);
+
+        info.holder_count = Some(holders_seen);
+        info.unlisted_balance_raw = Some(total_balance.saturating_sub(listed).to_string());
+        info
+    }
+
+    /// `finish` for a capped listing, such as a node's largest accounts, that
+    /// covers only the head of the distribution: no holder count or unlisted
+    /// remainder, and percentages only against a known supply.
+    pub fn finish_listing(self, decimals: u8, total_supply_raw: Option<u128>, kind: HolderKind) -> HolderInfo {
+        let denominator = total_supply_raw.unwrap_or(0);
+        self.summarize(decimals, denominator, kind).0
+    }
+
+    /// Percentages against `denominator` (none when zero), plus the listed total
+    fn summarize(self, decimals: u8, denominator: u128, kind: HolderKind) -> (HolderInfo, u128) {
+        let sorted = self.sorted();
+
+        let top1: u128 = sorted.iter().take(1).map(|(b, _)| *b).sum();
+        let top5: u128 = sorted.iter().take(5).map(|(b, _)| *b).sum();
+        let listed: u128 = sorted.iter().take(self.limit).map(|(b, _)| *b).sum();
+        let scale = 10_f64.powi(decimals as i32);
+
+        let top_holders = sorted.into_iter()
+            .take(self.limit)
+            .map(|(balance_raw, address)| HolderBalance {
+                address,
+                balance_raw: balance_raw.to_string(),
+                balance: Some(balance_raw as f64 / scale),
+                pct_of_supply: pct(balance_raw, denominator),
+                kind: kind.clone(),
+                label: None,
+            })
+            .collect();
+
+        let info = HolderInfo {
+            top1_pct: pct(top1, denominator),
+            top5_pct: pct(top5, denominator),
+            holder_count: None,
+            unlisted_balance_raw: None,
+            top_holders,
+        };
+        (info, listed)
+    }
+
+    fn denominator(&self, total_supply_raw: Option<u128>) -> u128 {
+        total_supply_raw.filter(|s| *s > 0).unwrap_or(self.total_balance)
+    }
+
+    fn sorted(&self) -> Vec<(u128, String)> {
+        let mut sorted: Vec<(u128, String)> = self.heap.iter()
+            .map(|Reverse(entry)| entry.clone())
+            .collect();
+        sorted.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        sorted
+    }
+}
+
+fn pct(part: u128, total: u128) -> Option<f64> {
+    if total == 0 {
+        None
+    } else {
+        Some(part as f64 / total as f64 * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_large_stream_retains_only_max_holders() {
+        let max_holders = 10;
+        let mut aggregator = HolderAggregator::new(max_holders);
+
+        // 50k holders with balances 1..=50_000, in scrambled order
+        let n: u128 = 50_000;
+        for i in 0..n {
+            let balance = (i * 7919) % n + 1;
+            aggregator.push(format!("holder_{}", balance), balance);
+        }
+
+        assert_eq!(aggregator.peak_retained(), max_holders);
+        assert_eq!(aggregator.holders_seen(), 50_000);
+
+        let total: u128 = n * (n + 1) / 2;
+        let top_n = aggregator.top_n_pct(None).unwrap();
+        let expected_top_n: u128 = (n - 9..=n).sum();
+        assert!((top_n - expected_top_n as f64 / total as f64 * 100.0).abs() < 1e-9);
+
+        let info = aggregator.finish(0, None);
+
+        assert_eq!(info.top_holders.len(), max_holders);
+        assert_eq!(info.holder_count, Some(50_000));
+        assert_eq!(info.top_holders[0].balance_raw, "50000");
+        assert_eq!(info.top_holders[9].balance_raw, "49991");
+        let unlisted = total - expected_top_n;
+        assert_eq!(info.unlisted_balance_raw, Some(unlisted.to_string()));
+
+        let expected_top1 = 50_000.0 / total as f64 * 100.0;
+        let expected_top5 = (49_996..=50_000u128).sum::<u128>() as f64 / total as f64 * 100.0;
+        assert!((info.top1_pct.unwrap() - expected_top1).abs() < 1e-9);
+        assert!((info.top5_pct.unwrap() - expected_top5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_small_limit_still_retains_top_five() {
+        let mut aggregator = HolderAggregator::new(2);
+        for balance in 1..=100u128 {
+            aggregator.push(format!("holder_{}", balance), balance);
+        }
+
+        // Below five, the floor for top5_pct sets the bound
+        assert_eq!(aggregator.peak_retained(), 5);
+
+        let info = aggregator.finish(0, Some(1_000));
+
+        assert_eq!(info.top_holders.len(), 2);
+        assert_eq!(info.top5_pct, Some(49.0));
+    }
+
+    #[test]
+    fn test_listing_has_no_count_or_remainder() {
+        let mut aggregator = HolderAggregator::new(3);
+        aggregator.push("a".to_string(), 500);
+        aggregator.push("b".to_string(), 300);
+
+        let info = aggregator.finish_listing(0, None, HolderKind::TokenAccount);
+
+        assert_eq!(info.holder_count, None);
+        assert_eq!(info.unlisted_balance_raw, None);
+        // Without a supply there is nothing to take a share of
+        assert_eq!(info.top1_pct, None);
+        assert_eq!(info.top_holders[0].kind, HolderKind::TokenAccount);
+    }
+
+    #[test]
+    fn test_uses_total_supply_when_known() {
+        let mut aggregator = HolderAggregator::new(3);
+        aggregator.push("a".to_string(), 500);
+        aggregator.push("b".to_string(), 300);
+        aggregator.push("c".to_string(), 0);
+
+        let info = aggregator.finish(2, Some(10_000));
+
+        assert_eq!(info.top1_pct, Some(5.0));
+        assert_eq!(info.top5_pct, Some(8.0));
+        assert_eq!(info.top_holders.len(), 2);
+        assert_eq!(info.top_holders[0].balance, Some(5.0));
+    }
+}
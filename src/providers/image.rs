@@ -0,0 +1,181 @@
+// src/providers/image.rs
+//
+// Reachability probe for a token's metadata image URI (HEAD request).
+// The URI comes from on-chain metadata anyone can write, so only https
+// URLs on public addresses are fetched, and redirects are not followed.
+
+use crate::types::ImageInfo;
+use reqwest::Url;
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+pub const IMAGE_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// HEAD-request `uri`. A response of any kind settles `reachable`; timeouts,
+/// connection failures and URIs refused as non-https or non-public leave it
+/// `None` (unknown) rather than `false`.
+pub async fn probe_image(uri: &str, timeout: Duration) -> ImageInfo {
+    match pinned_client(uri, timeout).await {
+        Ok(client) => head(&client, uri, timeout).await,
+        Err(problem) => ImageInfo {
+            uri: uri.to_string(),
+            reachable: None,
+            content_type: None,
+            http_status: None,
+            error: Some(problem),
+        },
+    }
+}
+
+/// Client for `uri` with its host pinned to the public address it was
+/// checked against, so a second DNS answer cannot point it elsewhere
+async fn pinned_client(uri: &str, timeout: Duration) -> Result<reqwest::Client, String> {
+    let url = Url::parse(uri).map_err(|e| format!("invalid image URI: {}", e))?;
+    if url.scheme() != "https" {
+        return Err(format!("image URI scheme '{}' not allowed: https only", url.scheme()));
+    }
+    let port = url.port_or_known_default().unwrap_or(443);
+    let host = url.host_str().ok_or("image URI has no host")?;
+    // IPv6 literals come bracketed
+    let address = match host.trim_start_matches('[').trim_end_matches(']').parse::<IpAddr>() {
+        Ok(ip) => SocketAddr::new(ip, port),
+        Err(_) => tokio::time::timeout(timeout, tokio::net::lookup_host((host, port)))
+            .await
+            .map_err(|_| "timeout".to_string())?
+            .map_err(|e| format!("image host lookup failed: {}", e))?
+            .next()
+            .ok_or_else(|| format!("image host {} has no address", host))?,
+    };
+    if !is_public(address.ip()) {
+        return Err(format!("image host address {} is not public", address.ip()));
+    }
+
+    reqwest::Client::builder()
+        .resolve(host, address)
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// Globally routable: not loopback, private, link-local, shared (CGNAT),
+/// documentation, multicast or unspecified
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let shared = ip.octets()[0] == 100 && (ip.octets()[1] & 0xc0) == 64;
+            !(ip.is_private() || ip.is_loopback() || ip.is_link_local() || ip.is_broadcast()
+                || ip.is_documentation() || ip.is_unspecified() || ip.is_multicast() || shared)
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_public(IpAddr::V4(mapped)),
+            None => {
+                let unique_local = (ip.segments()[0] & 0xfe00) == 0xfc00;
+                let link_local = (ip.segments()[0] & 0xffc0) == 0xfe80;
+                !(ip.is_loopback() || ip.is_unspecified() || ip.is_multicast() || unique_local || link_local)
+            }
+        },
+    }
+}
+
+async fn head(client: &reqwest::Client, uri: &str, timeout: Duration) -> ImageInfo {
+    let mut info = ImageInfo {
+        uri: uri.to_string(),
+        reachable: None,
+        content_type: None,
+        http_status: None,
+        error: None,
+    };
+
+    match client.head(uri).timeout(timeout).send().await {
+        Ok(response) => {
+            let status = response.status();
+            info.http_status = Some(status.as_u16());
+            info.reachable = Some(status.is_success());
+            info.content_type = response.headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string());
+        }
+        Err(e) => {
+            info.error = Some(if e.is_timeout() { "timeout".to_string() } else { e.to_string() });
+        }
+    }
+
+    info
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{http::header, routing::get, Router};
+
+    async fn serve_images() -> String {
+        let app = Router::new()
+            .route("/logo.png", get(|| async { ([(header::CONTENT_TYPE, "image/png")], "png") }))
+            .route("/slow.png", get(|| async {
+                tokio::time::sleep(Duration::from_secs(2)).await;
+                "late"
+            }));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_reachable_image() {
+        let base = serve_images().await;
+
+        let info = head(&reqwest::Client::new(), &format!("{}/logo.png", base), IMAGE_PROBE_TIMEOUT).await;
+
+        assert_eq!(info.reachable, Some(true));
+        assert_eq!(info.http_status, Some(200));
+        assert_eq!(info.content_type.as_deref(), Some("image/png"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_image_unreachable() {
+        let base = serve_images().await;
+
+        let info = head(&reqwest::Client::new(), &format!("{}/missing.png", base), IMAGE_PROBE_TIMEOUT).await;
+
+        assert_eq!(info.reachable, Some(false));
+        assert_eq!(info.http_status, Some(404));
+    }
+
+    #[tokio::test]
+    async fn test_timeout_is_unknown() {
+        let base = serve_images().await;
+
+        let info = head(&reqwest::Client::new(), &format!("{}/slow.png", base), Duration::from_millis(100)).await;
+
+        assert_eq!(info.reachable, None);
+        assert_eq!(info.error.as_deref(), Some("timeout"));
+    }
+
+    #[tokio::test]
+    async fn test_plain_http_and_private_hosts_refused() {
+        let base = serve_images().await;
+
+        let plain = probe_image(&format!("{}/logo.png", base), IMAGE_PROBE_TIMEOUT).await;
+        assert_eq!(plain.reachable, None);
+        assert!(plain.error.unwrap().contains("https only"));
+
+        for uri in ["https://127.0.0.1/logo.png", "https://10.0.0.8/logo.png", "https://[::ffff:169.254.169.254]/"] {
+            let info = probe_image(uri, IMAGE_PROBE_TIMEOUT).await;
+            assert_eq!(info.reachable, None);
+            assert!(info.error.unwrap().contains("is not public"), "{}", uri);
+        }
+    }
+
+    #[test]
+    fn test_public_addresses() {
+        assert!(is_public("93.184.216.34".parse().unwrap()));
+        assert!(is_public("2606:4700::1111".parse().unwrap()));
+        assert!(!is_public("100.64.0.1".parse().unwrap()));
+        assert!(!is_public("fd00::1".parse().unwrap()));
+        assert!(!is_public("fe80::1".parse().unwrap()));
+    }
+}
@@ -1,12 +1,23 @@
 use async_trait::async_trait;
 use crate::types::*;
-use super::{TokenProvider, ProviderError};
+use super::{trace, ProviderCapabilities, TokenProvider, ProviderError};
+use serde_json::json;
 use std::collections::HashMap;
+use std::time::Instant;
 
 pub struct MockProvider {
     pub name: String,
     pub facts: HashMap<String, TokenFacts>,
     pub errors: HashMap<String, ProviderError>,
+    /// Supply by (address, slot/block) for `fetch_supply_at`
+    pub supply_history: HashMap<(String, u64), SupplyInfo>,
+    /// Facts by (address, slot/block) for the other `fetch_*_at` reads
+    pub facts_history: HashMap<(String, u64), TokenFacts>,
+    pub capabilities: ProviderCapabilities,
+    /// Served by `fetch_labels`
+    pub labels: HashMap<String, AddressLabel>,
+    /// Returned by `ping`; None pings successfully
+    pub ping_error: Option<ProviderError>,
 }
 
 impl MockProvider {
@@ -15,6 +26,11 @@ impl MockProvider {
             name: name.to_string(),
             facts: HashMap::new(),
             errors: HashMap::new(),
+            supply_history: HashMap::new(),
+            facts_history: HashMap::new(),
+            capabilities: ProviderCapabilities::all(),
+            labels: HashMap::new(),
+            ping_error: None,
         }
     }
     
@@ -23,10 +39,62 @@ impl MockProvider {
         self
     }
     
+    pub fn with_supply_at(mut self, address: &str, slot_or_block: u64, supply: SupplyInfo) -> Self {
+        self.supply_history.insert((address.to_string(), slot_or_block), supply);
+        self
+    }
+
+    /// Serve `facts` to point-in-time reads at `slot_or_block`
+    pub fn with_facts_at(mut self, address: &str, slot_or_block: u64, facts: TokenFacts) -> Self {
+        self.facts_history.insert((address.to_string(), slot_or_block), facts);
+        self
+    }
+
+    pub fn with_capabilities(mut self, capabilities: ProviderCapabilities) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    pub fn with_label(mut self, address: &str, label: AddressLabel) -> Self {
+        self.labels.insert(address.to_string(), label);
+        self
+    }
+
+    pub fn with_ping_error(mut self, error: ProviderError) -> Self {
+        self.ping_error = Some(error);
+        self
+    }
+
     pub fn with_error(mut self, address: &str, error: ProviderError) -> Self {
         self.errors.insert(address.to_string(), error);
         self
     }
+
+    /// Look up one fact for `address`, recording the call in the active RPC trace
+    fn lookup<T>(
+        &self,
+        method: &str,
+        address: &str,
+        get: impl Fn(&TokenFacts) -> Option<T>,
+    ) -> Result<T, ProviderError> {
+        let started = Instant::now();
+
+        let result = if self.errors.contains_key(address) {
+            Err(ProviderError::Timeout)
+        } else {
+            self.facts.get(address)
+                .and_then(get)
+                .ok_or(ProviderError::NotFound)
+        };
+
+        let status = match &result {
+            Ok(_) => "ok".to_string(),
+            Err(e) => format!("{:?}", e),
+        };
+        trace::record_call(method, &json!([address]), &status, started, None);
+
+        result
+    }
 }
 
 #[async_trait]
@@ -34,54 +102,92 @@ impl TokenProvider for MockProvider {
     fn provider_name(&self) -> &str {
         &self.name
     }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        self.capabilities.clone()
+    }
+
+    async fn ping(&self) -> Result<(), ProviderError> {
+        let result = match &self.ping_error {
+            Some(error) => Err(error.clone()),
+            None => Ok(()),
+        };
+        let status = match &result {
+            Ok(_) => "ok".to_string(),
+            Err(e) => format!("{:?}", e),
+        };
+        trace::record_call("ping", &json!([]), &status, Instant::now(), None);
+        result
+    }
     
     async fn fetch_metadata(&self, address: &str) -> Result<Metadata, ProviderError> {
-        if let Some(_err) = self.errors.get(address) {
-            return Err(ProviderError::Timeout);
-        }
-        
-        self.facts.get(address)
-            .and_then(|f| f.metadata.clone())
-            .ok_or(ProviderError::NotFound)
+        self.lookup("fetch_metadata", address, |f| f.metadata.clone())
     }
     
     async fn fetch_supply(&self, address: &str) -> Result<SupplyInfo, ProviderError> {
-        if let Some(_err) = self.errors.get(address) {
-            return Err(ProviderError::Timeout);
-        }
-        
-        self.facts.get(address)
-            .and_then(|f| f.supply.clone())
-            .ok_or(ProviderError::NotFound)
+        self.lookup("fetch_supply", address, |f| f.supply.clone())
     }
     
     async fn fetch_authorities(&self, address: &str) -> Result<AuthorityInfo, ProviderError> {
-        if let Some(_err) = self.errors.get(address) {
-            return Err(ProviderError::Timeout);
-        }
-        
-        self.facts.get(address)
-            .and_then(|f| f.authorities.clone())
-            .ok_or(ProviderError::NotFound)
+        self.lookup("fetch_authorities", address, |f| f.authorities.clone())
     }
     
-    async fn fetch_holders(&self, address: &str, _limit: usize) -> Result<HolderInfo, ProviderError> {
-        if let Some(_err) = self.errors.get(address) {
-            return Err(ProviderError::Timeout);
+    async fn fetch_holders(
+        &self,
+        address: &str,
+        _limit: usize,
+        strategy: HolderStrategy,
+    ) -> Result<HolderInfo, ProviderError> {
+        match strategy {
+            // Stands in for the provider's cheap percentages-only endpoint
+            HolderStrategy::Aggregate => self.lookup("fetch_holders", address, |f| {
+                f.holders.clone().map(|holders| HolderInfo { top_holders: Vec::new(), ..holders })
+            }),
+            HolderStrategy::Full => self.lookup("fetch_holder_list", address, |f| f.holders.clone()),
         }
-        
-        self.facts.get(address)
-            .and_then(|f| f.holders.clone())
-            .ok_or(ProviderError::NotFound)
     }
     
     async fn fetch_creation_time(&self, address: &str) -> Result<CreationInfo, ProviderError> {
-        if let Some(_err) = self.errors.get(address) {
-            return Err(ProviderError::Timeout);
-        }
-        
-        self.facts.get(address)
-            .and_then(|f| f.creation.clone())
-            .ok_or(ProviderError::NotFound)
+        self.lookup("fetch_creation_time", address, |f| f.creation.clone())
+    }
+    
+    async fn fetch_tax_info(&self, address: &str) -> Result<TaxInfo, ProviderError> {
+        self.lookup("fetch_tax_info", address, |f| f.tax.clone())
+    }
+
+    async fn fetch_authority_history(&self, address: &str) -> Result<Vec<AuthorityChange>, ProviderError> {
+        self.lookup("fetch_authority_history", address, |f| f.authority_history.clone())
+    }
+
+    async fn fetch_bytecode(&self, address: &str) -> Result<BytecodeInfo, ProviderError> {
+        self.lookup("fetch_bytecode", address, |f| f.bytecode.clone())
+    }
+
+    async fn fetch_rent_status(&self, address: &str) -> Result<RentInfo, ProviderError> {
+        self.lookup("fetch_rent_status", address, |f| f.rent.clone())
+    }
+
+    async fn fetch_supply_at(&self, address: &str, slot_or_block: u64) -> Result<SupplyInfo, ProviderError> {
+        let key = (address.to_string(), slot_or_block);
+        self.lookup("fetch_supply_at", address, |_| {
+            self.supply_history.get(&key).cloned()
+                .or_else(|| self.facts_history.get(&key).and_then(|f| f.supply.clone()))
+        })
+    }
+
+    async fn fetch_metadata_at(&self, address: &str, slot_or_block: u64) -> Result<Metadata, ProviderError> {
+        let key = (address.to_string(), slot_or_block);
+        self.lookup("fetch_metadata_at", address, |_| self.facts_history.get(&key).and_then(|f| f.metadata.clone()))
+    }
+
+    async fn fetch_authorities_at(&self, address: &str, slot_or_block: u64) -> Result<AuthorityInfo, ProviderError> {
+        let key = (address.to_string(), slot_or_block);
+        self.lookup("fetch_authorities_at", address, |_| self.facts_history.get(&key).and_then(|f| f.authorities.clone()))
+    }
+
+    async fn fetch_labels(&self, addresses: &[String]) -> HashMap<String, AddressLabel> {
+        addresses.iter()
+            .filter_map(|a| self.labels.get(a).map(|label| (a.clone(), label.clone())))
+            .collect()
     }
 }
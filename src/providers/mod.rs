@@ -2,32 +2,373 @@
 
 use async_trait::async_trait;
 use crate::types::*;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::Semaphore;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum ProviderError {
     Timeout,
     InvalidResponse,
     NetworkError(String),
     NotFound,
+    /// HTTP 429 from the provider
+    RateLimited,
+    /// HTTP 401/403: the provider rejected our credentials
+    Unauthorized,
+    /// The endpoint could not be reached at all (DNS, connection refused)
+    Unreachable(String),
+    /// The EVM executed the call and it reverted; the contract answered,
+    /// just not with data
+    Reverted(String),
+    /// The provider answered only part of what the fact needs, and the
+    /// part it left out could change the answer
+    Incomplete(String),
+}
+
+impl ProviderError {
+    /// Stable machine-readable token for API clients
+    pub fn code(&self) -> &'static str {
+        match self {
+            ProviderError::Timeout => "timeout",
+            ProviderError::InvalidResponse => "invalid_response",
+            ProviderError::NetworkError(_) => "network",
+            ProviderError::NotFound => "not_found",
+            ProviderError::RateLimited => "rate_limited",
+            ProviderError::Unauthorized => "unauthorized",
+            ProviderError::Unreachable(_) => "unreachable",
+            ProviderError::Reverted(_) => "reverted",
+            ProviderError::Incomplete(_) => "incomplete",
+        }
+    }
+
+    /// Every further call to the provider would fail the same way;
+    /// retrying or fetching other facts is pointless
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, ProviderError::Unauthorized | ProviderError::Unreachable(_))
+    }
+
+    /// Map a failed send: connection-level failures are `Unreachable`
+    pub(crate) fn from_send(error: reqwest::Error) -> Self {
+        if error.is_connect() {
+            ProviderError::Unreachable(error.to_string())
+        } else {
+            ProviderError::NetworkError(error.to_string())
+        }
+    }
+}
+
+/// Facts a provider can fetch. `gather_facts` skips the others instead of
+/// issuing calls that can only come back empty.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProviderCapabilities {
+    pub metadata: bool,
+    pub supply: bool,
+    pub historical_supply: bool,
+    pub authorities: bool,
+    pub holders: bool,
+    pub creation: bool,
+    pub tax: bool,
+    pub authority_history: bool,
+    pub bytecode: bool,
+    pub mint_simulation: bool,
+    pub rent: bool,
+    /// Token name and symbol, as part of metadata
+    pub identity: bool,
+    /// Metadata, supply and authorities as of a past slot or block
+    pub point_in_time: bool,
+}
+
+impl ProviderCapabilities {
+    pub fn all() -> Self {
+        Self {
+            metadata: true,
+            supply: true,
+            historical_supply: true,
+            authorities: true,
+            holders: true,
+            creation: true,
+            tax: true,
+            authority_history: true,
+            bytecode: true,
+            mint_simulation: true,
+            rent: true,
+            identity: true,
+            point_in_time: true,
+        }
+    }
+
+    /// Whether `fact` (a `TokenFacts` field name) can be fetched; facts not
+    /// backed by a provider call (e.g. "image") always can
+    pub fn supports(&self, fact: &str) -> bool {
+        match fact {
+            "metadata" => self.metadata,
+            "supply" => self.supply,
+            "historical_supply" => self.historical_supply,
+            "authorities" => self.authorities,
+            "holders" => self.holders,
+            "creation" => self.creation,
+            "tax" => self.tax,
+            "authority_history" => self.authority_history,
+            "bytecode" => self.bytecode,
+            "mint_simulation" => self.mint_simulation,
+            "rent" => self.rent,
+            "identity" => self.identity,
+            _ => true,
+        }
+    }
+}
+
+impl Default for ProviderCapabilities {
+    fn default() -> Self {
+        Self::all()
+    }
 }
 
 #[async_trait]
-pub trait TokenProvider {
+pub trait TokenProvider: Send + Sync {
     fn provider_name(&self) -> &str;
+
+    /// Facts this provider can fetch; everything by default
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities::all()
+    }
+
+    /// Cheapest call that proves the provider is reachable and accepts our
+    /// credentials. Providers without one report success.
+    async fn ping(&self) -> Result<(), ProviderError> {
+        Ok(())
+    }
     
     async fn fetch_metadata(&self, address: &str) -> Result<Metadata, ProviderError>;
     async fn fetch_supply(&self, address: &str) -> Result<SupplyInfo, ProviderError>;
     async fn fetch_authorities(&self, address: &str) -> Result<AuthorityInfo, ProviderError>;
-    async fn fetch_holders(&self, address: &str, limit: usize) -> Result<HolderInfo, ProviderError>;
+    /// Top holders of `address`. With `HolderStrategy::Aggregate` the
+    /// `top_holders` list may be left empty.
+    async fn fetch_holders(
+        &self,
+        address: &str,
+        limit: usize,
+        strategy: HolderStrategy,
+    ) -> Result<HolderInfo, ProviderError>;
     async fn fetch_creation_time(&self, address: &str) -> Result<CreationInfo, ProviderError>;
+
+    /// Where a transfer tax is routed. Providers without tax detection return `NotFound`.
+    async fn fetch_tax_info(&self, _address: &str) -> Result<TaxInfo, ProviderError> {
+        Err(ProviderError::NotFound)
+    }
+
+    /// Recent authority reassignments, newest first. Providers without
+    /// transaction history return `NotFound`.
+    async fn fetch_authority_history(&self, _address: &str) -> Result<Vec<AuthorityChange>, ProviderError> {
+        Err(ProviderError::NotFound)
+    }
+
+    /// Supply as of an earlier slot (Solana) or block (EVM). Providers
+    /// without archival state return `NotFound`.
+    async fn fetch_supply_at(&self, _address: &str, _slot_or_block: u64) -> Result<SupplyInfo, ProviderError> {
+        Err(ProviderError::NotFound)
+    }
+
+    /// Metadata as of an earlier slot or block. Providers without
+    /// archival state return `NotFound`.
+    async fn fetch_metadata_at(&self, _address: &str, _slot_or_block: u64) -> Result<Metadata, ProviderError> {
+        Err(ProviderError::NotFound)
+    }
+
+    /// Authorities as of an earlier slot or block. Providers without
+    /// archival state return `NotFound`.
+    async fn fetch_authorities_at(&self, _address: &str, _slot_or_block: u64) -> Result<AuthorityInfo, ProviderError> {
+        Err(ProviderError::NotFound)
+    }
+
+    /// Deployed runtime bytecode hash (EVM). Other providers return `NotFound`.
+    async fn fetch_bytecode(&self, _address: &str) -> Result<BytecodeInfo, ProviderError> {
+        Err(ProviderError::NotFound)
+    }
+
+    /// Call `mint` from an unprivileged address without sending a
+    /// transaction and classify how it ends (EVM). Best effort; other
+    /// providers return `NotFound`.
+    async fn simulate_mint(&self, _address: &str) -> Result<MintSimulation, ProviderError> {
+        Err(ProviderError::NotFound)
+    }
+
+    /// Lamports of the mint account against the rent-exempt minimum for
+    /// its size (Solana). Other providers return `NotFound`.
+    async fn fetch_rent_status(&self, _address: &str) -> Result<RentInfo, ProviderError> {
+        Err(ProviderError::NotFound)
+    }
+
+    /// Known roles of `addresses` (exchange wallets, pools, ...), keyed by
+    /// address. Best effort; providers without a label source return none.
+    async fn fetch_labels(&self, _addresses: &[String]) -> HashMap<String, AddressLabel> {
+        HashMap::new()
+    }
+}
+
+/// Bounded pool for provider fetches shared by all analyses. At most `limit`
+/// fetches run at once; the rest wait in FIFO order, so bursts turn into
+/// latency instead of a stampede on the provider. Fetches borrow the
+/// provider and address, so they run on the submitting task once admitted
+/// rather than being moved onto spawned workers.
+#[derive(Debug)]
+pub struct ProviderExecutor {
+    limit: usize,
+    // Fair (FIFO) permits: one per concurrently running fetch
+    slots: Semaphore,
+    queued: AtomicUsize,
+    in_flight: AtomicUsize,
+    peak_in_flight: AtomicUsize,
+}
+
+impl ProviderExecutor {
+    pub fn new(limit: usize) -> Self {
+        let limit = limit.max(1);
+        Self {
+            limit,
+            slots: Semaphore::new(limit),
+            queued: AtomicUsize::new(0),
+            in_flight: AtomicUsize::new(0),
+            peak_in_flight: AtomicUsize::new(0),
+        }
+    }
+
+    /// Queue `fetch` and run it once a slot frees up
+    pub async fn run<F: Future>(&self, fetch: F) -> F::Output {
+        // Guards, like the permit, release on drop, so a caller cancelled
+        // mid-wait leaves the counters where it found them
+        let queued = CounterGuard::raise(&self.queued);
+        let permit = self.slots.acquire().await.expect("executor semaphore is never closed");
+        drop(queued);
+
+        let running = CounterGuard::raise(&self.in_flight);
+        self.peak_in_flight.fetch_max(running.value, Ordering::SeqCst);
+
+        let output = fetch.await;
+
+        drop(running);
+        drop(permit);
+        output
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Fetches waiting for a slot
+    pub fn queue_depth(&self) -> usize {
+        self.queued.load(Ordering::SeqCst)
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Highest number of fetches that ever ran at once
+    pub fn peak_in_flight(&self) -> usize {
+        self.peak_in_flight.load(Ordering::SeqCst)
+    }
+}
+
+/// Holds one increment of `counter`, undone on drop
+struct CounterGuard<'a> {
+    counter: &'a AtomicUsize,
+    /// Counter value just after the increment
+    value: usize,
+}
+
+impl<'a> CounterGuard<'a> {
+    fn raise(counter: &'a AtomicUsize) -> Self {
+        let value = counter.fetch_add(1, Ordering::SeqCst) + 1;
+        Self { counter, value }
+    }
+}
+
+impl Drop for CounterGuard<'_> {
+    fn drop(&mut self) {
+        self.counter.fetch_sub(1, Ordering::SeqCst);
+    }
 }
 
 // Module declarations
 pub mod mocks;
 pub mod helius;
 pub mod alchemy;
+pub mod quicknode;
+pub mod solana_parse;
+mod solana_rpc;
+pub mod trace;
+pub mod holder_aggregator;
+pub mod image;
+pub mod quantity;
+#[cfg(test)]
+pub(crate) mod rpc_harness;
 
 // Re-export for testing
 pub use mocks::MockProvider;
 pub use helius::HeliusProvider;
 pub use alchemy::AlchemyProvider;
+pub use quicknode::{QuickNodeMethods, QuickNodeProvider};
+pub use trace::{RpcCallCount, RpcTraceEntry, TraceCollector};
+pub use holder_aggregator::HolderAggregator;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_executor_caps_concurrency_under_flood() {
+        let executor = Arc::new(ProviderExecutor::new(4));
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_seen = Arc::new(AtomicUsize::new(0));
+
+        let tasks: Vec<_> = (0..200).map(|_| {
+            let executor = executor.clone();
+            let running = running.clone();
+            let max_seen = max_seen.clone();
+            tokio::spawn(async move {
+                executor.run(async {
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_seen.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(2)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                }).await
+            })
+        }).collect();
+
+        // The flood backs up behind the four slots
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        assert!(executor.queue_depth() > 0);
+        assert!(executor.in_flight() <= 4);
+
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert!(max_seen.load(Ordering::SeqCst) <= 4);
+        assert_eq!(executor.peak_in_flight(), 4);
+        assert_eq!(executor.queue_depth(), 0);
+        assert_eq!(executor.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_fetches_release_counters() {
+        let executor = ProviderExecutor::new(1);
+        let hang = || executor.run(std::future::pending::<()>());
+
+        // One fetch holds the slot, one waits behind it; both time out
+        let (running, waiting) = tokio::join!(
+            tokio::time::timeout(Duration::from_millis(20), hang()),
+            tokio::time::timeout(Duration::from_millis(20), hang()),
+        );
+        assert!(running.is_err() && waiting.is_err());
+
+        assert_eq!(executor.queue_depth(), 0);
+        assert_eq!(executor.in_flight(), 0);
+        assert_eq!(executor.peak_in_flight(), 1);
+    }
+}
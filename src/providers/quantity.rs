@@ -0,0 +1,66 @@
+// src/providers/quantity.rs
+//
+// RPC implementations disagree on how integer quantities are encoded: a
+// decimal string ("1000"), a JSON number (1000) or, on EVM, a hex string
+// ("0x3e8"). These helpers accept all three.
+
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+/// `deserialize_with` for a quantity kept in string form (see `quantity_string`)
+pub fn string_or_number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let value = Value::deserialize(deserializer)?;
+    quantity_string(&value)
+        .ok_or_else(|| serde::de::Error::custom(format!("expected a string or integer, got {}", value)))
+}
+
+/// String form of a quantity: numbers become decimal strings, strings
+/// (decimal or `0x` hex) pass through as sent. Read the value with
+/// `parse_quantity`, not `str::parse`.
+pub fn quantity_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => match n.as_u64() {
+            Some(v) => Some(v.to_string()),
+            // Beyond u64 serde_json only keeps an f64
+            None => n.as_f64().filter(|v| v.fract() == 0.0 && *v >= 0.0).map(|v| format!("{:.0}", v)),
+        },
+        _ => None,
+    }
+}
+
+/// Integer value of a quantity: `0x` hex string, decimal string or number
+pub fn parse_quantity(value: &Value) -> Option<u128> {
+    match value {
+        Value::String(s) => match s.strip_prefix("0x") {
+            Some("") => Some(0),
+            Some(hex) => u128::from_str_radix(hex, 16).ok(),
+            None => s.parse().ok(),
+        },
+        Value::Number(_) => quantity_string(value)?.parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_quantity_representations() {
+        assert_eq!(parse_quantity(&json!("0x3e8")), Some(1000));
+        assert_eq!(parse_quantity(&json!("1000")), Some(1000));
+        assert_eq!(parse_quantity(&json!(1000)), Some(1000));
+        assert_eq!(parse_quantity(&json!(1e20)), Some(100_000_000_000_000_000_000));
+        assert_eq!(parse_quantity(&json!("0x")), Some(0));
+        assert_eq!(parse_quantity(&json!(null)), None);
+        assert_eq!(parse_quantity(&json!("not a number")), None);
+    }
+
+    #[test]
+    fn test_quantity_string_passes_strings_through() {
+        assert_eq!(quantity_string(&json!("0x3e8")).as_deref(), Some("0x3e8"));
+        assert_eq!(quantity_string(&json!(1000)).as_deref(), Some("1000"));
+    }
+}
@@ -0,0 +1,208 @@
+use async_trait::async_trait;
+use crate::types::*;
+use super::{ProviderCapabilities, TokenProvider, ProviderError};
+use super::solana_parse::{
+    account_rent_fields, controller_kind_from_account, holders_from_largest_accounts,
+    metadata_from_account, multisig_from_account, parse_mint_account, LargestAccountsResponse,
+};
+use super::solana_rpc::{solana_capabilities, SolanaRpcClient};
+use serde_json::json;
+
+/// Standard Solana RPC method for the largest token accounts of a mint
+pub const DEFAULT_HOLDERS_METHOD: &str = "getTokenLargestAccounts";
+
+/// RPC methods behind facts whose source varies between QuickNode
+/// endpoints (add-ons, plan limits)
+#[derive(Clone, Debug)]
+pub struct QuickNodeMethods {
+    /// Method answering in the `getTokenLargestAccounts` shape, e.g. a
+    /// holders add-on; None when the endpoint offers none
+    pub holders: Option<String>,
+}
+
+impl Default for QuickNodeMethods {
+    fn default() -> Self {
+        Self {
+            holders: Some(DEFAULT_HOLDERS_METHOD.to_string()),
+        }
+    }
+}
+
+pub struct QuickNodeProvider {
+    /// Endpoint URL; QuickNode embeds the auth token in the path
+    rpc: SolanaRpcClient,
+    methods: QuickNodeMethods,
+}
+
+impl QuickNodeProvider {
+    pub fn new(rpc_url: impl Into<String>) -> Self {
+        Self {
+            rpc: SolanaRpcClient::new(rpc_url),
+            methods: QuickNodeMethods::default(),
+        }
+    }
+
+    pub fn with_methods(mut self, methods: QuickNodeMethods) -> Self {
+        self.methods = methods;
+        self
+    }
+}
+
+#[async_trait]
+impl TokenProvider for QuickNodeProvider {
+    fn provider_name(&self) -> &str {
+        "quicknode"
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            holders: self.methods.holders.is_some(),
+            ..solana_capabilities()
+        }
+    }
+
+    async fn ping(&self) -> Result<(), ProviderError> {
+        self.rpc.call::<serde_json::Value>("getHealth", json!([])).await.map(|_| ())
+    }
+
+    async fn fetch_metadata(&self, address: &str) -> Result<Metadata, ProviderError> {
+        Ok(metadata_from_account(&self.rpc.account_info(address).await?))
+    }
+
+    async fn fetch_supply(&self, address: &str) -> Result<SupplyInfo, ProviderError> {
+        Ok(parse_mint_account(&self.rpc.account_info(address).await?)?.supply())
+    }
+
+    async fn fetch_authorities(&self, address: &str) -> Result<AuthorityInfo, ProviderError> {
+        let info = parse_mint_account(&self.rpc.account_info(address).await?)?;
+
+        let mint_authority_account = match &info.mint_authority {
+            Some(authority) => self.rpc.authority_account(authority).await,
+            None => None,
+        };
+        let freeze_authority_account = match &info.freeze_authority {
+            Some(authority) => self.rpc.authority_account(authority).await,
+            None => None,
+        };
+
+        Ok(AuthorityInfo {
+            mint_mutable: Some(info.mint_authority.is_some()),
+            mint_authority: info.mint_authority,
+            freeze_authority: info.freeze_authority,
+            owner: None,
+            pending_owner: None,
+            mint_authority_multisig: mint_authority_account.as_ref().and_then(multisig_from_account),
+            freeze_authority_multisig: freeze_authority_account.as_ref().and_then(multisig_from_account),
+            controller_kind: mint_authority_account.as_ref().map(controller_kind_from_account),
+        })
+    }
+
+    async fn fetch_holders(
+        &self,
+        address: &str,
+        limit: usize,
+        strategy: HolderStrategy,
+    ) -> Result<HolderInfo, ProviderError> {
+        let method = self.methods.holders.as_deref().ok_or(ProviderError::NotFound)?;
+        let largest: LargestAccountsResponse = self.rpc.call(method, json!([address])).await?;
+        let mint = parse_mint_account(&self.rpc.account_info(address).await?)?;
+
+        // The same call answers both; aggregate just drops the list
+        let mut holders = holders_from_largest_accounts(largest.value, &mint, limit);
+        if strategy == HolderStrategy::Aggregate {
+            holders.top_holders.clear();
+        }
+        Ok(holders)
+    }
+
+    async fn fetch_creation_time(&self, _address: &str) -> Result<CreationInfo, ProviderError> {
+        // Would require transaction history back to the mint's creation
+        Ok(CreationInfo {
+            created_at: None,
+            age_seconds: None,
+            age_band: AgeBand::Unknown,
+            estimated: false,
+        })
+    }
+
+    async fn fetch_rent_status(&self, address: &str) -> Result<RentInfo, ProviderError> {
+        let (lamports, data_len) = account_rent_fields(&self.rpc.account_info(address).await?)?;
+
+        let minimum = self.rpc.rent_exempt_minimum(data_len).await;
+        Ok(RentInfo::new(lamports, data_len, minimum))
+    }
+
+    async fn fetch_authority_history(&self, address: &str) -> Result<Vec<AuthorityChange>, ProviderError> {
+        self.rpc.authority_history(address).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::rpc_harness::{rpc_result, serve_rpc};
+    use crate::providers::solana_parse::tests::mint_account_blob;
+    use crate::providers::HeliusProvider;
+
+    async fn mint_fixture_url() -> String {
+        let account: serde_json::Value = serde_json::from_str(&mint_account_blob(true)).unwrap();
+        serve_rpc(move |request| {
+            assert_eq!(request["method"], "getAccountInfo");
+            rpc_result(account.clone())
+        }).await
+    }
+
+    #[tokio::test]
+    async fn test_decodes_mint_like_helius() {
+        let url = mint_fixture_url().await;
+        let quicknode = QuickNodeProvider::new(url.clone());
+        let helius = HeliusProvider::with_rpc_url(url);
+
+        let (q, h) = (quicknode.fetch_metadata("Mint").await.unwrap(), helius.fetch_metadata("Mint").await.unwrap());
+        assert_eq!(format!("{:?}", q), format!("{:?}", h));
+        assert_eq!(q.decimals, Some(6));
+
+        let (q, h) = (quicknode.fetch_supply("Mint").await.unwrap(), helius.fetch_supply("Mint").await.unwrap());
+        assert_eq!(format!("{:?}", q), format!("{:?}", h));
+        assert_eq!(q.total_supply, Some(1000.0));
+
+        let (q, h) = (quicknode.fetch_authorities("Mint").await.unwrap(), helius.fetch_authorities("Mint").await.unwrap());
+        assert_eq!(format!("{:?}", q), format!("{:?}", h));
+        assert_eq!(q.mint_mutable, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_holders_from_configured_method() {
+        let account: serde_json::Value = serde_json::from_str(&mint_account_blob(true)).unwrap();
+        let url = serve_rpc(move |request| match request["method"].as_str().unwrap() {
+            "qn_largestHolders" => rpc_result(json!({
+                "context": { "slot": 1 },
+                "value": [
+                    { "address": "AcctB", "amount": "100000000", "decimals": 6 },
+                    { "address": "AcctA", "amount": "400000000", "decimals": 6 }
+                ]
+            })),
+            _ => rpc_result(account.clone()),
+        }).await;
+        let provider = QuickNodeProvider::new(url).with_methods(QuickNodeMethods {
+            holders: Some("qn_largestHolders".to_string()),
+        });
+
+        let holders = provider.fetch_holders("Mint", 10, HolderStrategy::Full).await.unwrap();
+        assert_eq!(holders.top1_pct, Some(40.0));
+        assert_eq!(holders.top5_pct, Some(50.0));
+        assert_eq!(holders.top_holders[0].address, "AcctA");
+        assert_eq!(holders.top_holders[0].balance, Some(400.0));
+        assert_eq!(holders.top_holders[0].kind, HolderKind::TokenAccount);
+    }
+
+    #[test]
+    fn test_holders_capability_follows_method_mapping() {
+        let provider = QuickNodeProvider::new("http://localhost");
+        assert!(provider.capabilities().holders);
+
+        let provider = provider.with_methods(QuickNodeMethods { holders: None });
+        assert!(!provider.capabilities().holders);
+        assert!(provider.capabilities().authority_history);
+    }
+}
@@ -0,0 +1,49 @@
+// src/providers/rpc_harness.rs
+//
+// Local JSON-RPC server for provider unit tests: providers built with
+// `with_rpc_url(harness_url)` exercise their real HTTP and parsing paths
+// offline.
+
+use axum::{extract::State, http::StatusCode, routing::post, Json, Router};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+/// Canned reply to one JSON-RPC request: HTTP status and raw body
+pub type RpcReply = (StatusCode, String);
+
+type Responder = Arc<dyn Fn(&Value) -> RpcReply + Send + Sync>;
+
+/// Serve `respond` on an ephemeral local port; returns the base URL.
+/// `respond` sees the full request body (`method`, `params`, ...).
+pub async fn serve_rpc<F>(respond: F) -> String
+where
+    F: Fn(&Value) -> RpcReply + Send + Sync + 'static,
+{
+    let responder: Responder = Arc::new(respond);
+    let app = Router::new()
+        .route("/", post(handle))
+        .with_state(responder);
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    format!("http://{}/", addr)
+}
+
+async fn handle(State(respond): State<Responder>, Json(request): Json<Value>) -> (StatusCode, String) {
+    respond(&request)
+}
+
+/// 200 with a JSON-RPC `result`
+pub fn rpc_result(result: Value) -> RpcReply {
+    (StatusCode::OK, json!({ "jsonrpc": "2.0", "id": 1, "result": result }).to_string())
+}
+
+/// 200 with a JSON-RPC `error` body
+pub fn rpc_error(code: i64, message: &str) -> RpcReply {
+    (
+        StatusCode::OK,
+        json!({ "jsonrpc": "2.0", "id": 1, "error": { "code": code, "message": message } }).to_string(),
+    )
+}
@@ -0,0 +1,653 @@
+// src/providers/solana_parse.rs
+//
+// Decoding of SPL account and transaction data, shared by the Solana
+// providers so every endpoint reads a mint the same way. Mint accounts are
+// read from `jsonParsed` results or, when the node cannot parse them, from
+// the raw base64 account layout.
+
+use crate::types::*;
+use super::{quantity, HolderAggregator, ProviderError};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Token-2022 program id; accounts it owns may carry extensions
+pub const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+
+/// Size of the base mint layout shared by both token programs
+const MINT_LEN: usize = 82;
+/// Token-2022 pads the base mint to the token-account size, then stores
+/// the account type byte and the extension TLVs
+const ACCOUNT_TYPE_OFFSET: usize = 165;
+const ACCOUNT_TYPE_MINT: u8 = 1;
+const EXTENSION_DEFAULT_ACCOUNT_STATE: u16 = 6;
+
+/// What the token programs record on a mint account
+#[derive(Clone, Debug, PartialEq)]
+pub struct MintFacts {
+    pub decimals: u8,
+    /// Raw units as a decimal string
+    pub supply_raw: String,
+    pub mint_authority: Option<String>,
+    pub freeze_authority: Option<String>,
+    pub is_initialized: Option<bool>,
+    pub standard: TokenStandard,
+    /// Token-2022 only; None for classic SPL mints
+    pub default_account_state: Option<AccountState>,
+}
+
+impl MintFacts {
+    pub fn metadata(&self) -> Metadata {
+        Metadata {
+            name: None, // Would need Metaplex metadata
+            symbol: None, // Would need Metaplex metadata
+            decimals: Some(self.decimals),
+            standard: self.standard.clone(),
+            is_initialized: self.is_initialized,
+            image_uri: None,
+            features: Vec::new(),
+            default_account_state: self.default_account_state.clone(),
+        }
+    }
+
+    pub fn supply(&self) -> SupplyInfo {
+        let total_supply = self.supply_raw.parse::<u64>().ok()
+            .map(|raw| raw as f64 / 10_f64.powi(self.decimals as i32));
+
+        SupplyInfo {
+            total_supply_raw: Some(self.supply_raw.clone()),
+            total_supply,
+            circulating_supply: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ParsedMintInfo {
+    decimals: u8,
+    /// Usually a decimal string; some RPC implementations send a number
+    #[serde(deserialize_with = "quantity::string_or_number")]
+    supply: String,
+    #[serde(rename = "mintAuthority")]
+    mint_authority: Option<String>,
+    #[serde(rename = "freezeAuthority")]
+    freeze_authority: Option<String>,
+    #[serde(rename = "isInitialized", default)]
+    is_initialized: Option<bool>,
+    /// Token-2022 extensions, e.g. `{"extension": "defaultAccountState", ...}`
+    #[serde(default)]
+    extensions: Vec<Value>,
+}
+
+/// Decode the mint in a getAccountInfo result (`{"value": {...}}`).
+/// `NotFound` for a missing account, `InvalidResponse` for data that is
+/// not a mint in either layout.
+pub fn parse_mint_account(value: &Value) -> Result<MintFacts, ProviderError> {
+    let account = &value["value"];
+    if account.is_null() {
+        return Err(ProviderError::NotFound);
+    }
+
+    let data = &account["data"];
+    if data["parsed"].is_object() {
+        return parse_json_mint(data);
+    }
+    match data.as_array().map(Vec::as_slice) {
+        Some([Value::String(blob), Value::String(encoding)]) if encoding == "base64" => {
+            let bytes = STANDARD.decode(blob).map_err(|_| ProviderError::InvalidResponse)?;
+            let token_2022 = account["owner"] == TOKEN_2022_PROGRAM_ID;
+            parse_raw_mint(&bytes, token_2022).ok_or(ProviderError::InvalidResponse)
+        }
+        _ => Err(ProviderError::InvalidResponse),
+    }
+}
+
+fn parse_json_mint(data: &Value) -> Result<MintFacts, ProviderError> {
+    if data["parsed"]["type"] != "mint" {
+        return Err(ProviderError::InvalidResponse);
+    }
+    let info: ParsedMintInfo = serde_json::from_value(data["parsed"]["info"].clone())
+        .map_err(|_| ProviderError::InvalidResponse)?;
+    let token_2022 = data["program"] == "spl-token-2022";
+
+    Ok(MintFacts {
+        default_account_state: if token_2022 { default_account_state(&info.extensions) } else { None },
+        decimals: info.decimals,
+        supply_raw: info.supply,
+        mint_authority: info.mint_authority,
+        freeze_authority: info.freeze_authority,
+        is_initialized: info.is_initialized,
+        standard: if token_2022 { TokenStandard::SplToken2022 } else { TokenStandard::SplToken },
+    })
+}
+
+/// Token-2022 default account state: the `defaultAccountState` extension
+/// when present, otherwise accounts start initialized
+fn default_account_state(extensions: &[Value]) -> Option<AccountState> {
+    let extension = extensions.iter()
+        .find(|ext| ext["extension"] == "defaultAccountState");
+    match extension {
+        None => Some(AccountState::Initialized),
+        Some(ext) => match ext["state"]["accountState"].as_str()? {
+            "frozen" => Some(AccountState::Frozen),
+            "initialized" => Some(AccountState::Initialized),
+            _ => None,
+        },
+    }
+}
+
+/// Base mint layout: mint authority (COption<Pubkey>), supply (u64 LE),
+/// decimals, is_initialized, freeze authority (COption<Pubkey>)
+fn parse_raw_mint(bytes: &[u8], token_2022: bool) -> Option<MintFacts> {
+    if bytes.len() < MINT_LEN {
+        return None;
+    }
+    let supply = u64::from_le_bytes(bytes[36..44].try_into().ok()?);
+
+    Some(MintFacts {
+        decimals: bytes[44],
+        supply_raw: supply.to_string(),
+        mint_authority: optional_pubkey(&bytes[0..36])?,
+        freeze_authority: optional_pubkey(&bytes[46..82])?,
+        is_initialized: Some(bytes[45] == 1),
+        standard: if token_2022 { TokenStandard::SplToken2022 } else { TokenStandard::SplToken },
+        default_account_state: if token_2022 { raw_default_account_state(bytes) } else { None },
+    })
+}
+
+/// COption<Pubkey>: u32 LE tag (0 = None, 1 = Some), then 32 key bytes.
+/// Outer None for an invalid tag.
+fn optional_pubkey(field: &[u8]) -> Option<Option<String>> {
+    match u32::from_le_bytes(field[0..4].try_into().ok()?) {
+        0 => Some(None),
+        1 => Some(Some(bs58::encode(&field[4..36]).into_string())),
+        _ => None,
+    }
+}
+
+/// Default account state from the Token-2022 extension TLVs (u16 type,
+/// u16 length, value)
+fn raw_default_account_state(bytes: &[u8]) -> Option<AccountState> {
+    if bytes.len() <= ACCOUNT_TYPE_OFFSET {
+        return Some(AccountState::Initialized);
+    }
+    if bytes[ACCOUNT_TYPE_OFFSET] != ACCOUNT_TYPE_MINT {
+        return None;
+    }
+
+    let mut tlv = &bytes[ACCOUNT_TYPE_OFFSET + 1..];
+    while tlv.len() >= 4 {
+        let kind = u16::from_le_bytes([tlv[0], tlv[1]]);
+        let len = u16::from_le_bytes([tlv[2], tlv[3]]) as usize;
+        let value = tlv.get(4..4 + len)?;
+        if kind == EXTENSION_DEFAULT_ACCOUNT_STATE {
+            return match value.first()? {
+                1 => Some(AccountState::Initialized),
+                2 => Some(AccountState::Frozen),
+                _ => None,
+            };
+        }
+        tlv = &tlv[4 + len..];
+    }
+    Some(AccountState::Initialized)
+}
+
+/// Lamports and data size of the account in a getAccountInfo result, for
+/// the rent-exempt comparison
+pub fn account_rent_fields(value: &Value) -> Result<(u64, u64), ProviderError> {
+    let account = &value["value"];
+    if account.is_null() {
+        return Err(ProviderError::NotFound);
+    }
+    let lamports = account["lamports"].as_u64().ok_or(ProviderError::InvalidResponse)?;
+
+    let data = &account["data"];
+    let data_len = match data.as_array().map(Vec::as_slice) {
+        Some([Value::String(blob), Value::String(encoding)]) if encoding == "base64" => {
+            STANDARD.decode(blob).ok().map(|bytes| bytes.len() as u64)
+        }
+        _ => data["space"].as_u64(),
+    };
+    Ok((lamports, data_len.ok_or(ProviderError::InvalidResponse)?))
+}
+
+/// Metadata of the mint in a getAccountInfo result; decimals and standard
+/// stay unknown when the account cannot be decoded
+pub fn metadata_from_account(value: &Value) -> Metadata {
+    parse_mint_account(value)
+        .map(|mint| mint.metadata())
+        .unwrap_or_else(|_| Metadata {
+            standard: TokenStandard::SplToken,
+            ..Default::default()
+        })
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct SignatureInfo {
+    pub(crate) signature: String,
+    #[serde(rename = "blockTime")]
+    pub(crate) block_time: Option<i64>,
+    pub(crate) err: Option<serde_json::Value>,
+}
+
+/// `getTokenLargestAccounts` result
+#[derive(Debug, Deserialize)]
+pub(crate) struct LargestAccountsResponse {
+    pub(crate) value: Vec<TokenAccountBalance>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct TokenAccountBalance {
+    pub(crate) address: String,
+    /// Raw units as a decimal string
+    #[serde(deserialize_with = "quantity::string_or_number")]
+    pub(crate) amount: String,
+}
+
+/// `SetAuthority` instructions targeting `mint` in a jsonParsed transaction,
+/// including inner (CPI) instructions
+pub(crate) fn authority_changes_in_transaction(
+    transaction: &serde_json::Value,
+    mint: &str,
+    signature: &str,
+    block_time: Option<i64>,
+    now: i64,
+) -> Vec<AuthorityChange> {
+    let outer = transaction["transaction"]["message"]["instructions"]
+        .as_array()
+        .into_iter()
+        .flatten();
+    let inner = transaction["meta"]["innerInstructions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|group| group["instructions"].as_array())
+        .flatten();
+
+    outer.chain(inner)
+        .filter(|ix| ix["parsed"]["type"] == "setAuthority")
+        .map(|ix| &ix["parsed"]["info"])
+        .filter(|info| info["account"] == mint || info["mint"] == mint)
+        .map(|info| AuthorityChange {
+            authority_type: info["authorityType"].as_str().unwrap_or("unknown").to_string(),
+            new_authority: info["newAuthority"].as_str().map(|s| s.to_string()),
+            signature: Some(signature.to_string()),
+            block_time,
+            age_seconds: block_time.map(|t| now.saturating_sub(t).max(0) as u64),
+        })
+        .collect()
+}
+
+/// `m`/`n` from a jsonParsed getAccountInfo result for an SPL multisig
+pub(crate) fn multisig_from_account(account: &serde_json::Value) -> Option<MultisigInfo> {
+    let parsed = &account["value"]["data"]["parsed"];
+    if parsed["type"] != "multisig" {
+        return None;
+    }
+
+    let info = &parsed["info"];
+    Some(MultisigInfo {
+        m: info["numRequiredSigners"].as_u64()? as u8,
+        n: info["numValidSigners"].as_u64()? as u8,
+    })
+}
+
+/// Owner of plain wallet accounts
+const SYSTEM_PROGRAM: &str = "11111111111111111111111111111111";
+
+/// Whether a jsonParsed getAccountInfo result is a keypair wallet or
+/// program-controlled. A missing account is an unfunded keypair; anything
+/// not owned by the System Program (a multisig, a PDA, a program) is
+/// program-controlled, except a multisig any one signer can act for.
+pub(crate) fn controller_kind_from_account(account: &serde_json::Value) -> ControllerKind {
+    let value = &account["value"];
+    if value.is_null() {
+        return ControllerKind::Key;
+    }
+    if multisig_from_account(account).is_some_and(|multisig| !multisig.requires_multiple_signers()) {
+        return ControllerKind::Key;
+    }
+    let executable = value["executable"].as_bool().unwrap_or(false);
+    if !executable && value["owner"] == SYSTEM_PROGRAM {
+        ControllerKind::Key
+    } else {
+        ControllerKind::Program
+    }
+}
+
+/// Top `limit` holders from the largest token accounts of a mint. These
+/// are token accounts, not owner wallets, and the list is capped by the
+/// node (20 for `getTokenLargestAccounts`), so no holder count.
+pub(crate) fn holders_from_largest_accounts(
+    accounts: Vec<TokenAccountBalance>,
+    mint: &MintFacts,
+    limit: usize,
+) -> HolderInfo {
+    let supply_raw = mint.supply_raw.parse::<u128>().ok().filter(|&s| s > 0);
+
+    let mut aggregator = HolderAggregator::new(limit);
+    for account in accounts {
+        if let Ok(raw) = account.amount.parse::<u128>() {
+            aggregator.push(account.address, raw);
+        }
+    }
+    aggregator.finish_listing(mint.decimals, supply_raw, HolderKind::TokenAccount)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// getAccountInfo result for a classic SPL mint: 6 decimals, 1000
+    /// tokens, both authorities disabled
+    pub(crate) fn mint_account_blob(is_initialized: bool) -> String {
+        json!({
+            "context": { "slot": 1 },
+            "value": {
+                "data": {
+                    "parsed": {
+                        "info": {
+                            "decimals": 6,
+                            "freezeAuthority": null,
+                            "isInitialized": is_initialized,
+                            "mintAuthority": null,
+                            "supply": "1000000000"
+                        },
+                        "type": "mint"
+                    },
+                    "program": "spl-token",
+                    "space": 82
+                },
+                "executable": false,
+                "lamports": 1461600,
+                "owner": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            }
+        }).to_string()
+    }
+
+    fn fixture(is_initialized: bool) -> Value {
+        serde_json::from_str(&mint_account_blob(is_initialized)).unwrap()
+    }
+
+    /// getAccountInfo result carrying `bytes` as base64 account data
+    fn raw_account(bytes: &[u8], owner: &str) -> Value {
+        json!({
+            "context": { "slot": 1 },
+            "value": { "data": [STANDARD.encode(bytes), "base64"], "owner": owner }
+        })
+    }
+
+    /// Base mint layout with `mint_authority` set and no freeze authority
+    fn raw_mint(mint_authority: [u8; 32], supply: u64, decimals: u8) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(MINT_LEN);
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&mint_authority);
+        bytes.extend_from_slice(&supply.to_le_bytes());
+        bytes.push(decimals);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 36]);
+        bytes
+    }
+
+    /// Token program id, a well-known 32-byte key
+    const TOKEN_PROGRAM_KEY: [u8; 32] = [
+        0x06, 0xdd, 0xf6, 0xe1, 0xd7, 0x65, 0xa1, 0x93, 0xd9, 0xcb, 0xe1, 0x46, 0xce, 0xeb, 0x79, 0xac,
+        0x1c, 0xb4, 0x85, 0xed, 0x5f, 0x5b, 0x37, 0x91, 0x3a, 0x8c, 0xf5, 0x85, 0x7e, 0xff, 0x00, 0xa9,
+    ];
+
+    #[test]
+    fn test_parsed_mint_decoded() {
+        let mint = parse_mint_account(&fixture(true)).unwrap();
+
+        assert_eq!(mint, MintFacts {
+            decimals: 6,
+            supply_raw: "1000000000".to_string(),
+            mint_authority: None,
+            freeze_authority: None,
+            is_initialized: Some(true),
+            standard: TokenStandard::SplToken,
+            default_account_state: None,
+        });
+        assert_eq!(mint.supply().total_supply, Some(1000.0));
+    }
+
+    #[test]
+    fn test_raw_mint_decoded() {
+        let bytes = raw_mint(TOKEN_PROGRAM_KEY, 1_000_000_000, 6);
+        let mint = parse_mint_account(&raw_account(&bytes, "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")).unwrap();
+
+        assert_eq!(mint.decimals, 6);
+        assert_eq!(mint.supply_raw, "1000000000");
+        assert_eq!(mint.mint_authority.as_deref(), Some("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"));
+        assert_eq!(mint.freeze_authority, None);
+        assert_eq!(mint.is_initialized, Some(true));
+        assert_eq!(mint.standard, TokenStandard::SplToken);
+    }
+
+    #[test]
+    fn test_raw_token_2022_default_frozen() {
+        let mut bytes = raw_mint([7; 32], 5, 0);
+        bytes.resize(ACCOUNT_TYPE_OFFSET, 0);
+        bytes.push(ACCOUNT_TYPE_MINT);
+        bytes.extend_from_slice(&EXTENSION_DEFAULT_ACCOUNT_STATE.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.push(2);
+
+        let mint = parse_mint_account(&raw_account(&bytes, TOKEN_2022_PROGRAM_ID)).unwrap();
+        assert_eq!(mint.standard, TokenStandard::SplToken2022);
+        assert_eq!(mint.default_account_state, Some(AccountState::Frozen));
+    }
+
+    #[test]
+    fn test_rent_fields_from_either_layout() {
+        assert_eq!(account_rent_fields(&fixture(true)).unwrap(), (1_461_600, 82));
+
+        let mut raw = raw_account(&raw_mint([7; 32], 5, 0), "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+        raw["value"]["lamports"] = json!(890_880);
+        assert_eq!(account_rent_fields(&raw).unwrap(), (890_880, MINT_LEN as u64));
+    }
+
+    #[test]
+    fn test_undecodable_accounts_rejected() {
+        let truncated = raw_account(&[1, 0, 0, 0], "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+        assert!(matches!(parse_mint_account(&truncated), Err(ProviderError::InvalidResponse)));
+
+        let missing = json!({ "context": { "slot": 1 }, "value": null });
+        assert!(matches!(parse_mint_account(&missing), Err(ProviderError::NotFound)));
+    }
+
+    #[test]
+    fn test_token_2022_default_frozen_parsed() {
+        let blob = json!({
+            "value": {
+                "data": {
+                    "parsed": {
+                        "info": {
+                            "decimals": 6,
+                            "freezeAuthority": "FreezeKey",
+                            "isInitialized": true,
+                            "mintAuthority": null,
+                            "supply": "1000000000",
+                            "extensions": [
+                                { "extension": "defaultAccountState", "state": { "accountState": "frozen" } }
+                            ]
+                        },
+                        "type": "mint"
+                    },
+                    "program": "spl-token-2022"
+                }
+            }
+        });
+        let metadata = metadata_from_account(&blob);
+
+        assert_eq!(metadata.standard, TokenStandard::SplToken2022);
+        assert_eq!(metadata.default_account_state, Some(AccountState::Frozen));
+
+        // Classic SPL mints have no default-account-state extension
+        let metadata = metadata_from_account(&fixture(true));
+        assert_eq!(metadata.standard, TokenStandard::SplToken);
+        assert_eq!(metadata.default_account_state, None);
+    }
+
+    #[test]
+    fn test_supply_as_string_or_number() {
+        let payload = |supply: serde_json::Value| json!({
+            "value": {
+                "data": {
+                    "parsed": {
+                        "info": {
+                            "decimals": 6,
+                            "freezeAuthority": null,
+                            "mintAuthority": null,
+                            "supply": supply
+                        },
+                        "type": "mint"
+                    },
+                    "program": "spl-token"
+                }
+            }
+        });
+
+        for supply in [json!("1000000000"), json!(1000000000u64)] {
+            let mint = parse_mint_account(&payload(supply)).unwrap();
+            assert_eq!(mint.supply_raw, "1000000000");
+        }
+    }
+
+    #[test]
+    fn test_initialized_mint_blob() {
+        let metadata = metadata_from_account(&fixture(true));
+
+        assert_eq!(metadata.is_initialized, Some(true));
+        assert_eq!(metadata.decimals, Some(6));
+    }
+
+    #[test]
+    fn test_uninitialized_mint_blob() {
+        let metadata = metadata_from_account(&fixture(false));
+
+        assert_eq!(metadata.is_initialized, Some(false));
+    }
+
+    #[test]
+    fn test_multisig_threshold_parsed() {
+        let multisig = json!({
+            "context": { "slot": 1 },
+            "value": {
+                "data": {
+                    "parsed": {
+                        "info": {
+                            "isInitialized": true,
+                            "numRequiredSigners": 3,
+                            "numValidSigners": 5,
+                            "signers": []
+                        },
+                        "type": "multisig"
+                    },
+                    "program": "spl-token"
+                }
+            }
+        });
+        assert_eq!(multisig_from_account(&multisig), Some(MultisigInfo { m: 3, n: 5 }));
+
+        // A plain wallet has no parsed token data
+        let wallet = json!({
+            "context": { "slot": 1 },
+            "value": { "data": ["", "base64"], "owner": "11111111111111111111111111111111" }
+        });
+        assert_eq!(multisig_from_account(&wallet), None);
+    }
+
+    #[test]
+    fn test_controller_kind_from_account() {
+        let wallet = json!({
+            "context": { "slot": 1 },
+            "value": { "data": ["", "base64"], "owner": "11111111111111111111111111111111", "executable": false }
+        });
+        assert_eq!(controller_kind_from_account(&wallet), ControllerKind::Key);
+        // Unfunded keypairs have no account at all
+        assert_eq!(controller_kind_from_account(&json!({ "context": { "slot": 1 }, "value": null })), ControllerKind::Key);
+
+        let multisig = json!({
+            "context": { "slot": 1 },
+            "value": { "data": ["", "base64"], "owner": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", "executable": false }
+        });
+        assert_eq!(controller_kind_from_account(&multisig), ControllerKind::Program);
+
+        // 1-of-n: any single signer holds the authority
+        let single_signer = json!({
+            "context": { "slot": 1 },
+            "value": {
+                "data": {
+                    "parsed": {
+                        "info": { "isInitialized": true, "numRequiredSigners": 1, "numValidSigners": 3, "signers": [] },
+                        "type": "multisig"
+                    },
+                    "program": "spl-token"
+                },
+                "owner": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                "executable": false
+            }
+        });
+        assert_eq!(controller_kind_from_account(&single_signer), ControllerKind::Key);
+    }
+
+    #[test]
+    fn test_set_authority_parsed_from_transaction() {
+        let mint = "Mint1111111111111111111111111111111111111111";
+        let transaction = json!({
+            "meta": {
+                "innerInstructions": [{
+                    "index": 0,
+                    "instructions": [{
+                        "parsed": {
+                            "info": {
+                                "account": mint,
+                                "authority": "OldAuthority",
+                                "authorityType": "freezeAccount",
+                                "newAuthority": null
+                            },
+                            "type": "setAuthority"
+                        },
+                        "program": "spl-token"
+                    }]
+                }]
+            },
+            "transaction": {
+                "message": {
+                    "instructions": [
+                        {
+                            "parsed": {
+                                "info": {
+                                    "account": mint,
+                                    "authority": "OldAuthority",
+                                    "authorityType": "mintTokens",
+                                    "newAuthority": "NewAuthority"
+                                },
+                                "type": "setAuthority"
+                            },
+                            "program": "spl-token"
+                        },
+                        {
+                            "parsed": { "info": {}, "type": "transfer" },
+                            "program": "system"
+                        }
+                    ]
+                }
+            }
+        });
+
+        let changes = authority_changes_in_transaction(&transaction, mint, "sig1", Some(1_000), 4_600);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].authority_type, "mintTokens");
+        assert_eq!(changes[0].new_authority.as_deref(), Some("NewAuthority"));
+        assert_eq!(changes[0].age_seconds, Some(3_600));
+        assert_eq!(changes[1].authority_type, "freezeAccount");
+        assert_eq!(changes[1].new_authority, None);
+
+        assert!(authority_changes_in_transaction(&transaction, "OtherMint", "sig1", None, 0).is_empty());
+    }
+}
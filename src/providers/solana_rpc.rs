@@ -0,0 +1,298 @@
+// src/providers/solana_rpc.rs
+//
+// JSON-RPC transport shared by the Solana providers: the standard methods
+// answer the same way whichever node serves them.
+
+use crate::checks::authority_stability::RECENT_TRANSFER_WINDOW_SECS;
+use crate::types::AuthorityChange;
+use super::{trace, ProviderCapabilities, ProviderError};
+use super::solana_parse::{authority_changes_in_transaction, SignatureInfo};
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Most recent signatures scanned for `SetAuthority` instructions
+pub(crate) const AUTHORITY_HISTORY_SIGNATURE_LIMIT: usize = 25;
+
+/// `getTransaction` calls in flight at once during a history scan
+const AUTHORITY_HISTORY_CONCURRENCY: usize = 5;
+
+/// What the standard Solana methods can serve. Names live in Metaplex
+/// metadata accounts, which are not read; account state is only served at
+/// the tip, with no creation lookup or archival supply; tax, bytecode and
+/// mint simulation are EVM concepts.
+pub(crate) fn solana_capabilities() -> ProviderCapabilities {
+    ProviderCapabilities {
+        identity: false,
+        point_in_time: false,
+        creation: false,
+        historical_supply: false,
+        tax: false,
+        bytecode: false,
+        mint_simulation: false,
+        ..ProviderCapabilities::all()
+    }
+}
+
+pub(crate) struct SolanaRpcClient {
+    rpc_url: String,
+    // Shared connection pool, reused across requests
+    client: reqwest::Client,
+    /// Rent-exempt minimum by account size; fixed by the cluster's rent
+    /// parameters, so asked once per size
+    rent_minimums: Mutex<HashMap<u64, u64>>,
+}
+
+impl SolanaRpcClient {
+    pub(crate) fn new(rpc_url: impl Into<String>) -> Self {
+        Self {
+            rpc_url: rpc_url.into(),
+            client: reqwest::Client::new(),
+            rent_minimums: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) async fn call<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<T, ProviderError> {
+        self.call_nullable(method, params).await?.ok_or(ProviderError::InvalidResponse)
+    }
+
+    /// `call` for methods that answer a missing item with a null result
+    pub(crate) async fn call_nullable<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<Option<T>, ProviderError> {
+        let started = Instant::now();
+
+        let text = match self.request(method, &params).await {
+            Ok(text) => text,
+            Err(e) => {
+                trace::record_call(method, &params, &format!("{:?}", e), started, None);
+                return Err(e);
+            }
+        };
+
+        let result = parse_rpc_response(&text);
+
+        let status = match &result {
+            Ok(_) => "ok".to_string(),
+            Err(e) => format!("{:?}", e),
+        };
+        let body = if trace::wants_bodies() { serde_json::from_str(&text).ok() } else { None };
+        trace::record_call(method, &params, &status, started, body);
+
+        result
+    }
+
+    async fn request(
+        &self,
+        method: &str,
+        params: &serde_json::Value,
+    ) -> Result<String, ProviderError> {
+        let request_body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let response = self.client
+            .post(&self.rpc_url)
+            .json(&request_body)
+            .timeout(std::time::Duration::from_secs(10))
+            .send()
+            .await
+            .map_err(ProviderError::from_send)?;
+
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(ProviderError::RateLimited);
+        }
+        if matches!(response.status(), reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN) {
+            return Err(ProviderError::Unauthorized);
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            eprintln!("RPC Error - Status: {}, Body: {}", status, body);
+            return Err(ProviderError::InvalidResponse);
+        }
+
+        response.text().await
+            .map_err(|e| ProviderError::NetworkError(e.to_string()))
+    }
+
+    /// `getAccountInfo` of `address`, jsonParsed. Metadata, supply,
+    /// authorities and rent all read the mint account, so within one
+    /// analysis's trace scope it is fetched once.
+    pub(crate) async fn account_info(&self, address: &str) -> Result<serde_json::Value, ProviderError> {
+        trace::memoized(&format!("getAccountInfo:{}", address), || self.call(
+            "getAccountInfo",
+            json!([
+                address,
+                {
+                    "encoding": "jsonParsed"
+                }
+            ])
+        )).await
+    }
+
+    /// Lamports an account of `data_len` bytes must hold to be rent-exempt;
+    /// None when the node would not say
+    pub(crate) async fn rent_exempt_minimum(&self, data_len: u64) -> Option<u64> {
+        let known = self.rent_minimums.lock().unwrap().get(&data_len).copied();
+        if known.is_some() {
+            return known;
+        }
+
+        let minimum: u64 = self.call("getMinimumBalanceForRentExemption", json!([data_len])).await.ok()?;
+        self.rent_minimums.lock().unwrap().insert(data_len, minimum);
+        Some(minimum)
+    }
+
+    /// Account holding `authority`, to tell multisigs and programs from
+    /// wallets. Lookup failures leave both unknown.
+    pub(crate) async fn authority_account(&self, authority: &str) -> Option<serde_json::Value> {
+        self.account_info(authority).await.ok()
+    }
+
+    /// `SetAuthority` changes among the most recent signatures of `address`.
+    /// `Incomplete` when the scan found no recent transfer but stopped short
+    /// of the recent-transfer window, so one could lie beyond it.
+    pub(crate) async fn authority_history(&self, address: &str) -> Result<Vec<AuthorityChange>, ProviderError> {
+        let signatures: Vec<SignatureInfo> = self.call(
+            "getSignaturesForAddress",
+            json!([
+                address,
+                {
+                    "limit": AUTHORITY_HISTORY_SIGNATURE_LIMIT
+                }
+            ])
+        ).await?;
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        let succeeded: Vec<&SignatureInfo> = signatures.iter().filter(|s| s.err.is_none()).collect();
+        let params: Vec<serde_json::Value> = succeeded.iter()
+            .map(|info| json!([
+                info.signature,
+                {
+                    "encoding": "jsonParsed",
+                    "maxSupportedTransactionVersion": 0
+                }
+            ]))
+            .collect();
+        let transactions: Vec<Option<serde_json::Value>> = stream::iter(params)
+            .map(|params| self.call_nullable("getTransaction", params))
+            .buffered(AUTHORITY_HISTORY_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        // Transactions the node no longer keeps come back null: skip them
+        let changes: Vec<AuthorityChange> = succeeded.iter()
+            .zip(&transactions)
+            .filter_map(|(info, transaction)| Some((info, transaction.as_ref()?)))
+            .flat_map(|(info, transaction)| authority_changes_in_transaction(
+                transaction,
+                address,
+                &info.signature,
+                info.block_time,
+                now,
+            ))
+            .collect();
+
+        let recent_transfer = changes.iter().any(|c| {
+            c.new_authority.is_some() && c.age_seconds.is_some_and(|age| age < RECENT_TRANSFER_WINDOW_SECS)
+        });
+        if !recent_transfer && !covers_recent_window(&signatures, now) {
+            return Err(ProviderError::Incomplete(format!(
+                "{} most recent signatures do not reach back {} seconds",
+                signatures.len(), RECENT_TRANSFER_WINDOW_SECS
+            )));
+        }
+
+        Ok(changes)
+    }
+}
+
+/// Whether `signatures` (newest first) saw every signature in the
+/// recent-transfer window: a short page is the whole history, a full one
+/// must end past the window
+fn covers_recent_window(signatures: &[SignatureInfo], now: i64) -> bool {
+    if signatures.len() < AUTHORITY_HISTORY_SIGNATURE_LIMIT {
+        return true;
+    }
+    signatures.last()
+        .and_then(|oldest| oldest.block_time)
+        .is_some_and(|t| now.saturating_sub(t) >= RECENT_TRANSFER_WINDOW_SECS as i64)
+}
+
+/// The result, or None when the node answered null without an error
+fn parse_rpc_response<T: for<'de> Deserialize<'de>>(text: &str) -> Result<Option<T>, ProviderError> {
+    let rpc_response: RpcResponse<T> = serde_json::from_str(text)
+        .map_err(|e| {
+            eprintln!("JSON Parse Error: {}", e);
+            ProviderError::InvalidResponse
+        })?;
+
+    match (rpc_response.result, rpc_response.error) {
+        (Some(result), _) => Ok(Some(result)),
+        (None, Some(error)) => {
+            eprintln!("RPC Error Body: {}", error);
+            Err(ProviderError::InvalidResponse)
+        }
+        (None, None) => Ok(None),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcResponse<T> {
+    result: Option<T>,
+    error: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signature(block_time: Option<i64>) -> SignatureInfo {
+        SignatureInfo { signature: "sig".to_string(), block_time, err: None }
+    }
+
+    #[test]
+    fn test_full_page_must_reach_past_window() {
+        let now = 1_800_000_000;
+        let window = RECENT_TRANSFER_WINDOW_SECS as i64;
+        let page = |oldest: Option<i64>| {
+            let mut signatures: Vec<SignatureInfo> = (1..AUTHORITY_HISTORY_SIGNATURE_LIMIT)
+                .map(|_| signature(Some(now)))
+                .collect();
+            signatures.push(signature(oldest));
+            signatures
+        };
+
+        assert!(covers_recent_window(&[signature(Some(now))], now));
+        assert!(covers_recent_window(&page(Some(now - window)), now));
+        assert!(!covers_recent_window(&page(Some(now - 60)), now));
+        assert!(!covers_recent_window(&page(None), now));
+    }
+
+    #[test]
+    fn test_null_result_is_not_an_error() {
+        let missing: Option<serde_json::Value> = parse_rpc_response(r#"{"jsonrpc":"2.0","id":1,"result":null}"#).unwrap();
+        assert!(missing.is_none());
+
+        let failed = parse_rpc_response::<serde_json::Value>(r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32000}}"#);
+        assert!(matches!(failed, Err(ProviderError::InvalidResponse)));
+    }
+}
@@ -0,0 +1,153 @@
+// src/providers/trace.rs
+//
+// Per-analysis RPC trace. Providers are shared across requests, so the
+// collector is scoped to the analysis task instead of stored on the provider.
+// The same scope carries a memo of fetched accounts, so the facts of one
+// analysis share a read without a later analysis ever seeing it.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::sync::OnceCell;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RpcTraceEntry {
+    pub method: String,
+    pub params: serde_json::Value,
+    pub status: String,
+    pub duration_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<serde_json::Value>,
+}
+
+/// Provider calls made to produce one response, for attributing per-call
+/// RPC cost to analyses
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RpcCallCount {
+    pub total: u32,
+    /// Calls per provider method
+    #[serde(default)]
+    pub by_method: BTreeMap<String, u32>,
+}
+
+/// Result of one fetch, filled by whichever caller gets there first
+type MemoSlot = Arc<OnceCell<serde_json::Value>>;
+
+#[derive(Clone, Default)]
+pub struct TraceCollector {
+    entries: Arc<Mutex<Vec<RpcTraceEntry>>>,
+    include_bodies: bool,
+    memo: Arc<Mutex<HashMap<String, MemoSlot>>>,
+}
+
+impl TraceCollector {
+    pub fn new(include_bodies: bool) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(Vec::new())),
+            include_bodies,
+            memo: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn include_bodies(&self) -> bool {
+        self.include_bodies
+    }
+
+    pub fn record(&self, entry: RpcTraceEntry) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.push(entry);
+        }
+    }
+
+    pub fn entries(&self) -> Vec<RpcTraceEntry> {
+        self.entries.lock().map(|e| e.clone()).unwrap_or_default()
+    }
+
+    /// Calls recorded so far; counted whether or not the trace is returned
+    pub fn call_count(&self) -> RpcCallCount {
+        let mut count = RpcCallCount::default();
+        if let Ok(entries) = self.entries.lock() {
+            for entry in entries.iter() {
+                count.total += 1;
+                *count.by_method.entry(entry.method.clone()).or_default() += 1;
+            }
+        }
+        count
+    }
+}
+
+tokio::task_local! {
+    static ACTIVE_TRACE: TraceCollector;
+}
+
+/// Run `fut` with `collector` receiving every provider call made inside it
+pub async fn with_trace<F: Future>(collector: TraceCollector, fut: F) -> F::Output {
+    ACTIVE_TRACE.scope(collector, fut).await
+}
+
+/// Record a call against the active collector. No-op outside `with_trace`.
+/// `response` is only kept when the collector asked for full bodies.
+pub fn record_call(
+    method: &str,
+    params: &serde_json::Value,
+    status: &str,
+    started: Instant,
+    response: Option<serde_json::Value>,
+) {
+    let _ = ACTIVE_TRACE.try_with(|collector| {
+        collector.record(RpcTraceEntry {
+            method: method.to_string(),
+            params: params.clone(),
+            status: status.to_string(),
+            duration_ms: started.elapsed().as_millis() as u64,
+            response: if collector.include_bodies() { response } else { None },
+        });
+    });
+}
+
+/// Whether the active collector wants response bodies (avoids parsing them otherwise)
+pub fn wants_bodies() -> bool {
+    ACTIVE_TRACE.try_with(|c| c.include_bodies()).unwrap_or(false)
+}
+
+/// Run `fetch` at most once per `key` within the active scope; every other
+/// caller, concurrent or later, gets its result. Outside `with_trace`, and
+/// after a failed fetch, it simply runs.
+pub async fn memoized<F, Fut, E>(key: &str, fetch: F) -> Result<serde_json::Value, E>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<serde_json::Value, E>>,
+{
+    let slot = ACTIVE_TRACE.try_with(|collector| {
+        collector.memo.lock().ok().map(|mut memo| memo.entry(key.to_string()).or_default().clone())
+    }).ok().flatten();
+
+    match slot {
+        Some(slot) => slot.get_or_try_init(fetch).await.cloned(),
+        None => fetch().await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_record_outside_scope_is_noop() {
+        record_call("getAccountInfo", &json!([]), "ok", Instant::now(), None);
+
+        let collector = TraceCollector::new(false);
+        with_trace(collector.clone(), async {
+            record_call("getAccountInfo", &json!(["mint"]), "ok", Instant::now(), Some(json!({"a": 1})));
+        }).await;
+
+        let entries = collector.entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].method, "getAccountInfo");
+        // Bodies are dropped unless requested
+        assert!(entries[0].response.is_none());
+    }
+}
@@ -0,0 +1,110 @@
+// src/queue.rs
+//
+// Bounded work queue drained by a fixed pool of worker tasks. Request
+// handlers submit jobs and await the result, so the number of in-flight
+// analyses (and RPC load) is capped by the worker count rather than by
+// however many requests arrive at once.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::sync::{mpsc, oneshot, Mutex};
+
+pub const DEFAULT_QUEUE_CAPACITY: usize = 64;
+pub const DEFAULT_WORKERS: usize = 8;
+
+type Job = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Returned when every queue slot is taken
+#[derive(Debug, PartialEq)]
+pub struct QueueFull;
+
+pub struct WorkQueue {
+    sender: mpsc::Sender<Job>,
+    // Held so the channel stays open even with no workers
+    _receiver: Arc<Mutex<mpsc::Receiver<Job>>>,
+}
+
+impl WorkQueue {
+    /// Spawn `workers` tasks; must be called within a Tokio runtime.
+    /// `capacity` bounds jobs waiting for a worker (running jobs excluded).
+    pub fn new(capacity: usize, workers: usize) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>(capacity.max(1));
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        for _ in 0..workers {
+            let receiver = receiver.clone();
+            tokio::spawn(async move {
+                loop {
+                    // Lock only while taking a job, not while running it
+                    let job = receiver.lock().await.recv().await;
+                    match job {
+                        Some(job) => job.await,
+                        None => break,
+                    }
+                }
+            });
+        }
+
+        Self {
+            sender,
+            _receiver: receiver,
+        }
+    }
+
+    /// Queue `work` without waiting for a slot; the receiver yields its output
+    pub fn try_submit<F, T>(&self, work: F) -> Result<oneshot::Receiver<T>, QueueFull>
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let (reply, result) = oneshot::channel();
+        let job: Job = Box::pin(async move {
+            // The submitter may have gone away; the work is still done
+            let _ = reply.send(work.await);
+        });
+
+        self.sender.try_send(job).map_err(|_| QueueFull)?;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_jobs_run_and_return_results() {
+        let queue = WorkQueue::new(4, 2);
+
+        let a = queue.try_submit(async { 1 + 1 }).unwrap();
+        let b = queue.try_submit(async { "done" }).unwrap();
+
+        assert_eq!(a.await.unwrap(), 2);
+        assert_eq!(b.await.unwrap(), "done");
+    }
+
+    #[tokio::test]
+    async fn test_saturated_queue_rejects_overflow() {
+        let queue = WorkQueue::new(1, 1);
+
+        // Occupy the only worker until released
+        let (started_tx, started_rx) = oneshot::channel();
+        let (release_tx, release_rx) = oneshot::channel::<()>();
+        let running = queue.try_submit(async move {
+            let _ = started_tx.send(());
+            let _ = release_rx.await;
+        }).unwrap();
+        started_rx.await.unwrap();
+
+        // Fills the single waiting slot
+        let queued = queue.try_submit(async { "queued" }).unwrap();
+
+        // No room left: rejected immediately instead of queuing
+        assert_eq!(queue.try_submit(async { "overflow" }).err(), Some(QueueFull));
+
+        release_tx.send(()).unwrap();
+        running.await.unwrap();
+        assert_eq!(queued.await.unwrap(), "queued");
+    }
+}
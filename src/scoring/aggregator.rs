@@ -1,5 +1,16 @@
+use crate::checks::SupplyBasis;
 use crate::types::*;
+use super::profile::{PartialPenalty, ScoringProfile, WeightPolicy};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Lowest fairness score for each grade
+pub const STRONG_CUTOFF: u8 = 80;
+pub const MIXED_CUTOFF: u8 = 60;
+pub const FRAGILE_CUTOFF: u8 = 40;
+
+const CRITICAL_FAILURE_RULE: &str = "A failed critical check grades Compromised regardless of score";
+const UNKNOWN_CRITICAL_RULE: &str = "An unverified critical check caps a Strong grade at Mixed";
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ScoreComponent {
@@ -15,34 +26,118 @@ pub struct ScoreResult {
     pub fairness_score: Option<u8>,
     pub grade: Grade,
     pub components: Vec<ScoreComponent>,
-    pub weights_total: u8,
+    /// Sum of the weights of the known checks. Wider than a single weight:
+    /// a full run of checks can exceed 255.
+    pub weights_total: u32,
     pub notes: Vec<String>,
+    /// Why the grade was capped below what the score alone would give
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub override_reason: Option<String>,
+}
+
+/// The effective scoring policy behind a grade, echoed for reproducibility
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScoringConfig {
+    pub model: String,
+    /// Weight applied to each check id, after policy and confidence scaling
+    pub weights: BTreeMap<String, u8>,
+    pub grade_cutoffs: GradeCutoffs,
+    pub weight_policy: WeightPolicy,
+    pub confidence_weighting: bool,
+    pub min_coverage: f64,
+    pub strict_token_2022: bool,
+    pub concentration_basis: SupplyBasis,
+    pub partial_penalty: PartialPenalty,
+    /// Grade overrides that can apply on top of the score
+    pub override_rules: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GradeCutoffs {
+    pub strong: u8,
+    pub mixed: u8,
+    pub fragile: u8,
+}
+
+/// Describe how `aggregate_score_with_profile(checks, profile)` grades
+pub fn scoring_config(checks: &[CheckResult], profile: &ScoringProfile) -> ScoringConfig {
+    let mut override_rules = vec![
+        CRITICAL_FAILURE_RULE.to_string(),
+        UNKNOWN_CRITICAL_RULE.to_string(),
+    ];
+    if profile.min_coverage > 0.0 {
+        override_rules.push(format!(
+            "Below {:.0}% known check weight the grade is Insufficient",
+            profile.min_coverage
+        ));
+    }
+    match profile.partial_penalty {
+        PartialPenalty::None => {}
+        PartialPenalty::Points(points) => override_rules.push(format!(
+            "A partial analysis loses {} points before grading",
+            points
+        )),
+        PartialPenalty::DemoteGrade => {
+            override_rules.push("A partial analysis is demoted one grade, no lower than Fragile".to_string())
+        }
+    }
+
+    ScoringConfig {
+        model: "weighted_sum_v1".to_string(),
+        weights: checks.iter().map(|c| (c.id.clone(), profile.weight_for(c))).collect(),
+        grade_cutoffs: GradeCutoffs {
+            strong: STRONG_CUTOFF,
+            mixed: MIXED_CUTOFF,
+            fragile: FRAGILE_CUTOFF,
+        },
+        weight_policy: profile.weight_policy.clone(),
+        confidence_weighting: profile.confidence_weighting,
+        min_coverage: profile.min_coverage,
+        strict_token_2022: profile.strict_token_2022,
+        concentration_basis: profile.concentration_basis,
+        partial_penalty: profile.partial_penalty,
+        override_rules,
+    }
 }
 
 pub fn aggregate_score(checks: &[CheckResult]) -> ScoreResult {
-    let mut weights_total: u8 = 0;
+    aggregate_score_with_profile(checks, &ScoringProfile::default())
+}
+
+pub fn aggregate_score_with_profile(checks: &[CheckResult], profile: &ScoringProfile) -> ScoreResult {
+    let mut weights_total: u32 = 0;
+    let mut intended_weight: u32 = 0;
     let mut points_total: f64 = 0.0;
     let mut components = Vec::new();
     let mut has_critical_failure = false;
+    let mut unknown_critical: Vec<String> = Vec::new();
 
     for check in checks {
+        let weight = profile.weight_for(check);
+        // A check that does not apply to this token (or request) was never expected
+        let applicable = check.is_expected();
+        if applicable {
+            intended_weight += weight as u32;
+        }
+
         let component = match check.score_component {
             Some(score) => {
-                weights_total += check.weight;
-                let weighted_points = (check.weight as f64) * (score as f64 / 100.0);
+                weights_total += weight as u32;
+                let weighted_points = (weight as f64) * (score as f64 / 100.0);
+                // Exact points feed the score; rounding is for display
                 points_total += weighted_points;
 
                 ScoreComponent {
                     id: check.id.clone(),
-                    weight: check.weight,
+                    weight,
                     component_score: Some(score),
-                    weighted_points: Some(weighted_points),
+                    weighted_points: Some(round_to(weighted_points, profile.points_precision)),
                 }
             }
             None => {
                 ScoreComponent {
                     id: check.id.clone(),
-                    weight: check.weight,
+                    weight,
                     component_score: None,
                     weighted_points: None,
                 }
@@ -54,6 +149,9 @@ pub fn aggregate_score(checks: &[CheckResult]) -> ScoreResult {
         if matches!(check.severity, Severity::Critical) && matches!(check.status, CheckStatus::Fail) {
             has_critical_failure = true;
         }
+        if matches!(check.severity, Severity::Critical) && matches!(check.status, CheckStatus::Unknown) && applicable {
+            unknown_critical.push(check.id.clone());
+        }
     }
 
     let fairness_score = if weights_total == 0 {
@@ -62,32 +160,114 @@ pub fn aggregate_score(checks: &[CheckResult]) -> ScoreResult {
         Some(((points_total / weights_total as f64) * 100.0).round() as u8)
     };
 
-    let grade = if has_critical_failure {
+    let mut notes = vec![
+        "Composite score summarizes structure; individual checks are the source of truth.".to_string(),
+    ];
+
+    // Share of the intended weight backed by known checks
+    let coverage = if intended_weight == 0 {
+        0.0
+    } else {
+        weights_total as f64 / intended_weight as f64 * 100.0
+    };
+    let insufficient_coverage = coverage < profile.min_coverage;
+
+    let mut grade = if has_critical_failure {
         Grade::Compromised
+    } else if insufficient_coverage {
+        notes.push(format!(
+            "Only {:.0}% of check weight is known; at least {:.0}% is required to grade.",
+            coverage, profile.min_coverage
+        ));
+        Grade::Insufficient
     } else if let Some(score) = fairness_score {
         grade_from_score(score)
     } else {
         Grade::Compromised
     };
 
+    // An unverified critical dimension caps the grade, however well the rest scores
+    let mut override_reason = None;
+    if !unknown_critical.is_empty() && matches!(grade, Grade::Strong) {
+        grade = Grade::Mixed;
+        override_reason = Some(format!(
+            "Grade capped at Mixed: critical check(s) could not be verified ({}).",
+            unknown_critical.join(", ")
+        ));
+    }
+
     ScoreResult {
         model: "weighted_sum_v1".to_string(),
         fairness_score,
         grade,
         components,
         weights_total,
-        notes: vec![
-            "Composite score summarizes structure; individual checks are the source of truth.".to_string(),
-        ],
+        notes,
+        override_reason,
     }
 }
 
+/// Downrank the score of an analysis whose status is `Partial`, per
+/// `penalty`. `Compromised` and `Insufficient` grades are left as they are;
+/// a penalty never raises a grade, nor lowers one past `Fragile`:
+/// missing data is not evidence of compromise.
+pub fn apply_partial_penalty(score: &mut ScoreResult, penalty: PartialPenalty) {
+    if matches!(score.grade, Grade::Compromised | Grade::Insufficient) {
+        return;
+    }
+
+    let reason = match penalty {
+        PartialPenalty::None => return,
+        PartialPenalty::Points(points) => {
+            let Some(fairness_score) = score.fairness_score else {
+                return;
+            };
+            let penalized = fairness_score.saturating_sub(points);
+            score.fairness_score = Some(penalized);
+            let regraded = match grade_from_score(penalized) {
+                Grade::Compromised => Grade::Fragile,
+                grade => grade,
+            };
+            score.grade = worse_grade(score.grade.clone(), regraded);
+            format!("Partial analysis: {} points deducted before grading.", points)
+        }
+        PartialPenalty::DemoteGrade => {
+            score.grade = match score.grade {
+                Grade::Strong => Grade::Mixed,
+                _ => Grade::Fragile,
+            };
+            "Partial analysis: grade demoted one step.".to_string()
+        }
+    };
+
+    score.override_reason = Some(match score.override_reason.take() {
+        Some(existing) => format!("{} {}", existing, reason),
+        None => reason,
+    });
+}
+
+/// The lower of two grades from Strong, Mixed, Fragile and Compromised
+fn worse_grade(a: Grade, b: Grade) -> Grade {
+    let rank = |grade: &Grade| match grade {
+        Grade::Strong => 3,
+        Grade::Mixed => 2,
+        Grade::Fragile => 1,
+        _ => 0,
+    };
+    if rank(&b) < rank(&a) { b } else { a }
+}
+
+fn round_to(value: f64, places: u8) -> f64 {
+    let scale = 10_f64.powi(places as i32);
+    (value * scale).round() / scale
+}
+
 fn grade_from_score(score: u8) -> Grade {
-    if score >= 80 {
+    if score >= STRONG_CUTOFF {
         Grade::Strong
-    } else if score >= 60 {
+    } else if score >= MIXED_CUTOFF {
         Grade::Mixed
-    } else if score >= 40 {
+    } else if score >= FRAGILE_CUTOFF {
         Grade::Fragile
     } else {
         Grade::Compromised
@@ -97,6 +277,7 @@ fn grade_from_score(score: u8) -> Grade {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scoring::{SeverityWeights, WeightPolicy};
     use serde_json::json;
 
     fn make_check(
@@ -116,6 +297,9 @@ mod tests {
             evidence: json!({}),
             weight,
             score_component,
+            confidence: 1.0,
+            unknown_reason: None,
+            learn_more_url: None,
         }
     }
 
@@ -184,6 +368,20 @@ mod tests {
         assert!(matches!(result.grade, Grade::Compromised));
     }
 
+    #[test]
+    fn test_partial_penalty_stops_at_fragile() {
+        let fragile = || aggregate_score(&[make_check("check1", CheckStatus::Pass, Severity::Medium, 50, Some(50))]);
+
+        let mut demoted = fragile();
+        apply_partial_penalty(&mut demoted, PartialPenalty::DemoteGrade);
+        assert!(matches!(demoted.grade, Grade::Fragile));
+
+        let mut points = fragile();
+        apply_partial_penalty(&mut points, PartialPenalty::Points(40));
+        assert_eq!(points.fairness_score, Some(10));
+        assert!(matches!(points.grade, Grade::Fragile));
+    }
+
     #[test]
     fn test_grade_thresholds() {
         let checks_strong = vec![
@@ -226,4 +424,161 @@ mod tests {
         assert_eq!(result.fairness_score, Some(95));
         assert!(matches!(result.grade, Grade::Strong));
     }
+
+    #[test]
+    fn test_min_coverage_insufficient_when_only_age_known() {
+        let profile = ScoringProfile { min_coverage: 50.0, ..Default::default() };
+        let checks = vec![
+            make_check("mint_authority_disabled", CheckStatus::Unknown, Severity::Critical, 25, None),
+            make_check("freeze_authority_disabled", CheckStatus::Unknown, Severity::High, 20, None),
+            make_check("holder_concentration", CheckStatus::Unknown, Severity::Medium, 20, None),
+            make_check("token_age", CheckStatus::Pass, Severity::Low, 10, Some(100)),
+        ];
+
+        let result = aggregate_score_with_profile(&checks, &profile);
+
+        // 10 / 75 = 13% coverage
+        assert_eq!(result.fairness_score, Some(100));
+        assert!(matches!(result.grade, Grade::Insufficient));
+
+        // Default profile grades it, but the unknown critical check caps it
+        let result = aggregate_score(&checks);
+        assert!(matches!(result.grade, Grade::Mixed));
+    }
+
+    #[test]
+    fn test_min_coverage_graded_when_authorities_and_age_known() {
+        let profile = ScoringProfile { min_coverage: 50.0, ..Default::default() };
+        let checks = vec![
+            make_check("mint_authority_disabled", CheckStatus::Pass, Severity::Critical, 25, Some(100)),
+            make_check("freeze_authority_disabled", CheckStatus::Pass, Severity::High, 20, Some(100)),
+            make_check("holder_concentration", CheckStatus::Unknown, Severity::Medium, 20, None),
+            make_check("token_age", CheckStatus::Pass, Severity::Low, 10, Some(100)),
+        ];
+
+        let result = aggregate_score_with_profile(&checks, &profile);
+
+        // 55 / 75 = 73% coverage
+        assert!(matches!(result.grade, Grade::Strong));
+    }
+
+    #[test]
+    fn test_not_applicable_excluded_from_coverage() {
+        let profile = ScoringProfile { min_coverage: 50.0, ..Default::default() };
+        let mut not_applicable = make_check("authority_is_program", CheckStatus::Unknown, Severity::Medium, 40, None);
+        not_applicable.unknown_reason = Some(UnknownReason::NotApplicable);
+        let checks = vec![
+            make_check("mint_authority_disabled", CheckStatus::Pass, Severity::Critical, 25, Some(100)),
+            make_check("holder_concentration", CheckStatus::Unknown, Severity::Medium, 20, None),
+            not_applicable,
+        ];
+
+        let result = aggregate_score_with_profile(&checks, &profile);
+
+        // 25 / 45 known; counting the inapplicable 40 it would be 25 / 85
+        assert!(matches!(result.grade, Grade::Strong));
+    }
+
+    #[test]
+    fn test_unknown_critical_caps_grade_at_mixed() {
+        let checks = vec![
+            make_check("mint_authority_disabled", CheckStatus::Unknown, Severity::Critical, 25, None),
+            make_check("token_age", CheckStatus::Pass, Severity::Low, 10, Some(100)),
+            make_check("standard_sanity", CheckStatus::Pass, Severity::Medium, 10, Some(100)),
+        ];
+
+        let result = aggregate_score(&checks);
+
+        assert_eq!(result.fairness_score, Some(100));
+        assert!(matches!(result.grade, Grade::Mixed));
+        assert!(result.override_reason.unwrap().contains("mint_authority_disabled"));
+    }
+
+    #[test]
+    fn test_known_critical_not_capped() {
+        let checks = vec![
+            make_check("mint_authority_disabled", CheckStatus::Pass, Severity::Critical, 25, Some(100)),
+            make_check("token_age", CheckStatus::Pass, Severity::Low, 10, Some(100)),
+            make_check("standard_sanity", CheckStatus::Pass, Severity::Medium, 10, Some(100)),
+        ];
+
+        let result = aggregate_score(&checks);
+
+        assert!(matches!(result.grade, Grade::Strong));
+        assert_eq!(result.override_reason, None);
+    }
+
+    #[test]
+    fn test_weight_policy_by_severity_overrides_check_weights() {
+        let checks = vec![
+            make_check("check1", CheckStatus::Pass, Severity::Critical, 30, Some(100)),
+            make_check("check2", CheckStatus::Pass, Severity::Medium, 20, Some(100)),
+            make_check("check3", CheckStatus::Fail, Severity::Low, 10, Some(0)),
+        ];
+
+        let per_check = aggregate_score(&checks);
+        assert_eq!(per_check.weights_total, 60);
+
+        let profile = ScoringProfile {
+            weight_policy: WeightPolicy::BySeverity(SeverityWeights::default()),
+            ..Default::default()
+        };
+        let by_severity = aggregate_score_with_profile(&checks, &profile);
+
+        assert_eq!(by_severity.weights_total, 25 + 15 + 10);
+        assert_eq!(by_severity.components[0].weight, 25);
+        assert_eq!(by_severity.fairness_score, Some(80));
+    }
+
+    #[test]
+    fn test_confidence_weighting_scales_weights() {
+        let mut estimate = make_check("holders", CheckStatus::Fail, Severity::High, 20, Some(0));
+        estimate.confidence = 0.5;
+        let checks = vec![
+            make_check("authority", CheckStatus::Pass, Severity::Critical, 20, Some(100)),
+            estimate,
+        ];
+
+        assert_eq!(aggregate_score(&checks).fairness_score, Some(50));
+
+        let profile = ScoringProfile { confidence_weighting: true, ..Default::default() };
+        let weighted = aggregate_score_with_profile(&checks, &profile);
+
+        assert_eq!(weighted.components[1].weight, 10);
+        assert_eq!(weighted.weights_total, 30);
+        assert_eq!(weighted.fairness_score, Some(67));
+    }
+
+    #[test]
+    fn test_weighted_points_rounded_but_score_exact() {
+        // 5 * 0.33 and 5 * 0.17 are 1.6500000000000001 and 0.8500000000000001
+        let checks = vec![
+            make_check("check1", CheckStatus::Pass, Severity::Low, 5, Some(33)),
+            make_check("check2", CheckStatus::Pass, Severity::Low, 5, Some(17)),
+        ];
+
+        let result = aggregate_score(&checks);
+        let serialized = serde_json::to_value(&result).unwrap();
+        assert_eq!(serialized["components"][0]["weighted_points"].to_string(), "1.65");
+        assert_eq!(serialized["components"][1]["weighted_points"].to_string(), "0.85");
+        assert_eq!(result.fairness_score, Some(25));
+
+        // Whole points would sum to 3 of 10 (30); the score still uses 2.5
+        let whole = ScoringProfile { points_precision: 0, ..Default::default() };
+        let result = aggregate_score_with_profile(&checks, &whole);
+        assert_eq!(result.components[0].weighted_points, Some(2.0));
+        assert_eq!(result.components[1].weighted_points, Some(1.0));
+        assert_eq!(result.fairness_score, Some(25));
+    }
+
+    #[test]
+    fn test_weights_total_beyond_u8() {
+        let checks: Vec<CheckResult> = (0..12)
+            .map(|i| make_check(&format!("check{}", i), CheckStatus::Pass, Severity::High, 25, Some(100)))
+            .collect();
+
+        let result = aggregate_score(&checks);
+        assert_eq!(result.weights_total, 300);
+        assert_eq!(result.fairness_score, Some(100));
+    }
 }
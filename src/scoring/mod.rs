@@ -1,5 +1,13 @@
 // src/scoring/mod.rs
 
 pub mod aggregator;
+pub mod profile;
 
-pub use aggregator::{aggregate_score, ScoreResult, ScoreComponent};
+pub use aggregator::{
+    aggregate_score, aggregate_score_with_profile, apply_partial_penalty, scoring_config,
+    GradeCutoffs, ScoreComponent, ScoreResult, ScoringConfig, FRAGILE_CUTOFF,
+};
+pub use profile::{
+    PartialPenalty, ProfileWarning, ScoringProfile, SeverityWeights, WeightPolicy,
+    DEFAULT_POINTS_PRECISION, MAX_CHECK_WEIGHT, MAX_WEIGHTS_TOTAL, MIN_CRITICAL_WEIGHT,
+};
@@ -0,0 +1,310 @@
+use crate::checks::SupplyBasis;
+use crate::types::{CheckResult, Severity};
+use serde::{Deserialize, Serialize};
+
+/// Lowest weight a `Critical` severity may carry before `validate` flags it
+pub const MIN_CRITICAL_WEIGHT: u8 = 10;
+/// Largest weight a single check may carry, under any policy
+pub const MAX_CHECK_WEIGHT: u8 = 100;
+/// Largest sum of check weights one grade may be computed from
+pub const MAX_WEIGHTS_TOTAL: u32 = 10_000;
+/// Decimal places `ScoreComponent.weighted_points` is reported with
+pub const DEFAULT_POINTS_PRECISION: u8 = 2;
+
+/// Tunable scoring policy. `ScoringProfile::default()` reproduces the
+/// built-in weighted_sum_v1 behavior.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScoringProfile {
+    /// Minimum share (0-100) of the run checks' total weight that must be
+    /// known before a grade is produced. Below it the grade is `Insufficient`.
+    /// A known critical failure still grades `Compromised`.
+    #[serde(default)]
+    pub min_coverage: f64,
+    /// Treat Token-2022 mints as a standard-sanity warning (its extensions
+    /// widen what an authority can do) instead of a clean pass.
+    #[serde(default)]
+    pub strict_token_2022: bool,
+    /// Supply holder-concentration shares are scored against
+    #[serde(default)]
+    pub concentration_basis: SupplyBasis,
+    /// Where each check's weight comes from
+    #[serde(default)]
+    pub weight_policy: WeightPolicy,
+    /// Scale each check's weight by its `confidence`, so estimates count
+    /// for less than parsed on-chain state
+    #[serde(default)]
+    pub confidence_weighting: bool,
+    /// Reject the profile when `validate` finds inconsistencies instead of
+    /// only warning
+    #[serde(default)]
+    pub strict_validation: bool,
+    /// Grade adjustment for analyses whose status is `Partial`. Off by
+    /// default: a partial analysis is graded on its known checks alone.
+    #[serde(default)]
+    pub partial_penalty: PartialPenalty,
+    /// Decimal places each component's `weighted_points` is rounded to.
+    /// Display only: the fairness score is computed from unrounded points.
+    #[serde(default = "default_points_precision")]
+    pub points_precision: u8,
+    /// Severity `has_identity` fails at for a token with neither name nor
+    /// symbol; `Low` by default, `Medium` at most
+    #[serde(default = "default_nameless_severity")]
+    pub nameless_severity: Severity,
+}
+
+fn default_points_precision() -> u8 {
+    DEFAULT_POINTS_PRECISION
+}
+
+fn default_nameless_severity() -> Severity {
+    Severity::Low
+}
+
+/// How a `Partial` analysis is downranked
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartialPenalty {
+    /// Graded like a complete analysis
+    #[default]
+    None,
+    /// Subtract this many points from the fairness score, then regrade
+    /// (no lower than Fragile)
+    Points(u8),
+    /// Drop the grade one step (Strong to Mixed, Mixed to Fragile), no
+    /// lower than Fragile
+    DemoteGrade,
+}
+
+/// A gross mismatch between weights and severities
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ProfileWarning {
+    /// Stable token: critical_underweighted, severity_inverted
+    pub code: String,
+    pub message: String,
+}
+
+/// How check weights are assigned when aggregating
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeightPolicy {
+    /// Each check's own hardcoded weight
+    #[default]
+    PerCheck,
+    /// One weight per severity, applied to every check
+    BySeverity(SeverityWeights),
+}
+
+impl WeightPolicy {
+    pub fn weight_for(&self, check: &CheckResult) -> u8 {
+        match self {
+            WeightPolicy::PerCheck => check.weight,
+            WeightPolicy::BySeverity(weights) => weights.weight_for(&check.severity),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SeverityWeights {
+    pub critical: u8,
+    pub high: u8,
+    pub medium: u8,
+    pub low: u8,
+}
+
+impl SeverityWeights {
+    pub fn weight_for(&self, severity: &Severity) -> u8 {
+        match severity {
+            Severity::Critical => self.critical,
+            Severity::High => self.high,
+            Severity::Medium => self.medium,
+            Severity::Low => self.low,
+        }
+    }
+}
+
+impl Default for SeverityWeights {
+    fn default() -> Self {
+        Self {
+            critical: 25,
+            high: 20,
+            medium: 15,
+            low: 10,
+        }
+    }
+}
+
+impl ScoringProfile {
+    /// Weight `check` carries under this profile
+    pub fn weight_for(&self, check: &CheckResult) -> u8 {
+        let weight = self.weight_policy.weight_for(check);
+        if self.confidence_weighting {
+            (weight as f64 * check.confidence.clamp(0.0, 1.0)).round() as u8
+        } else {
+            weight
+        }
+    }
+
+    /// Check that weights follow severities: a `Critical` weight of at
+    /// least `MIN_CRITICAL_WEIGHT`, and no severity outweighing a more
+    /// severe one. Returns the warnings, or an error listing them under
+    /// `strict_validation`. Severity weights above `MAX_CHECK_WEIGHT` are
+    /// always an error. Per-check weights come with the checks; see
+    /// `validate_checks`.
+    pub fn validate(&self) -> Result<Vec<ProfileWarning>, String> {
+        let mut warnings = Vec::new();
+        // A missing name is a hygiene signal, never grounds for a cap
+        if matches!(self.nameless_severity, Severity::Critical | Severity::High) {
+            return Err(format!(
+                "nameless_severity must be Low or Medium, not {:?}", self.nameless_severity
+            ));
+        }
+        if let WeightPolicy::BySeverity(weights) = &self.weight_policy {
+            let heaviest = weights.critical.max(weights.high).max(weights.medium).max(weights.low);
+            if heaviest > MAX_CHECK_WEIGHT {
+                return Err(format!(
+                    "Severity weight {} exceeds the maximum of {}", heaviest, MAX_CHECK_WEIGHT
+                ));
+            }
+            if weights.critical < MIN_CRITICAL_WEIGHT {
+                warnings.push(ProfileWarning {
+                    code: "critical_underweighted".to_string(),
+                    message: format!(
+                        "critical weight {} is below the minimum of {}",
+                        weights.critical, MIN_CRITICAL_WEIGHT
+                    ),
+                });
+            }
+            let ladder = [
+                ("critical", weights.critical),
+                ("high", weights.high),
+                ("medium", weights.medium),
+                ("low", weights.low),
+            ];
+            for (i, (lower, lower_weight)) in ladder.iter().enumerate() {
+                if let Some((higher, higher_weight)) = ladder[..i].iter().find(|(_, w)| w < lower_weight) {
+                    warnings.push(ProfileWarning {
+                        code: "severity_inverted".to_string(),
+                        message: format!(
+                            "{} weight {} exceeds {} weight {}",
+                            lower, lower_weight, higher, higher_weight
+                        ),
+                    });
+                }
+            }
+        }
+
+        if self.strict_validation && !warnings.is_empty() {
+            let messages: Vec<&str> = warnings.iter().map(|w| w.message.as_str()).collect();
+            return Err(format!("Inconsistent scoring profile: {}", messages.join("; ")));
+        }
+        Ok(warnings)
+    }
+
+    /// Reject checks whose weights under this profile exceed
+    /// `MAX_CHECK_WEIGHT` each or `MAX_WEIGHTS_TOTAL` together. Needed
+    /// wherever checks (and so their weights) come from outside the crate:
+    /// rescore requests, registered custom checks.
+    pub fn validate_checks(&self, checks: &[CheckResult]) -> Result<(), String> {
+        let mut total: u32 = 0;
+        for check in checks {
+            let weight = self.weight_for(check);
+            if weight > MAX_CHECK_WEIGHT {
+                return Err(format!(
+                    "Check '{}' weight {} exceeds the maximum of {}", check.id, weight, MAX_CHECK_WEIGHT
+                ));
+            }
+            total = total.saturating_add(weight as u32);
+        }
+        if total > MAX_WEIGHTS_TOTAL {
+            return Err(format!(
+                "Total check weight {} exceeds the maximum of {}", total, MAX_WEIGHTS_TOTAL
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for ScoringProfile {
+    fn default() -> Self {
+        Self {
+            min_coverage: 0.0,
+            strict_token_2022: false,
+            concentration_basis: SupplyBasis::Total,
+            weight_policy: WeightPolicy::PerCheck,
+            confidence_weighting: false,
+            strict_validation: false,
+            partial_penalty: PartialPenalty::None,
+            points_precision: DEFAULT_POINTS_PRECISION,
+            nameless_severity: default_nameless_severity(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn by_severity(critical: u8, high: u8, medium: u8, low: u8) -> ScoringProfile {
+        ScoringProfile {
+            weight_policy: WeightPolicy::BySeverity(SeverityWeights { critical, high, medium, low }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_balanced_profile_is_valid() {
+        assert_eq!(ScoringProfile::default().validate(), Ok(vec![]));
+        assert_eq!(by_severity(25, 20, 15, 10).validate(), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_misconfigured_profile_warns() {
+        let profile = by_severity(2, 20, 15, 30);
+
+        let warnings = profile.validate().unwrap();
+        let codes: Vec<&str> = warnings.iter().map(|w| w.code.as_str()).collect();
+        assert_eq!(codes, vec!["critical_underweighted", "severity_inverted", "severity_inverted", "severity_inverted"]);
+        assert_eq!(warnings[1].message, "high weight 20 exceeds critical weight 2");
+    }
+
+    #[test]
+    fn test_oversized_weights_rejected() {
+        assert!(by_severity(255, 20, 15, 10).validate().unwrap_err().contains("exceeds the maximum"));
+
+        let check = |weight| CheckResult {
+            id: "heavy".to_string(),
+            label: "heavy".to_string(),
+            category: "test".to_string(),
+            status: crate::types::CheckStatus::Pass,
+            severity: Severity::Low,
+            value: serde_json::json!(true),
+            evidence: serde_json::json!({}),
+            weight,
+            score_component: Some(100),
+            confidence: 1.0,
+            unknown_reason: None,
+            learn_more_url: None,
+        };
+        let profile = ScoringProfile::default();
+        assert_eq!(profile.validate_checks(&[check(100), check(100)]), Ok(()));
+        assert!(profile.validate_checks(&[check(200)]).unwrap_err().contains("'heavy' weight 200"));
+        let many = vec![check(100); 101];
+        assert!(profile.validate_checks(&many).unwrap_err().contains("Total check weight 10100"));
+    }
+
+    #[test]
+    fn test_nameless_severity_capped_at_medium() {
+        let profile = |nameless_severity| ScoringProfile { nameless_severity, ..Default::default() };
+        assert!(profile(Severity::Medium).validate().is_ok());
+        let error = profile(Severity::High).validate().unwrap_err();
+        assert!(error.contains("nameless_severity must be Low or Medium"));
+    }
+
+    #[test]
+    fn test_strict_profile_rejected() {
+        let profile = ScoringProfile { strict_validation: true, ..by_severity(2, 20, 15, 10) };
+
+        let error = profile.validate().unwrap_err();
+        assert!(error.contains("critical weight 2 is below the minimum of 10"));
+    }
+}
@@ -1,67 +1,969 @@
 use axum::{
-    extract::State,
-    http::StatusCode,
-    routing::post,
+    async_trait,
+    extract::{rejection::JsonRejection, FromRequest, Path, Query, Request, State},
+    http::{header, HeaderMap, StatusCode},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{get, post},
     Json, Router,
 };
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use tower_http::cors::{CorsLayer, Any};
+use tower_http::limit::RequestBodyLimitLayer;
+use futures_util::stream::{self, StreamExt};
+use std::collections::{HashMap, HashSet};
+use std::env;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
 
-use crate::api::types::{AnalyzeRequest, AnalyzeResponse};
-use crate::api::cached_analyze::analyze_with_cache;
+use crate::api::types::{AnalysisStatus, AnalyzeOptions, AnalyzeRequest, AnalyzeResponse, RescoreRequest, RescoreResponse, Verdict};
+use crate::api::cached_analyze::{analyze_with_shared_cache, ANALYZE_MANY_CONCURRENCY};
+use crate::api::coalesce::{RequestCoalescer, DEFAULT_COALESCE_WINDOW};
+use crate::api::config::AnalyzeConfig;
+use crate::api::chain::{normalize_address, normalize_chain, validate_address};
+use crate::api::attestation::attach_attestation;
+use crate::api::fields::{parse_fields, sparse_view};
+use crate::api::wire::WireFormat;
+use crate::providers::{ProviderExecutor, TokenProvider};
 use crate::providers::helius::HeliusProvider;
 use crate::providers::alchemy::AlchemyProvider;
-use crate::cache::SimpleCache;
+use crate::providers::quicknode::{QuickNodeMethods, QuickNodeProvider};
+use crate::scoring::{aggregate_score_with_profile, ScoringProfile};
+use crate::types::{CheckResult, CheckStatus, Grade};
+use crate::cache::{AnalysisHistory, HistoryPoint, SimpleCache};
+use crate::cache::simple_cache::is_from_cache;
+use crate::queue::{WorkQueue, DEFAULT_QUEUE_CAPACITY, DEFAULT_WORKERS};
+
+pub const DEFAULT_PROVIDER_CONCURRENCY: usize = 16;
+
+/// Largest accepted request body; analyze requests are a few hundred bytes
+pub const DEFAULT_MAX_BODY_BYTES: usize = 256 * 1024;
+
+/// Most addresses one `GET /api/v1/analyze/:chain?addresses=..` may name
+pub const MAX_MULTI_ADDRESSES: usize = 10;
+
+/// Canonical chains served, each backed by one configured provider
+pub const SERVED_CHAINS: [&str; 4] = ["solana", "ethereum", "base", "evm"];
 
 pub struct AppState {
     pub cache: Mutex<SimpleCache>,
+    pub history: Mutex<AnalysisHistory>,
+    /// Provider per canonical chain, built once at startup
+    pub providers: HashMap<String, Arc<dyn TokenProvider>>,
+    /// Analyses run on a fixed worker pool; a full queue answers 503
+    pub queue: WorkQueue,
+    /// Analysis configuration, including the shared provider fetch executor
+    pub analyze_config: AnalyzeConfig,
+    /// Shared secret for response attestations; `attest` is rejected without it
+    pub attestation_secret: Option<Vec<u8>>,
+    /// Canonical chain for requests that omit `chain`
+    pub default_chain: Option<String>,
+    /// Who may send `rpc_url_override`, and to where; overrides are
+    /// rejected when None
+    pub rpc_override: Option<RpcOverridePolicy>,
+}
+
+/// Gate for per-request RPC endpoints. The server makes requests to the
+/// given URL, so callers must authenticate and the endpoint's origin must
+/// be allowlisted (SSRF).
+#[derive(Clone, Debug)]
+pub struct RpcOverridePolicy {
+    /// Bearer token callers present in `Authorization`
+    pub token: String,
+    /// Permitted endpoint origins, e.g. "https://rpc.example.com"
+    pub allowed_origins: HashSet<String>,
+}
+
+impl RpcOverridePolicy {
+    pub fn new(token: String, origins: &[String]) -> Self {
+        Self {
+            token,
+            allowed_origins: origins.iter().filter_map(|o| endpoint_origin(o)).collect(),
+        }
+    }
+
+    /// Provider for `chain` against `url`, if the bearer token matches and
+    /// the URL's origin is allowlisted
+    pub fn provider_for(
+        &self,
+        chain: &str,
+        url: &str,
+        bearer: Option<&str>,
+    ) -> Result<Arc<dyn TokenProvider>, ApiError> {
+        if !bearer.is_some_and(|bearer| constant_time_eq(bearer.as_bytes(), self.token.as_bytes())) {
+            return Err(ApiError::unauthorized(
+                "rpc_override_unauthorized",
+                "rpc_url_override requires a valid bearer token".to_string(),
+            ));
+        }
+        // The URL may embed an API key; only its origin is echoed back
+        let origin = endpoint_origin(url).ok_or_else(|| {
+            ApiError::bad_request("invalid_rpc_url", "rpc_url_override must be an http(s) URL".to_string())
+        })?;
+        if !self.allowed_origins.contains(&origin) {
+            return Err(ApiError::bad_request(
+                "rpc_host_not_allowed",
+                format!("RPC endpoint '{}' is not in the override allowlist", origin),
+            ));
+        }
+        Ok(match chain {
+            "solana" => Arc::new(HeliusProvider::with_rpc_url(url)),
+            _ => Arc::new(AlchemyProvider::with_rpc_url(url).with_chain(chain)),
+        })
+    }
+}
+
+/// Byte comparison whose timing does not depend on where the inputs differ,
+/// so the override token cannot be guessed a byte at a time
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// `scheme://host[:port]` of an http(s) URL
+fn endpoint_origin(url: &str) -> Option<String> {
+    let url = reqwest::Url::parse(url.trim()).ok()?;
+    matches!(url.scheme(), "http" | "https").then(|| url.origin().ascii_serialization())
+}
+
+/// Process-level settings, read once at startup
+#[derive(Clone, Debug)]
+pub struct ServerConfig {
+    pub port: u16,
     pub helius_api_key: String,
     pub alchemy_api_key: String,
+    /// QuickNode Solana endpoint (`QUICKNODE_RPC_URL`), required for
+    /// `SOLANA_PROVIDER=quicknode`
+    pub quicknode_rpc_url: Option<String>,
+    /// Holder method for QuickNode (`QUICKNODE_HOLDERS_METHOD`, default
+    /// "getTokenLargestAccounts"; "none" when the endpoint has none)
+    pub quicknode_methods: QuickNodeMethods,
+    pub attestation_secret: Option<Vec<u8>>,
+    /// Provider name for Solana (`SOLANA_PROVIDER`, default "helius")
+    pub solana_provider: String,
+    /// Provider name for EVM chains (`EVM_PROVIDER`, default "alchemy")
+    pub evm_provider: String,
+    /// Analyses waiting for a worker before new ones are rejected (`QUEUE_CAPACITY`)
+    pub queue_capacity: usize,
+    /// Concurrent analyses (`ANALYSIS_WORKERS`)
+    pub workers: usize,
+    /// Concurrent provider fetches across all analyses (`PROVIDER_CONCURRENCY`)
+    pub provider_concurrency: usize,
+    /// Keccak-256 hashes of known-malicious EVM bytecode (`KNOWN_MALICIOUS_BYTECODE`, comma-separated)
+    pub known_malicious_bytecode: HashSet<String>,
+    /// Request body cap in bytes (`MAX_BODY_BYTES`); larger bodies get 413
+    pub max_body_bytes: usize,
+    /// Chain for requests without `chain` (`DEFAULT_CHAIN`); such requests
+    /// are rejected when unset
+    pub default_chain: Option<String>,
+    /// Identical requests within this many ms share one analysis
+    /// (`COALESCE_WINDOW_MS`); 0 disables coalescing
+    pub coalesce_window_ms: u64,
+    /// Bearer token enabling per-request `rpc_url_override`
+    /// (`RPC_OVERRIDE_TOKEN`); overrides are rejected when unset
+    pub rpc_override_token: Option<String>,
+    /// Endpoint origins overrides may target (`RPC_OVERRIDE_ORIGINS`,
+    /// comma-separated, e.g. "https://rpc.example.com")
+    pub rpc_override_origins: Vec<String>,
+    /// Scoring profile for every analysis (`SCORING_PROFILE`, JSON; the
+    /// default profile when unset)
+    pub scoring: ScoringProfile,
+}
+
+impl ServerConfig {
+    pub fn from_env() -> Self {
+        let helius_api_key = env::var("HELIUS_API_KEY")
+            .expect("HELIUS_API_KEY environment variable must be set");
+
+        let alchemy_api_key = env::var("ALCHEMY_API_KEY")
+            .expect("ALCHEMY_API_KEY environment variable must be set");
+
+        let quicknode_rpc_url = env::var("QUICKNODE_RPC_URL")
+            .ok()
+            .filter(|u| !u.trim().is_empty());
+
+        let quicknode_methods = match env::var("QUICKNODE_HOLDERS_METHOD") {
+            Ok(method) if method.trim().eq_ignore_ascii_case("none") => QuickNodeMethods { holders: None },
+            Ok(method) if !method.trim().is_empty() => QuickNodeMethods { holders: Some(method.trim().to_string()) },
+            _ => QuickNodeMethods::default(),
+        };
+
+        // Read PORT from environment (Render provides this)
+        let port = env::var("PORT")
+            .unwrap_or_else(|_| "3000".to_string())
+            .parse::<u16>()
+            .expect("PORT must be a valid number");
+
+        let attestation_secret = env::var("ATTESTATION_SECRET")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(String::into_bytes);
+
+        let solana_provider = env::var("SOLANA_PROVIDER")
+            .unwrap_or_else(|_| "helius".to_string())
+            .to_lowercase();
+
+        let evm_provider = env::var("EVM_PROVIDER")
+            .unwrap_or_else(|_| "alchemy".to_string())
+            .to_lowercase();
+
+        let queue_capacity = env::var("QUEUE_CAPACITY")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(DEFAULT_QUEUE_CAPACITY);
+
+        let workers = env::var("ANALYSIS_WORKERS")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|&n| n > 0)
+            .unwrap_or(DEFAULT_WORKERS);
+
+        let provider_concurrency = env::var("PROVIDER_CONCURRENCY")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|&n| n > 0)
+            .unwrap_or(DEFAULT_PROVIDER_CONCURRENCY);
+
+        let known_malicious_bytecode = env::var("KNOWN_MALICIOUS_BYTECODE")
+            .map(|v| {
+                v.split(',')
+                    .map(|h| h.trim().to_lowercase())
+                    .filter(|h| !h.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let max_body_bytes = env::var("MAX_BODY_BYTES")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|&n| n > 0)
+            .unwrap_or(DEFAULT_MAX_BODY_BYTES);
+
+        let default_chain = env::var("DEFAULT_CHAIN")
+            .ok()
+            .filter(|c| !c.trim().is_empty());
+
+        let coalesce_window_ms = env::var("COALESCE_WINDOW_MS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_COALESCE_WINDOW.as_millis() as u64);
+
+        let rpc_override_token = env::var("RPC_OVERRIDE_TOKEN")
+            .ok()
+            .filter(|t| !t.trim().is_empty());
+
+        let rpc_override_origins = env::var("RPC_OVERRIDE_ORIGINS")
+            .map(|v| {
+                v.split(',')
+                    .map(|o| o.trim().to_string())
+                    .filter(|o| !o.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let scoring = env::var("SCORING_PROFILE")
+            .ok()
+            .filter(|p| !p.trim().is_empty())
+            .map(|p| serde_json::from_str(&p).expect("SCORING_PROFILE must be a valid scoring profile"))
+            .unwrap_or_default();
+
+        Self {
+            port,
+            helius_api_key,
+            alchemy_api_key,
+            quicknode_rpc_url,
+            quicknode_methods,
+            attestation_secret,
+            solana_provider,
+            evm_provider,
+            queue_capacity,
+            workers,
+            provider_concurrency,
+            known_malicious_bytecode,
+            max_body_bytes,
+            default_chain,
+            coalesce_window_ms,
+            rpc_override_token,
+            rpc_override_origins,
+            scoring,
+        }
+    }
+}
+
+/// Build the named provider for a canonical chain
+pub fn select_provider(
+    name: &str,
+    chain: &str,
+    config: &ServerConfig,
+) -> Result<Arc<dyn TokenProvider>, String> {
+    match (name, chain) {
+        ("helius", "solana") => Ok(Arc::new(HeliusProvider::new(config.helius_api_key.clone()))),
+        ("quicknode", "solana") => {
+            let url = config.quicknode_rpc_url.clone()
+                .ok_or("Provider 'quicknode' requires QUICKNODE_RPC_URL")?;
+            Ok(Arc::new(QuickNodeProvider::new(url).with_methods(config.quicknode_methods.clone())))
+        }
+        ("alchemy", "ethereum" | "base" | "evm") => {
+            Ok(Arc::new(AlchemyProvider::new(config.alchemy_api_key.clone(), chain)))
+        }
+        _ => Err(format!(
+            "Provider '{}' is not available for chain '{}'. Available: helius, quicknode (solana), alchemy (ethereum, base)",
+            name, chain
+        )),
+    }
+}
+
+impl AppState {
+    /// Must be called within a Tokio runtime (spawns the analysis workers)
+    pub fn from_config(config: ServerConfig) -> Result<Self, String> {
+        let default_chain = match &config.default_chain {
+            Some(chain) => Some(normalize_chain(chain).ok_or_else(|| {
+                format!("Unsupported DEFAULT_CHAIN '{}'. Supported: solana, ethereum, base", chain)
+            })?),
+            None => None,
+        };
+
+        let analyze_config = AnalyzeConfig {
+            executor: Some(Arc::new(ProviderExecutor::new(config.provider_concurrency))),
+            coalescer: (config.coalesce_window_ms > 0).then(|| {
+                Arc::new(RequestCoalescer::new(Duration::from_millis(config.coalesce_window_ms)))
+            }),
+            known_malicious_bytecode: config.known_malicious_bytecode.clone(),
+            scoring: config.scoring.clone(),
+            ..Default::default()
+        };
+        for warning in analyze_config.scoring.validate()? {
+            eprintln!("Scoring profile warning ({}): {}", warning.code, warning.message);
+        }
+
+        let mut providers = HashMap::new();
+        for chain in SERVED_CHAINS {
+            let name = if chain == "solana" { &config.solana_provider } else { &config.evm_provider };
+            providers.insert(chain.to_string(), select_provider(name, chain, &config)?);
+        }
+
+        let rpc_override = config.rpc_override_token.clone()
+            .map(|token| RpcOverridePolicy::new(token, &config.rpc_override_origins));
+
+        Ok(Self {
+            cache: Mutex::new(SimpleCache::new()),
+            history: Mutex::new(AnalysisHistory::default()),
+            providers,
+            queue: WorkQueue::new(config.queue_capacity, config.workers),
+            analyze_config,
+            attestation_secret: config.attestation_secret,
+            default_chain,
+            rpc_override,
+        })
+    }
+}
+
+/// JSON error body returned for rejected requests
+#[derive(Debug, Serialize)]
+pub struct ApiError {
+    #[serde(skip)]
+    pub status: StatusCode,
+    pub error: String,
+    pub message: String,
+}
+
+impl ApiError {
+    pub fn bad_request(error: &str, message: String) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            error: error.to_string(),
+            message,
+        }
+    }
+
+    pub fn unauthorized(error: &str, message: String) -> Self {
+        Self {
+            status: StatusCode::UNAUTHORIZED,
+            error: error.to_string(),
+            message,
+        }
+    }
+
+    pub fn not_found(error: &str, message: String) -> Self {
+        Self {
+            status: StatusCode::NOT_FOUND,
+            error: error.to_string(),
+            message,
+        }
+    }
+
+    pub fn payload_too_large(message: String) -> Self {
+        Self {
+            status: StatusCode::PAYLOAD_TOO_LARGE,
+            error: "payload_too_large".to_string(),
+            message,
+        }
+    }
+
+    pub fn internal(error: &str, message: String) -> Self {
+        Self {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            error: error.to_string(),
+            message,
+        }
+    }
+
+    pub fn bad_gateway(error: &str, message: String) -> Self {
+        Self {
+            status: StatusCode::BAD_GATEWAY,
+            error: error.to_string(),
+            message,
+        }
+    }
+
+    pub fn service_unavailable(error: &str, message: String) -> Self {
+        Self {
+            status: StatusCode::SERVICE_UNAVAILABLE,
+            error: error.to_string(),
+            message,
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(self)).into_response()
+    }
+}
+
+/// `Json` extractor whose rejections are `ApiError`s naming the offending
+/// field and position, instead of axum's plain-text 400/422
+pub struct ApiJson<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for ApiJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match Json::<T>::from_request(req, state).await {
+            Ok(Json(value)) => Ok(ApiJson(value)),
+            Err(rejection) => Err(json_rejection_error(rejection)),
+        }
+    }
+}
+
+fn json_rejection_error(rejection: JsonRejection) -> ApiError {
+    // Body cut off by the size limit while being read
+    if rejection.status() == StatusCode::PAYLOAD_TOO_LARGE {
+        return ApiError::payload_too_large(rejection.body_text());
+    }
+
+    let error = match &rejection {
+        JsonRejection::JsonSyntaxError(_) => "malformed_json",
+        JsonRejection::JsonDataError(_) => "invalid_request",
+        JsonRejection::MissingJsonContentType(_) => "unsupported_media_type",
+        _ => "invalid_body",
+    };
+
+    // Reported as 400 regardless of axum's choice (422 for data errors),
+    // except a missing content type which stays 415
+    let status = match &rejection {
+        JsonRejection::MissingJsonContentType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        _ => StatusCode::BAD_REQUEST,
+    };
+
+    ApiError {
+        status,
+        error: error.to_string(),
+        message: rejection.body_text(),
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct AnalyzeQuery {
+    /// Comma-separated top-level response fields (sparse fieldset)
+    pub fields: Option<String>,
+}
+
+/// POST /api/v1/analyze: the full response, or a sparse view when `fields`
+/// is given (query parameter, falling back to `options.fields`). Encoded as
+/// MessagePack or CBOR when the `Accept` header asks for it.
+pub async fn analyze_route(
+    state: State<Arc<AppState>>,
+    Query(query): Query<AnalyzeQuery>,
+    headers: HeaderMap,
+    ApiJson(request): ApiJson<AnalyzeRequest>,
+) -> Result<Response, ApiError> {
+    let format = WireFormat::from_accept(
+        headers.get(header::ACCEPT).and_then(|v| v.to_str().ok()),
+    );
+    let fields = query.fields.as_deref()
+        .map(parse_fields)
+        .or_else(|| request.options.fields.clone());
+
+    // The attestation signs the full body; a filtered view could not be verified
+    if fields.is_some() && request.options.attest {
+        return Err(ApiError::bad_request(
+            "fields_with_attestation",
+            "Sparse fieldsets cannot be combined with attestation".to_string(),
+        ));
+    }
+
+    let bearer = headers.get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+    let Json(response) = run_analysis(state, request, bearer).await?;
+
+    match fields {
+        Some(fields) => encoded(format, &sparse_view(&response, &fields)),
+        None => encoded(format, &response),
+    }
+}
+
+/// Serialize a response body in the negotiated wire format
+fn encoded<T: Serialize>(format: WireFormat, value: &T) -> Result<Response, ApiError> {
+    let body = format.encode(value)
+        .map_err(|e| ApiError::internal("encoding_failed", e))?;
+    Ok(([(header::CONTENT_TYPE, format.content_type())], body).into_response())
 }
 
 pub async fn analyze_handler(
+    state: State<Arc<AppState>>,
+    ApiJson(request): ApiJson<AnalyzeRequest>,
+) -> Result<Json<AnalyzeResponse>, ApiError> {
+    run_analysis(state, request, None).await
+}
+
+/// `analyze_handler` with the caller's bearer token, which authorizes
+/// `rpc_url_override`
+async fn run_analysis(
     State(state): State<Arc<AppState>>,
-    Json(request): Json<AnalyzeRequest>,
-) -> Result<Json<AnalyzeResponse>, StatusCode> {
+    mut request: AnalyzeRequest,
+    bearer: Option<&str>,
+) -> Result<Json<AnalyzeResponse>, ApiError> {
+    if request.chain.trim().is_empty() {
+        request.chain = state.default_chain.clone().ok_or_else(|| {
+            ApiError::bad_request(
+                "missing_chain",
+                "No chain given and no default chain is configured".to_string(),
+            )
+        })?;
+    }
+
     println!("Received request for: {} on {}", request.address, request.chain);
 
-    let mut cache = state.cache.lock().await;
+    // Canonicalize chain aliases before dispatch
+    request.chain = normalize_chain(&request.chain).ok_or_else(|| {
+        ApiError::bad_request(
+            "unsupported_chain",
+            format!("Unsupported chain '{}'. Supported: solana, ethereum, base", request.chain),
+        )
+    })?;
+    request.address = normalize_address(&request.chain, &request.address);
 
-    // Create provider based on chain
-    let response = match request.chain.as_str() {
-        "solana" => {
-            let provider = HeliusProvider::new(state.helius_api_key.clone());
-            analyze_with_cache(request, &provider, &mut cache).await
-        }
-        "base" | "ethereum" | "evm" => {
-            let provider = AlchemyProvider::new(state.alchemy_api_key.clone(), &request.chain);
-            analyze_with_cache(request, &provider, &mut cache).await
-        }
-        _ => {
-            return Err(StatusCode::BAD_REQUEST);
+    // Slots are Solana's clock, blocks every other chain's
+    let (used, expected) = if request.chain == "solana" { ("at_block", "at_slot") } else { ("at_slot", "at_block") };
+    let mismatched = if request.chain == "solana" { request.options.at_block } else { request.options.at_slot };
+    if mismatched.is_some() {
+        return Err(ApiError::bad_request(
+            "invalid_point_in_time",
+            format!("{} does not apply to {}; use {}", used, request.chain, expected),
+        ));
+    }
+
+    if request.options.attest && state.attestation_secret.is_none() {
+        return Err(ApiError::bad_request(
+            "attestation_unavailable",
+            "Attestation requested but no signing secret is configured".to_string(),
+        ));
+    }
+
+    // Provider configured for this chain at startup, unless the caller may
+    // route this analysis through their own node
+    let provider = match request.options.rpc_url_override.as_deref() {
+        Some(url) => {
+            let policy = state.rpc_override.as_ref().ok_or_else(|| {
+                ApiError::bad_request(
+                    "rpc_override_unavailable",
+                    "Per-request RPC endpoints are not enabled on this server".to_string(),
+                )
+            })?;
+            policy.provider_for(&request.chain, url, bearer)?
         }
+        None => state.providers.get(&request.chain).cloned().ok_or_else(|| {
+            ApiError::bad_request(
+                "unsupported_chain",
+                format!("Unsupported chain '{}'", request.chain),
+            )
+        })?,
     };
 
+    if request.options.point_in_time(&request.chain).is_some() && !provider.capabilities().point_in_time {
+        return Err(ApiError::bad_request(
+            "invalid_point_in_time",
+            format!("The {} provider cannot read past state", provider.provider_name()),
+        ));
+    }
+
+    let attest = request.options.attest;
+    let overridden = request.options.rpc_url_override.is_some();
+    let job_state = state.clone();
+    let result = state.queue.try_submit(async move {
+        let state = job_state;
+        let response = analyze_with_shared_cache(
+            request,
+            provider.as_ref(),
+            &state.cache,
+            &state.analyze_config,
+        ).await;
+
+        // Only fresh analyses of the current state, from the operator's own
+        // providers, extend the timeline: a point-in-time analysis would be
+        // stamped with today's date, and a caller's node can report anything
+        let point_in_time = response.at_slot.is_some() || response.at_block.is_some();
+        if !is_from_cache(&response) && response.status != AnalysisStatus::NotCached
+            && !point_in_time && !overridden
+        {
+            let key = history_key(&response.chain, &response.address);
+            state.history.lock().await.record(&key, &response);
+        }
+
+        response
+    }).map_err(|_| {
+        ApiError::service_unavailable(
+            "queue_full",
+            "Too many analyses in progress, retry shortly".to_string(),
+        )
+    })?;
+
+    let mut response = result.await.map_err(|_| {
+        ApiError::service_unavailable("analysis_failed", "Analysis worker stopped".to_string())
+    })?;
+
+    // Signed last, so cached copies stay unsigned and every body is signed as served
+    if let (true, Some(secret)) = (attest, state.attestation_secret.as_deref()) {
+        attach_attestation(&mut response, secret);
+    }
+
     Ok(Json(response))
 }
 
-pub async fn run_server(port: u16, helius_api_key: String, alchemy_api_key: String) {
-    let state = Arc::new(AppState {
-        cache: Mutex::new(SimpleCache::new()),
-        helius_api_key,
-        alchemy_api_key,
+#[derive(Debug, Deserialize)]
+pub struct MultiAnalyzeQuery {
+    /// Comma-separated token addresses
+    pub addresses: String,
+}
+
+/// One address of a multi-address analysis: its response, or why it could
+/// not be analyzed
+#[derive(Debug, Serialize)]
+pub struct AddressAnalysis {
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<AnalyzeResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ApiError>,
+}
+
+/// GET /api/v1/analyze/:chain?addresses=a,b,c: quick analysis of a few
+/// tokens on one chain with default options. At most `MAX_MULTI_ADDRESSES`
+/// addresses, `ANALYZE_MANY_CONCURRENCY` analyzed at a time; results are
+/// in request order, and an address that fails validation or analysis gets
+/// its error inline instead of failing the whole request.
+pub async fn analyze_multi_handler(
+    State(state): State<Arc<AppState>>,
+    Path(chain): Path<String>,
+    Query(query): Query<MultiAnalyzeQuery>,
+) -> Result<Json<Vec<AddressAnalysis>>, ApiError> {
+    let chain = normalize_chain(&chain).ok_or_else(|| {
+        ApiError::bad_request(
+            "unsupported_chain",
+            format!("Unsupported chain '{}'. Supported: solana, ethereum, base", chain),
+        )
+    })?;
+    let addresses: Vec<String> = query.addresses.split(',')
+        .map(|a| a.trim().to_string())
+        .filter(|a| !a.is_empty())
+        .collect();
+    if addresses.is_empty() {
+        return Err(ApiError::bad_request(
+            "missing_addresses",
+            "addresses must name at least one token".to_string(),
+        ));
+    }
+    if addresses.len() > MAX_MULTI_ADDRESSES {
+        return Err(ApiError::bad_request(
+            "too_many_addresses",
+            format!("At most {} addresses per request, got {}", MAX_MULTI_ADDRESSES, addresses.len()),
+        ));
+    }
+
+    let results = stream::iter(addresses)
+        .map(|address| {
+            let state = state.clone();
+            let chain = chain.clone();
+            async move {
+                let result = match validate_address(&chain, &address) {
+                    Ok(()) => {
+                        let request = AnalyzeRequest {
+                            chain,
+                            address: address.clone(),
+                            options: AnalyzeOptions::default(),
+                        };
+                        run_analysis(State(state), request, None).await.map(|Json(response)| response)
+                    }
+                    Err(message) => Err(ApiError::bad_request("invalid_address", message)),
+                };
+                let (response, error) = match result {
+                    Ok(response) => (Some(response), None),
+                    Err(error) => (None, Some(error)),
+                };
+                AddressAnalysis { address, response, error }
+            }
+        })
+        .buffered(ANALYZE_MANY_CONCURRENCY)
+        .collect()
+        .await;
+
+    Ok(Json(results))
+}
+
+/// POST /api/v1/rescore: re-grade previously returned checks under a
+/// client-supplied profile. Makes no provider calls.
+pub async fn rescore_handler(
+    ApiJson(request): ApiJson<RescoreRequest>,
+) -> Result<Json<RescoreResponse>, ApiError> {
+    let warnings = request.profile.validate()
+        .map_err(|message| ApiError::bad_request("invalid_profile", message))?;
+    request.profile.validate_checks(&request.checks)
+        .map_err(|message| ApiError::bad_request("invalid_checks", message))?;
+    Ok(Json(RescoreResponse {
+        score: aggregate_score_with_profile(&request.checks, &request.profile),
+        warnings,
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GradeQuery {
+    /// Server default chain when omitted
+    #[serde(default)]
+    pub chain: String,
+    pub address: String,
+}
+
+/// Minimal polling payload
+#[derive(Debug, Serialize)]
+pub struct GradeResponse {
+    pub grade: Grade,
+    pub fairness_score: Option<u8>,
+    pub verdict: Verdict,
+    pub from_cache: bool,
+}
+
+/// GET /api/v1/grade?chain=..&address=..: just the grade, for dashboards
+/// polling many tokens. Served from the analysis cache when fresh.
+pub async fn grade_handler(
+    state: State<Arc<AppState>>,
+    Query(query): Query<GradeQuery>,
+) -> Result<Json<GradeResponse>, ApiError> {
+    let request = AnalyzeRequest {
+        chain: query.chain,
+        address: query.address,
+        options: AnalyzeOptions::default(),
+    };
+    let response = analyze_existing_token(state, request).await?;
+
+    Ok(Json(GradeResponse {
+        from_cache: is_from_cache(&response),
+        grade: response.score.grade,
+        fairness_score: response.score.fairness_score,
+        verdict: response.verdict,
+    }))
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct GradeSummaryQuery {
+    #[serde(default)]
+    pub force_refresh: bool,
+}
+
+/// Minimal per-token polling payload
+#[derive(Debug, Serialize)]
+pub struct GradeSummary {
+    pub address: String,
+    pub chain: String,
+    pub grade: Grade,
+    pub fairness_score: Option<u8>,
+    /// Weight-averaged confidence of the decided checks; None when no check
+    /// was decided
+    pub confidence: Option<f64>,
+    pub cached: bool,
+}
+
+/// GET /api/v1/grade/:chain/:address: grade summary from the cached full
+/// analysis; `?force_refresh=true` re-analyzes
+pub async fn grade_summary_handler(
+    state: State<Arc<AppState>>,
+    Path((chain, address)): Path<(String, String)>,
+    Query(query): Query<GradeSummaryQuery>,
+) -> Result<Json<GradeSummary>, ApiError> {
+    let request = AnalyzeRequest {
+        chain,
+        address,
+        options: AnalyzeOptions { force_refresh: query.force_refresh, ..AnalyzeOptions::default() },
+    };
+    let response = analyze_existing_token(state, request).await?;
+
+    Ok(Json(GradeSummary {
+        cached: is_from_cache(&response),
+        confidence: data_confidence(&response.checks),
+        address: response.address,
+        chain: response.chain,
+        grade: response.score.grade,
+        fairness_score: response.score.fairness_score,
+    }))
+}
+
+/// Full analysis for the grade endpoints; 404 when there is no such token,
+/// 502/503 when the provider failed to say
+async fn analyze_existing_token(
+    state: State<Arc<AppState>>,
+    request: AnalyzeRequest,
+) -> Result<AnalyzeResponse, ApiError> {
+    let Json(response) = analyze_handler(state, ApiJson(request)).await?;
+    if response.status != AnalysisStatus::Error {
+        return Ok(response);
+    }
+
+    // Neither metadata nor authorities could be read: a provider that
+    // answered "not found" (or found no fungible token) settles it, one
+    // that failed does not
+    let failure = response.error_details.iter().find(|d| d.code != "not_found");
+    Err(match failure {
+        Some(detail) if detail.fact == "provider" || detail.code == "rate_limited" => {
+            ApiError::service_unavailable("provider_unavailable", detail.message.clone())
+        }
+        Some(detail) => ApiError::bad_gateway("provider_error", detail.message.clone()),
+        None => ApiError::not_found(
+            "token_not_found",
+            format!("No token found at '{}' on {}", response.address, response.chain),
+        ),
+    })
+}
+
+fn data_confidence(checks: &[CheckResult]) -> Option<f64> {
+    let decided = checks.iter().filter(|c| c.status != CheckStatus::Unknown);
+    let (weighted, total) = decided.fold((0.0, 0.0), |(weighted, total), check| {
+        (weighted + check.confidence * check.weight as f64, total + check.weight as f64)
     });
+    (total > 0.0).then(|| (weighted / total * 100.0).round() / 100.0)
+}
+
+#[derive(Debug, Serialize)]
+pub struct HistoryResponse {
+    pub chain: String,
+    pub address: String,
+    pub points: Vec<HistoryPoint>,
+}
+
+pub async fn history_handler(
+    State(state): State<Arc<AppState>>,
+    Path((chain, address)): Path<(String, String)>,
+) -> Result<Json<HistoryResponse>, ApiError> {
+    history_for(&state, chain, address).await
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HistoryQuery {
+    /// Server default chain when omitted
+    #[serde(default)]
+    pub chain: String,
+    pub address: String,
+}
+
+/// GET /api/v1/history?chain=..&address=..: grade/score timeline of recent
+/// fresh analyses, oldest first
+pub async fn history_query_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<HistoryQuery>,
+) -> Result<Json<HistoryResponse>, ApiError> {
+    let chain = if query.chain.trim().is_empty() {
+        state.default_chain.clone().ok_or_else(|| {
+            ApiError::bad_request(
+                "missing_chain",
+                "No chain given and no default chain is configured".to_string(),
+            )
+        })?
+    } else {
+        query.chain
+    };
+    history_for(&state, chain, query.address).await
+}
+
+async fn history_for(
+    state: &AppState,
+    chain: String,
+    address: String,
+) -> Result<Json<HistoryResponse>, ApiError> {
+    let chain = normalize_chain(&chain).ok_or_else(|| {
+        ApiError::bad_request("unsupported_chain", format!("Unsupported chain '{}'", chain))
+    })?;
+    let address = normalize_address(&chain, &address);
+
+    let points = state.history.lock().await.get(&history_key(&chain, &address));
+
+    Ok(Json(HistoryResponse {
+        chain,
+        address,
+        points,
+    }))
+}
+
+fn history_key(chain: &str, address: &str) -> String {
+    format!("{}:{}", chain, address)
+}
 
+/// All routes and middleware; request bodies over `max_body_bytes` get 413
+pub fn build_router(state: Arc<AppState>, max_body_bytes: usize) -> Router {
     let cors = CorsLayer::new()
         .allow_origin(Any)
         .allow_methods(Any)
         .allow_headers(Any);
 
-    let app = Router::new()
-        .route("/api/v1/analyze", post(analyze_handler))
+    Router::new()
+        .route("/api/v1/analyze", post(analyze_route))
+        .route("/api/v1/analyze/:chain", get(analyze_multi_handler))
+        .route("/api/v1/rescore", post(rescore_handler))
+        .route("/api/v1/grade", get(grade_handler))
+        .route("/api/v1/grade/:chain/:address", get(grade_summary_handler))
+        .route("/api/v1/history", get(history_query_handler))
+        .route("/api/v1/history/:chain/:address", get(history_handler))
+        .layer(RequestBodyLimitLayer::new(max_body_bytes))
+        .layer(middleware::map_response(json_payload_too_large))
         .layer(cors)
-        .with_state(state);
+        .with_state(state)
+}
+
+/// The body limit layer rejects oversized `Content-Length`s with a plain-text
+/// 413; give clients the usual JSON error instead
+async fn json_payload_too_large(response: Response) -> Response {
+    let is_json = response.headers()
+        .get(header::CONTENT_TYPE)
+        .is_some_and(|v| v.as_bytes().starts_with(b"application/json"));
+
+    if response.status() != StatusCode::PAYLOAD_TOO_LARGE || is_json {
+        return response;
+    }
+
+    ApiError::payload_too_large("Request body exceeds the size limit".to_string()).into_response()
+}
+
+pub async fn run_server(config: ServerConfig) {
+    let port = config.port;
+    let max_body_bytes = config.max_body_bytes;
+    let state = Arc::new(AppState::from_config(config).expect("invalid provider configuration"));
+
+    let app = build_router(state, max_body_bytes);
 
     // CRITICAL FIX: Bind to 0.0.0.0 instead of 127.0.0.1 for external access
     let addr = format!("0.0.0.0:{}", port);
@@ -76,3 +978,734 @@ pub async fn run_server(port: u16, helius_api_key: String, alchemy_api_key: Stri
         .await
         .unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::types::AnalyzeOptions;
+
+    fn test_config() -> ServerConfig {
+        ServerConfig {
+            port: 0,
+            helius_api_key: "test".to_string(),
+            alchemy_api_key: "test".to_string(),
+            quicknode_rpc_url: None,
+            quicknode_methods: QuickNodeMethods::default(),
+            attestation_secret: None,
+            solana_provider: "helius".to_string(),
+            evm_provider: "alchemy".to_string(),
+            queue_capacity: DEFAULT_QUEUE_CAPACITY,
+            workers: DEFAULT_WORKERS,
+            provider_concurrency: DEFAULT_PROVIDER_CONCURRENCY,
+            known_malicious_bytecode: HashSet::new(),
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            default_chain: None,
+            coalesce_window_ms: DEFAULT_COALESCE_WINDOW.as_millis() as u64,
+            rpc_override_token: None,
+            rpc_override_origins: Vec::new(),
+            scoring: ScoringProfile::default(),
+        }
+    }
+
+    fn test_state() -> Arc<AppState> {
+        Arc::new(AppState::from_config(test_config()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_configured_provider_selected_per_chain() {
+        let state = test_state();
+
+        assert_eq!(state.providers["solana"].provider_name(), "helius");
+        assert_eq!(state.providers["base"].provider_name(), "alchemy");
+        assert_eq!(state.providers["ethereum"].provider_name(), "alchemy");
+
+        let unavailable = ServerConfig {
+            solana_provider: "solscan".to_string(),
+            ..test_config()
+        };
+        let err = AppState::from_config(unavailable).err().unwrap();
+        assert!(err.contains("solscan"));
+
+        // Providers are bound to the chains they support
+        assert!(select_provider("helius", "base", &test_config()).is_err());
+
+        // QuickNode needs its endpoint configured
+        assert!(select_provider("quicknode", "solana", &test_config()).is_err());
+        let quicknode = ServerConfig {
+            quicknode_rpc_url: Some("https://example.solana-mainnet.quiknode.pro/token/".to_string()),
+            ..test_config()
+        };
+        assert_eq!(select_provider("quicknode", "solana", &quicknode).unwrap().provider_name(), "quicknode");
+    }
+
+    async fn extract(body: &str) -> Result<AnalyzeRequest, ApiError> {
+        let request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/api/v1/analyze")
+            .header("content-type", "application/json")
+            .body(axum::body::Body::from(body.to_string()))
+            .unwrap();
+
+        ApiJson::<AnalyzeRequest>::from_request(request, &()).await.map(|ApiJson(r)| r)
+    }
+
+    #[tokio::test]
+    async fn test_malformed_json_rejected_with_position() {
+        let err = extract(r#"{"chain": "solana", "address": "#).await.unwrap_err();
+
+        assert_eq!(err.status, StatusCode::BAD_REQUEST);
+        assert_eq!(err.error, "malformed_json");
+        assert!(err.message.contains("line 1"), "{}", err.message);
+    }
+
+    #[tokio::test]
+    async fn test_type_mismatch_names_field() {
+        let body = r#"{"chain": "solana", "address": "abc", "options": {"max_holders": "10"}}"#;
+        let err = extract(body).await.unwrap_err();
+
+        assert_eq!(err.status, StatusCode::BAD_REQUEST);
+        assert_eq!(err.error, "invalid_request");
+        assert!(err.message.contains("options.max_holders"), "{}", err.message);
+
+        // Missing options fall back to defaults
+        let request = extract(r#"{"chain": "solana", "address": "abc"}"#).await.unwrap();
+        assert_eq!(request.options.max_holders, 10);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_chain_alias_rejected() {
+        let request = AnalyzeRequest {
+            chain: "dogechain".to_string(),
+            address: "some_address".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+
+        let err = analyze_handler(State(test_state()), ApiJson(request)).await.unwrap_err();
+
+        assert_eq!(err.status, StatusCode::BAD_REQUEST);
+        assert_eq!(err.error, "unsupported_chain");
+        assert!(err.message.contains("dogechain"));
+    }
+
+    #[tokio::test]
+    async fn test_handler_reuses_startup_provider() {
+        use crate::providers::MockProvider;
+        use crate::types::{Metadata, TokenFacts, TokenStandard};
+
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                symbol: Some("SHARED".to_string()),
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let provider: Arc<dyn TokenProvider> =
+            Arc::new(MockProvider::new("startup").with_facts("shared_token", facts));
+
+        let mut state = AppState::from_config(test_config()).unwrap();
+        state.providers.insert("solana".to_string(), provider.clone());
+        let state = Arc::new(state);
+
+        for _ in 0..3 {
+            let request = AnalyzeRequest {
+                chain: "solana".to_string(),
+                address: "shared_token".to_string(),
+                options: AnalyzeOptions {
+                    force_refresh: true,
+                    ..AnalyzeOptions::default()
+                },
+            };
+            let Json(response) = analyze_handler(State(state.clone()), ApiJson(request)).await.unwrap();
+            assert_eq!(response.token.unwrap().symbol.as_deref(), Some("SHARED"));
+        }
+
+        // Same instance still in place, and no per-request handles left behind
+        assert!(Arc::ptr_eq(&state.providers["solana"], &provider));
+        assert_eq!(Arc::strong_count(&provider), 2);
+    }
+
+    #[tokio::test]
+    async fn test_full_queue_returns_503() {
+        // No workers: queued jobs stay queued, so the queue is deterministically full
+        let state = Arc::new(AppState::from_config(ServerConfig {
+            queue_capacity: 1,
+            workers: 0,
+            ..test_config()
+        }).unwrap());
+        let _occupant = state.queue.try_submit(async {}).unwrap();
+
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "some_address".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+
+        let err = analyze_handler(State(state), ApiJson(request)).await.unwrap_err();
+
+        assert_eq!(err.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(err.error, "queue_full");
+    }
+
+    #[tokio::test]
+    async fn test_fields_query_returns_sparse_view() {
+        use crate::providers::MockProvider;
+
+        let mut state = AppState::from_config(test_config()).unwrap();
+        state.providers.insert(
+            "solana".to_string(),
+            Arc::new(MockProvider::new("test").with_facts("sparse_token", Default::default())),
+        );
+
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "sparse_token".to_string(),
+            options: AnalyzeOptions::default(),
+        };
+        let query = AnalyzeQuery { fields: Some("score,grade".to_string()) };
+
+        let response = analyze_route(
+            State(Arc::new(state)),
+            Query(query),
+            HeaderMap::new(),
+            ApiJson(request),
+        ).await.unwrap();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+
+        assert!(body.get("score").is_some());
+        assert!(body.get("checks").is_none());
+        assert!(!body.to_string().contains("evidence"));
+        assert_eq!(body["ignored_fields"], serde_json::json!(["grade"]));
+    }
+
+    #[tokio::test]
+    async fn test_attest_without_secret_rejected() {
+        let request = AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "some_address".to_string(),
+            options: AnalyzeOptions {
+                attest: true,
+                ..AnalyzeOptions::default()
+            },
+        };
+
+        let err = analyze_handler(State(test_state()), ApiJson(request)).await.unwrap_err();
+
+        assert_eq!(err.status, StatusCode::BAD_REQUEST);
+        assert_eq!(err.error, "attestation_unavailable");
+    }
+
+    #[tokio::test]
+    async fn test_history_endpoint_normalizes_chain() {
+        let state = test_state();
+        let response = crate::cache::simple_cache::tests::make_test_response();
+        state.history.lock().await.record("solana:test_address", &response);
+
+        let Json(body) = history_handler(
+            State(state),
+            Path(("SOL".to_string(), "test_address".to_string())),
+        ).await.unwrap();
+
+        assert_eq!(body.chain, "solana");
+        assert_eq!(body.points.len(), 1);
+        assert_eq!(body.points[0].analysis_id, "test123");
+    }
+
+    #[tokio::test]
+    async fn test_history_query_lists_analyses_in_order() {
+        use crate::providers::MockProvider;
+        use crate::types::{AgeBand, CreationInfo, Metadata, TokenFacts, TokenStandard};
+
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                decimals: Some(9),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            creation: Some(CreationInfo {
+                created_at: Some("2026-01-20T00:00:00Z".to_string()),
+                age_seconds: None,
+                age_band: AgeBand::Unknown,
+                estimated: false,
+            }),
+            ..Default::default()
+        };
+        let mut state = AppState::from_config(test_config()).unwrap();
+        state.providers.insert(
+            "solana".to_string(),
+            Arc::new(MockProvider::new("test").with_facts("aging_token", facts)),
+        );
+        let state = Arc::new(state);
+
+        // The token ages between analyses, so each scores higher
+        let created = 1_768_867_200;
+        let mut analyses = Vec::new();
+        for as_of in [created + 3_600, created + 2 * 86_400, created + 30 * 86_400] {
+            let Json(response) = analyze_handler(State(state.clone()), ApiJson(AnalyzeRequest {
+                chain: "solana".to_string(),
+                address: "aging_token".to_string(),
+                options: AnalyzeOptions { as_of: Some(as_of), ..AnalyzeOptions::default() },
+            })).await.unwrap();
+            analyses.push(response);
+        }
+        let scores: Vec<Option<u8>> = analyses.iter().map(|r| r.score.fairness_score).collect();
+        assert!(scores[0] < scores[1] && scores[1] < scores[2], "{:?}", scores);
+
+        let Json(body) = history_query_handler(
+            State(state),
+            Query(HistoryQuery { chain: "sol".to_string(), address: "aging_token".to_string() }),
+        ).await.unwrap();
+
+        let ids: Vec<&str> = body.points.iter().map(|p| p.analysis_id.as_str()).collect();
+        let expected: Vec<&str> = analyses.iter().map(|r| r.analysis_id.as_str()).collect();
+        assert_eq!(ids, expected);
+        let history_scores: Vec<Option<u8>> = body.points.iter().map(|p| p.fairness_score).collect();
+        assert_eq!(history_scores, scores);
+    }
+
+    #[tokio::test]
+    async fn test_point_in_time_analysis_not_recorded_in_history() {
+        use crate::providers::MockProvider;
+
+        let mut state = AppState::from_config(test_config()).unwrap();
+        state.providers.insert(
+            "solana".to_string(),
+            Arc::new(MockProvider::new("test").with_facts_at("token", 100, Default::default())),
+        );
+        let state = Arc::new(state);
+
+        let Json(response) = analyze_handler(State(state.clone()), ApiJson(AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { at_slot: Some(100), ..AnalyzeOptions::default() },
+        })).await.unwrap();
+
+        assert_eq!(response.at_slot, Some(100));
+        assert!(state.history.lock().await.get("solana:token").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_point_in_time_rejected_without_archival_provider() {
+        use crate::providers::{MockProvider, ProviderCapabilities};
+
+        let mut state = AppState::from_config(test_config()).unwrap();
+        let capabilities = ProviderCapabilities { point_in_time: false, ..ProviderCapabilities::all() };
+        state.providers.insert(
+            "solana".to_string(),
+            Arc::new(MockProvider::new("test").with_capabilities(capabilities)),
+        );
+
+        let err = analyze_handler(State(Arc::new(state)), ApiJson(AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "token".to_string(),
+            options: AnalyzeOptions { at_slot: Some(100), ..AnalyzeOptions::default() },
+        })).await.unwrap_err();
+
+        assert_eq!(err.status, StatusCode::BAD_REQUEST);
+        assert_eq!(err.error, "invalid_point_in_time");
+    }
+
+    #[tokio::test]
+    async fn test_rescore_matches_library_result() {
+        let mut response = crate::cache::simple_cache::tests::make_test_response();
+        response.checks = vec![
+            crate::checks::check_mint_authority_disabled(&Default::default()),
+            crate::checks::check_token_age(&Default::default()),
+        ];
+        let mut body = serde_json::to_value(&response).unwrap();
+        body["profile"] = serde_json::json!({ "min_coverage": 50.0 });
+
+        let request: RescoreRequest = serde_json::from_value(body).unwrap();
+        let Json(RescoreResponse { score, warnings }) = rescore_handler(ApiJson(request.clone())).await.unwrap();
+        assert!(warnings.is_empty());
+
+        let expected = aggregate_score_with_profile(&response.checks, &request.profile);
+        assert_eq!(
+            serde_json::to_value(&score).unwrap(),
+            serde_json::to_value(&expected).unwrap()
+        );
+        assert!(matches!(score.grade, crate::types::Grade::Insufficient));
+    }
+
+    #[tokio::test]
+    async fn test_rescore_rejects_oversized_weights() {
+        let mut check = crate::checks::check_token_age(&Default::default());
+        check.weight = 255;
+        let request = RescoreRequest { checks: vec![check], profile: crate::scoring::ScoringProfile::default() };
+
+        let err = rescore_handler(ApiJson(request)).await.unwrap_err();
+        assert_eq!(err.status, StatusCode::BAD_REQUEST);
+        assert_eq!(err.error, "invalid_checks");
+    }
+
+    #[tokio::test]
+    async fn test_rescore_returns_profile_warnings() {
+        let checks = vec![crate::checks::check_token_age(&Default::default())];
+        let request: RescoreRequest = serde_json::from_value(serde_json::json!({
+            "checks": checks,
+            "profile": { "weight_policy": { "by_severity": { "critical": 2, "high": 20, "medium": 15, "low": 10 } } },
+        })).unwrap();
+
+        let Json(response) = rescore_handler(ApiJson(request)).await.unwrap();
+        assert!(response.warnings.iter().any(|w| w.code == "critical_underweighted"));
+        let body = serde_json::to_value(&response).unwrap();
+        assert!(body["grade"].is_string());
+        assert_eq!(body["warnings"][0]["code"], "critical_underweighted");
+    }
+
+    #[tokio::test]
+    async fn test_configured_scoring_profile_validated() {
+        let profile = ScoringProfile {
+            nameless_severity: crate::types::Severity::High,
+            ..Default::default()
+        };
+        let error = AppState::from_config(ServerConfig { scoring: profile, ..test_config() }).err().unwrap();
+        assert!(error.contains("nameless_severity"));
+
+        let profile = ScoringProfile { min_coverage: 50.0, ..Default::default() };
+        let state = AppState::from_config(ServerConfig { scoring: profile, ..test_config() }).unwrap();
+        assert_eq!(state.analyze_config.scoring.min_coverage, 50.0);
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secret2"));
+        assert!(!constant_time_eq(b"", b"secret"));
+    }
+
+    fn override_state(origin: &str) -> Arc<AppState> {
+        Arc::new(AppState::from_config(ServerConfig {
+            rpc_override_token: Some("secret".to_string()),
+            rpc_override_origins: vec![origin.to_string()],
+            ..test_config()
+        }).unwrap())
+    }
+
+    async fn analyze_with_override(state: Arc<AppState>, url: &str, token: &str) -> Result<Response, ApiError> {
+        let request = AnalyzeRequest {
+            chain: "ethereum".to_string(),
+            address: "0x1111111111111111111111111111111111111111".to_string(),
+            options: AnalyzeOptions { rpc_url_override: Some(url.to_string()), ..Default::default() },
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert(header::AUTHORIZATION, format!("Bearer {}", token).parse().unwrap());
+        analyze_route(State(state), Query(AnalyzeQuery::default()), headers, ApiJson(request)).await
+    }
+
+    #[tokio::test]
+    async fn test_allowed_rpc_override_is_used() {
+        use crate::providers::rpc_harness::{rpc_error, serve_rpc};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let seen = calls.clone();
+        let url = serve_rpc(move |_| {
+            seen.fetch_add(1, Ordering::SeqCst);
+            rpc_error(3, "execution reverted")
+        }).await;
+        let state = override_state(url.trim_end_matches('/'));
+
+        let response = analyze_with_override(state.clone(), &url, "secret").await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(calls.load(Ordering::SeqCst) > 0, "override endpoint was not called");
+        // A caller-chosen node does not get to write the shared timeline
+        assert_eq!(state.history.lock().await.tracked_addresses(), 0);
+
+        // Without the token the same request is refused
+        let err = analyze_with_override(state, &url, "wrong").await.unwrap_err();
+        assert_eq!(err.status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_disallowed_rpc_override_host_rejected() {
+        let state = override_state("https://rpc.example.com");
+
+        let err = analyze_with_override(state, "https://169.254.169.254/latest", "secret").await.unwrap_err();
+
+        assert_eq!(err.status, StatusCode::BAD_REQUEST);
+        assert_eq!(err.error, "rpc_host_not_allowed");
+        assert_eq!(err.message, "RPC endpoint 'https://169.254.169.254' is not in the override allowlist");
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_rejected_with_413() {
+        let app = build_router(test_state(), 1024);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let padding = "x".repeat(4096);
+        let body = format!(r#"{{"chain":"solana","address":"{}"}}"#, padding);
+        let response = reqwest::Client::new()
+            .post(format!("http://{}/api/v1/analyze", addr))
+            .header("content-type", "application/json")
+            .body(body)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), reqwest::StatusCode::PAYLOAD_TOO_LARGE);
+        let error: serde_json::Value = response.json().await.unwrap();
+        assert_eq!(error["error"], "payload_too_large");
+    }
+
+    #[tokio::test]
+    async fn test_grade_endpoint_small_and_cached() {
+        use crate::providers::MockProvider;
+        use crate::types::{Metadata, TokenFacts, TokenStandard};
+
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                symbol: Some("POLL".to_string()),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut state = AppState::from_config(test_config()).unwrap();
+        state.providers.insert(
+            "solana".to_string(),
+            Arc::new(MockProvider::new("test").with_facts("poll_token", facts)),
+        );
+        let state = Arc::new(state);
+        let query = || GradeQuery {
+            chain: "solana".to_string(),
+            address: "poll_token".to_string(),
+        };
+
+        let Json(first) = grade_handler(State(state.clone()), Query(query())).await.unwrap();
+        let json = serde_json::to_value(&first).unwrap();
+        let mut keys: Vec<&String> = json.as_object().unwrap().keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["fairness_score", "from_cache", "grade", "verdict"]);
+        assert!(!first.from_cache);
+
+        let Json(second) = grade_handler(State(state.clone()), Query(query())).await.unwrap();
+        assert!(second.from_cache);
+
+        let err = grade_handler(
+            State(state),
+            Query(GradeQuery { chain: "solana".to_string(), address: "missing".to_string() }),
+        ).await.unwrap_err();
+        assert_eq!(err.status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_grade_provider_failure_is_not_404() {
+        use crate::providers::{MockProvider, ProviderError};
+
+        let mut state = AppState::from_config(test_config()).unwrap();
+        state.providers.insert(
+            "solana".to_string(),
+            Arc::new(MockProvider::new("test").with_error("flaky_token", ProviderError::Timeout)),
+        );
+        let state = Arc::new(state);
+
+        let err = grade_handler(
+            State(state),
+            Query(GradeQuery { chain: "solana".to_string(), address: "flaky_token".to_string() }),
+        ).await.unwrap_err();
+        assert_eq!(err.status, StatusCode::BAD_GATEWAY);
+        assert_eq!(err.error, "provider_error");
+    }
+
+    #[tokio::test]
+    async fn test_grade_summary_matches_full_analysis() {
+        use crate::providers::MockProvider;
+        use crate::types::{AuthorityInfo, Metadata, TokenFacts, TokenStandard};
+
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                name: Some("Poll Token".to_string()),
+                decimals: Some(6),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            authorities: Some(AuthorityInfo {
+                mint_authority: Some("MintKey".to_string()),
+                mint_mutable: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut state = AppState::from_config(test_config()).unwrap();
+        state.providers.insert(
+            "solana".to_string(),
+            Arc::new(MockProvider::new("test").with_facts("poll_token", facts)),
+        );
+        let state = Arc::new(state);
+        let path = || Path(("sol".to_string(), "poll_token".to_string()));
+
+        let Json(summary) = grade_summary_handler(
+            State(state.clone()), path(), Query(GradeSummaryQuery::default()),
+        ).await.unwrap();
+        let json = serde_json::to_value(&summary).unwrap();
+        let mut keys: Vec<&String> = json.as_object().unwrap().keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["address", "cached", "chain", "confidence", "fairness_score", "grade"]);
+        assert_eq!(summary.chain, "solana");
+        assert!(!summary.cached);
+        // Parsed mint state, plus the getter-grade name read by has_identity
+        assert_eq!(summary.confidence, Some(0.99));
+
+        let Json(full) = analyze_handler(State(state.clone()), ApiJson(AnalyzeRequest {
+            chain: "solana".to_string(),
+            address: "poll_token".to_string(),
+            options: AnalyzeOptions::default(),
+        })).await.unwrap();
+        assert_eq!(json["grade"], serde_json::to_value(&full.score.grade).unwrap());
+        assert_eq!(summary.fairness_score, full.score.fairness_score);
+
+        let Json(cached) = grade_summary_handler(
+            State(state.clone()), path(), Query(GradeSummaryQuery::default()),
+        ).await.unwrap();
+        assert!(cached.cached);
+        let Json(refreshed) = grade_summary_handler(
+            State(state), path(), Query(GradeSummaryQuery { force_refresh: true }),
+        ).await.unwrap();
+        assert!(!refreshed.cached);
+    }
+
+    #[tokio::test]
+    async fn test_multi_address_get_returns_one_result_per_address() {
+        use crate::providers::MockProvider;
+        use crate::types::{Metadata, TokenFacts, TokenStandard};
+
+        let addresses = [
+            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+            "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB",
+            "So11111111111111111111111111111111111111112",
+        ];
+        let mut provider = MockProvider::new("test");
+        for (i, address) in addresses.iter().enumerate() {
+            provider = provider.with_facts(address, TokenFacts {
+                metadata: Some(Metadata {
+                    symbol: Some(format!("TOK{}", i)),
+                    standard: TokenStandard::SplToken,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        }
+        let mut state = AppState::from_config(test_config()).unwrap();
+        state.providers.insert("solana".to_string(), Arc::new(provider));
+        let state = Arc::new(state);
+
+        let query = MultiAnalyzeQuery { addresses: addresses.join(", ") };
+        let Json(results) = analyze_multi_handler(State(state.clone()), Path("sol".to_string()), Query(query))
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 3);
+        for (i, (result, address)) in results.iter().zip(addresses).enumerate() {
+            assert_eq!(result.address, address);
+            let symbol = result.response.as_ref().unwrap().token.as_ref().unwrap().symbol.clone();
+            assert_eq!(symbol, Some(format!("TOK{}", i)));
+        }
+
+        // A malformed address is reported inline; the rest still run
+        let query = MultiAnalyzeQuery { addresses: format!("{},not-an-address", addresses[0]) };
+        let Json(results) = analyze_multi_handler(State(state.clone()), Path("solana".to_string()), Query(query))
+            .await
+            .unwrap();
+        assert!(results[0].response.is_some());
+        assert_eq!(results[1].error.as_ref().unwrap().error, "invalid_address");
+
+        let too_many = [addresses[0]; MAX_MULTI_ADDRESSES + 1].join(",");
+        let err = analyze_multi_handler(State(state), Path("solana".to_string()), Query(MultiAnalyzeQuery { addresses: too_many }))
+            .await
+            .unwrap_err();
+        assert_eq!(err.error, "too_many_addresses");
+    }
+
+    #[tokio::test]
+    async fn test_msgpack_response_round_trips() {
+        use crate::providers::MockProvider;
+        use crate::types::{Metadata, TokenFacts, TokenStandard};
+
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                symbol: Some("BIN".to_string()),
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut state = AppState::from_config(test_config()).unwrap();
+        state.providers.insert(
+            "solana".to_string(),
+            Arc::new(MockProvider::new("test").with_facts("bin_token", facts)),
+        );
+        let app = build_router(Arc::new(state), DEFAULT_MAX_BODY_BYTES);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let post = |accept: &'static str| {
+            reqwest::Client::new()
+                .post(format!("http://{}/api/v1/analyze", addr))
+                .header("accept", accept)
+                .json(&serde_json::json!({ "chain": "solana", "address": "bin_token" }))
+                .send()
+        };
+
+        let response = post("application/msgpack").await.unwrap();
+        assert_eq!(response.headers()["content-type"], "application/msgpack");
+        let body = response.bytes().await.unwrap();
+        let decoded: AnalyzeResponse = rmp_serde::from_slice(&body).unwrap();
+        assert_eq!(decoded.address, "bin_token");
+        assert_eq!(decoded.token.unwrap().symbol.as_deref(), Some("BIN"));
+        assert!(!decoded.checks.is_empty());
+        assert!(decoded.checks.iter().all(|c| !c.evidence.is_null()));
+
+        let response = post("application/cbor").await.unwrap();
+        assert_eq!(response.headers()["content-type"], "application/cbor");
+        let body = response.bytes().await.unwrap();
+        let decoded: AnalyzeResponse = ciborium::from_reader(body.as_ref()).unwrap();
+        assert_eq!(decoded.address, "bin_token");
+
+        // JSON stays the default
+        let response = post("*/*").await.unwrap();
+        assert_eq!(response.headers()["content-type"], "application/json");
+    }
+
+    #[tokio::test]
+    async fn test_default_chain_used_when_omitted() {
+        use crate::providers::MockProvider;
+        use crate::types::{Metadata, TokenFacts, TokenStandard};
+
+        let facts = TokenFacts {
+            metadata: Some(Metadata {
+                standard: TokenStandard::SplToken,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let config = ServerConfig { default_chain: Some("SOL".to_string()), ..test_config() };
+        let mut state = AppState::from_config(config).unwrap();
+        state.providers.insert(
+            "solana".to_string(),
+            Arc::new(MockProvider::new("test").with_facts("minimal_token", facts)),
+        );
+        let state = Arc::new(state);
+
+        let request = extract(r#"{"address": "minimal_token"}"#).await.unwrap();
+        let Json(response) = analyze_handler(State(state), ApiJson(request)).await.unwrap();
+
+        assert_eq!(response.chain, "solana");
+        assert!(response.checks.iter().any(|c| c.id == "mint_authority_disabled"));
+
+        // Without a configured default the omission is an error
+        let request = extract(r#"{"address": "minimal_token"}"#).await.unwrap();
+        let err = analyze_handler(State(test_state()), ApiJson(request)).await.unwrap_err();
+        assert_eq!(err.status, StatusCode::BAD_REQUEST);
+        assert_eq!(err.error, "missing_chain");
+
+        let bad_default = ServerConfig { default_chain: Some("dogechain".to_string()), ..test_config() };
+        assert!(AppState::from_config(bad_default).is_err());
+    }
+}
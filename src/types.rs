@@ -3,19 +3,51 @@
 use candid::{CandidType, Deserialize};
 use serde::Serialize;
 
-#[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, CandidType, Serialize, Deserialize)]
 pub struct Metadata {
     pub name: Option<String>,
     pub symbol: Option<String>,
     pub decimals: Option<u8>,
     pub standard: TokenStandard,
+    /// Mint layout `is_initialized` flag (Solana)
+    pub is_initialized: Option<bool>,
+    /// Image URI from off-chain metadata, when the provider resolves it
+    pub image_uri: Option<String>,
+    /// State new token accounts start in (Token-2022 default-account-state
+    /// extension); None when not Token-2022 or not reported
+    #[serde(default)]
+    pub default_account_state: Option<AccountState>,
+    /// Optional interfaces the token implements (informational)
+    #[serde(default)]
+    pub features: Vec<TokenFeature>,
 }
 
-#[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
+/// Optional token interfaces of interest to wallets and integrators
+#[derive(Clone, Debug, PartialEq, CandidType, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenFeature {
+    /// EIP-2612 `permit`: gasless approvals
+    Permit,
+    /// EIP-3009 `transferWithAuthorization`: gasless transfers
+    TransferWithAuthorization,
+}
+
+#[derive(Clone, Debug, PartialEq, CandidType, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountState {
+    Initialized,
+    Frozen,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, CandidType, Serialize, Deserialize)]
 pub enum TokenStandard {
     SplToken,
     SplToken2022,
     Erc20,
+    /// EVM contract that is not an ERC20: an ERC-721/1155 collection, or a
+    /// contract whose ERC20 calls revert
+    NonFungibleOrUnknown,
+    #[default]
     Unknown,
 }
 
@@ -23,20 +55,100 @@ pub enum TokenStandard {
 pub struct SupplyInfo {
     pub total_supply_raw: Option<String>,
     pub total_supply: Option<f64>,
+    /// Circulating supply, when the provider reports one distinct from total
+    /// or labeled contracts (vesting, treasury) are among the top holders
+    #[serde(default)]
+    pub circulating_supply: Option<f64>,
 }
 
-#[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, CandidType, Serialize, Deserialize)]
 pub struct AuthorityInfo {
     pub mint_authority: Option<String>,
     pub freeze_authority: Option<String>,
     pub owner: Option<String>,
     pub mint_mutable: Option<bool>,
+    /// Pending owner of a two-step (Ownable2Step-style) transfer
+    pub pending_owner: Option<String>,
+    /// Set when the mint authority is an SPL multisig account
+    pub mint_authority_multisig: Option<MultisigInfo>,
+    /// Set when the freeze authority is an SPL multisig account
+    pub freeze_authority_multisig: Option<MultisigInfo>,
+    /// What holds the mint authority (Solana) or ownership (EVM), when
+    /// looked up
+    pub controller_kind: Option<ControllerKind>,
+}
+
+/// Account behind an authority: code (a program or contract, e.g. a DAO,
+/// timelock or multisig) or a bare private key (wallet, EOA)
+#[derive(Clone, Copy, Debug, PartialEq, CandidType, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ControllerKind {
+    Program,
+    Key,
+}
+
+/// SPL multisig threshold: `m` of `n` signers must approve
+#[derive(Clone, Debug, PartialEq, CandidType, Serialize, Deserialize)]
+pub struct MultisigInfo {
+    pub m: u8,
+    pub n: u8,
+}
+
+impl SupplyInfo {
+    /// Raw supply as reported: `0x` hex (EVM `totalSupply()`) or decimal
+    pub fn raw_units(&self) -> Option<f64> {
+        let raw = self.total_supply_raw.as_deref()?;
+        match raw.strip_prefix("0x") {
+            Some(hex) => u128::from_str_radix(hex, 16).ok().map(|v| v as f64),
+            None => raw.parse::<f64>().ok(),
+        }
+    }
+
+    /// `raw_units` exactly; None when not an integer or beyond `u128`
+    pub fn raw_quantity(&self) -> Option<u128> {
+        let raw = self.total_supply_raw.as_deref()?;
+        match raw.strip_prefix("0x") {
+            Some(hex) => u128::from_str_radix(hex, 16).ok(),
+            None => raw.parse().ok(),
+        }
+    }
+}
+
+impl MultisigInfo {
+    /// A single required signer is no safer than one key
+    pub fn requires_multiple_signers(&self) -> bool {
+        self.m >= 2
+    }
+
+    /// Multiple signers and at least half of the keys: with a lower
+    /// threshold (2-of-10) a small subset of signers still decides alone
+    pub fn is_threshold_protected(&self) -> bool {
+        self.requires_multiple_signers() && 2 * self.m as u16 >= self.n as u16
+    }
+
+    pub fn m_of_n(&self) -> String {
+        format!("{}-of-{}", self.m, self.n)
+    }
+}
+
+/// Supply as of an earlier slot (Solana) or block (EVM)
+#[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
+pub struct HistoricalSupply {
+    pub at: u64,
+    pub supply: SupplyInfo,
 }
 
 #[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
 pub struct HolderInfo {
     pub top1_pct: Option<f64>,
     pub top5_pct: Option<f64>,
+    /// Total number of non-zero holders, when the provider can count them
+    #[serde(default)]
+    pub holder_count: Option<u64>,
+    /// Combined raw balance of the holders left out of `top_holders`, when
+    /// the provider saw every holder
+    #[serde(default)]
+    pub unlisted_balance_raw: Option<String>,
     pub top_holders: Vec<HolderBalance>,
 }
 
@@ -46,6 +158,121 @@ pub struct HolderBalance {
     pub balance_raw: String,
     pub balance: Option<f64>,
     pub pct_of_supply: Option<f64>,
+    #[serde(default)]
+    pub kind: HolderKind,
+    /// Known role of the address (exchange wallet, pool, ...), when labeled
+    #[serde(default)]
+    pub label: Option<AddressLabel>,
+}
+
+/// Known role of an address, from the provider or the configured label set
+#[derive(Clone, Debug, PartialEq, CandidType, Serialize, Deserialize)]
+pub struct AddressLabel {
+    pub kind: LabelKind,
+    /// e.g. "Binance 14"
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, CandidType, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LabelKind {
+    /// Centralized-exchange wallet: custodial balances of many users
+    Cex,
+    /// DEX liquidity pool
+    DexPool,
+    /// Burn / dead address
+    Burn,
+    /// Other contract (vesting, bridge, treasury, ...)
+    Contract,
+}
+
+/// Fact name for the `top_holders` list in `TokenFacts::not_requested`
+pub const HOLDER_LIST_FACT: &str = "holder_list";
+/// Unknown evidence of list-based checks under `HolderStrategy::Aggregate`
+pub const HOLDER_LIST_NOT_REQUESTED: &str = "holder list not fetched (holder_strategy: aggregate)";
+
+/// How much holder data `fetch_holders` pulls. Under `Aggregate` the
+/// list-based checks report Unknown (`NotRequested`) rather than judging
+/// an empty list.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HolderStrategy {
+    /// `top1_pct`, `top5_pct` and the count only; enough for scoring
+    #[default]
+    Aggregate,
+    /// Also the `top_holders` list, for listing and labels
+    Full,
+}
+
+/// What a holder account is, when the provider can tell
+#[derive(Clone, Debug, Default, PartialEq, CandidType, Serialize, Deserialize)]
+pub enum HolderKind {
+    /// Liquidity pool
+    Lp,
+    /// Burn / dead address
+    Burn,
+    /// Ordinary holder
+    Wallet,
+    /// Solana token account whose owner was not resolved: may be a wallet,
+    /// a pool vault or the owner's own account
+    TokenAccount,
+    #[default]
+    Unclassified,
+}
+
+/// Result of probing the metadata image URI. Informational only.
+#[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
+pub struct ImageInfo {
+    pub uri: String,
+    /// None when the probe timed out or could not connect
+    pub reachable: Option<bool>,
+    pub content_type: Option<String>,
+    pub http_status: Option<u16>,
+    pub error: Option<String>,
+}
+
+/// One on-chain authority reassignment (Solana `SetAuthority`)
+#[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
+pub struct AuthorityChange {
+    /// e.g. "mintTokens", "freezeAccount"
+    pub authority_type: String,
+    /// None when the authority was revoked
+    pub new_authority: Option<String>,
+    pub signature: Option<String>,
+    pub block_time: Option<i64>,
+    pub age_seconds: Option<u64>,
+}
+
+/// Mint account balance against the rent-exempt minimum for its size
+/// (Solana)
+#[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
+pub struct RentInfo {
+    pub lamports: u64,
+    /// Account data size in bytes
+    pub data_len: u64,
+    /// None when the node did not report the minimum
+    pub rent_exempt_minimum: Option<u64>,
+    /// None when the minimum is unknown
+    pub rent_exempt: Option<bool>,
+}
+
+impl RentInfo {
+    pub fn new(lamports: u64, data_len: u64, rent_exempt_minimum: Option<u64>) -> Self {
+        Self {
+            lamports,
+            data_len,
+            rent_exempt_minimum,
+            rent_exempt: rent_exempt_minimum.map(|minimum| lamports >= minimum),
+        }
+    }
+}
+
+/// Deployed contract code (EVM)
+#[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
+pub struct BytecodeInfo {
+    /// `0x`-prefixed keccak-256 of the runtime bytecode
+    pub code_hash: String,
+    pub code_size: usize,
 }
 
 #[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
@@ -53,6 +280,9 @@ pub struct CreationInfo {
     pub created_at: Option<String>,
     pub age_seconds: Option<u64>,
     pub age_band: AgeBand,
+    /// Age inferred from other activity because creation time was unavailable
+    #[serde(default)]
+    pub estimated: bool,
 }
 
 #[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
@@ -64,12 +294,111 @@ pub enum AgeBand {
 }
 
 #[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
+pub struct TaxInfo {
+    pub tax_recipient: Option<String>,
+    pub recipient_is_contract: Option<bool>,
+    pub detection_method: Option<String>,
+}
+
+/// How a simulated `mint` call from an unprivileged caller ended (EVM)
+#[derive(Clone, Debug, PartialEq, CandidType, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MintCallOutcome {
+    /// The call went through: anyone can mint
+    Succeeded,
+    /// Reverted with an access-control error: `mint` exists but is guarded
+    Unauthorized,
+    /// Reverted without data: no `mint` function to dispatch to
+    UnknownSelector,
+    /// Reverted for some other reason
+    Inconclusive,
+}
+
+/// Best-effort dynamic probe of mintability (EVM `eth_call` of `mint`)
+#[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
+pub struct MintSimulation {
+    pub outcome: MintCallOutcome,
+    /// Revert message, when the node returned one
+    pub revert_reason: Option<String>,
+    /// `from` address of the simulated call
+    pub caller: String,
+}
+
+/// A failed fact fetch in machine-readable form
+#[derive(Clone, Debug, PartialEq, CandidType, Serialize, Deserialize)]
+pub struct ErrorDetail {
+    /// Fact name, e.g. "supply"
+    pub fact: String,
+    /// Stable token: timeout, not_found, rate_limited, invalid_response, network
+    pub code: String,
+    pub message: String,
+}
+
+/// Where one fact came from and when it was fetched
+#[derive(Clone, Debug, PartialEq, CandidType, Serialize, Deserialize)]
+pub struct FactSource {
+    /// Fact name, e.g. "authorities"
+    pub fact: String,
+    pub provider: String,
+    /// Provider method that produced the fact, e.g. "fetch_authorities"
+    pub method: String,
+    pub fetched_at: String,
+}
+
+#[derive(Clone, Debug, Default, CandidType, Serialize, Deserialize)]
 pub struct TokenFacts {
     pub metadata: Option<Metadata>,
     pub supply: Option<SupplyInfo>,
     pub authorities: Option<AuthorityInfo>,
     pub holders: Option<HolderInfo>,
     pub creation: Option<CreationInfo>,
+    pub tax: Option<TaxInfo>,
+    pub authority_history: Option<Vec<AuthorityChange>>,
+    pub image: Option<ImageInfo>,
+    pub bytecode: Option<BytecodeInfo>,
+    /// Supply at the requested baseline slot/block, for inflation detection
+    #[serde(default)]
+    pub historical_supply: Option<HistoricalSupply>,
+    /// Result of simulating `mint` (EVM, opt-in)
+    #[serde(default)]
+    pub mint_simulation: Option<MintSimulation>,
+    /// Mint account rent status (Solana)
+    #[serde(default)]
+    pub rent: Option<RentInfo>,
+    /// Facts whose fetch failed with a provider error (e.g. "authorities")
+    #[serde(default)]
+    pub fetch_errors: Vec<String>,
+    /// Facts the provider declared it cannot fetch, so none was attempted
+    #[serde(default)]
+    pub unsupported: Vec<String>,
+    /// Facts the request opted out of (`holder_list` under
+    /// `holder_strategy: aggregate`)
+    #[serde(default)]
+    pub not_requested: Vec<String>,
+    /// Provenance of each fact present above
+    #[serde(default)]
+    pub sources: Vec<FactSource>,
+    /// Every failed fetch, including plain absence (`not_found`)
+    #[serde(default)]
+    pub error_details: Vec<ErrorDetail>,
+}
+
+impl TokenFacts {
+    /// Reason for an absent fact: a failed fetch, a provider that cannot
+    /// fetch it, or simply no data
+    pub fn missing_reason(&self, fact: &str) -> UnknownReason {
+        if self.fetch_errors.iter().any(|f| f == fact) {
+            UnknownReason::ProviderError
+        } else if self.unsupported.iter().any(|f| f == fact) {
+            UnknownReason::Unsupported
+        } else {
+            UnknownReason::DataMissing
+        }
+    }
+
+    pub fn is_requested(&self, fact: &str) -> bool {
+        !self.not_requested.iter().any(|f| f == fact)
+    }
 }
 
 // CheckResult uses serde_json::Value for flexible evidence
@@ -84,16 +413,58 @@ pub struct CheckResult {
     pub evidence: serde_json::Value,
     pub weight: u8,
     pub score_component: Option<u8>,
+    /// Reliability (0.0-1.0) of the data behind this result; see
+    /// `checks::confidence`
+    #[serde(default = "full_confidence")]
+    pub confidence: f64,
+    /// Why the status is `Unknown`; None for known results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unknown_reason: Option<UnknownReason>,
+    /// Page explaining what the check means and why it matters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub learn_more_url: Option<String>,
 }
 
-#[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
+impl CheckResult {
+    /// Whether a result was expected: checks that do not apply to the
+    /// token, or whose data the request opted out of, are not counted
+    pub fn is_expected(&self) -> bool {
+        !matches!(
+            self.unknown_reason,
+            Some(UnknownReason::NotApplicable | UnknownReason::NotRequested)
+        )
+    }
+}
+
+fn full_confidence() -> f64 {
+    1.0
+}
+
+#[derive(Clone, Debug, PartialEq, CandidType, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnknownReason {
+    /// The provider had no such data
+    DataMissing,
+    /// Fetching the data failed
+    ProviderError,
+    /// The check does not apply to this chain or token
+    NotApplicable,
+    /// The check produced a result that could not be serialized
+    InvalidResult,
+    /// The configured provider cannot fetch the data this check needs
+    Unsupported,
+    /// The request opted out of the data this check needs
+    NotRequested,
+}
+
+#[derive(Clone, Debug, PartialEq, CandidType, Serialize, Deserialize)]
 pub enum CheckStatus {
     Pass,
     Fail,
     Unknown,
 }
 
-#[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, CandidType, Serialize, Deserialize)]
 pub enum Severity {
     Critical,
     High,
@@ -107,4 +478,25 @@ pub enum Grade {
     Mixed,
     Fragile,
     Compromised,
+    /// Too little of the intended check weight is known to grade
+    Insufficient,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multisig_threshold_protection() {
+        let protected = |m, n| MultisigInfo { m, n }.is_threshold_protected();
+
+        assert!(protected(3, 5));
+        assert!(protected(2, 4));
+        // A small subset of the keys decides alone
+        assert!(!protected(2, 10));
+        // One signer is one key, whatever the account type
+        assert!(!protected(1, 1));
+        assert!(!protected(1, 3));
+        assert_eq!(MultisigInfo { m: 3, n: 5 }.m_of_n(), "3-of-5");
+    }
 }
@@ -11,27 +11,34 @@ fn test_fair_launch_solana_full_flow() {
             symbol: Some("FAIR".to_string()),
             decimals: Some(9),
             standard: TokenStandard::SplToken,
+            ..Default::default()
         }),
         supply: Some(SupplyInfo {
             total_supply_raw: Some("1000000000000000".to_string()),
             total_supply: Some(1000000.0),
+            circulating_supply: None,
         }),
         authorities: Some(AuthorityInfo {
             mint_authority: None,
             freeze_authority: None,
             owner: None,
             mint_mutable: Some(false),
+            ..Default::default()
         }),
         holders: Some(HolderInfo {
             top1_pct: Some(8.5),
             top5_pct: Some(28.0),
+            holder_count: None,
+            unlisted_balance_raw: None,
             top_holders: vec![],
         }),
         creation: Some(CreationInfo {
             created_at: Some("2026-01-20T00:00:00Z".to_string()),
             age_seconds: Some(864000),
             age_band: AgeBand::GreaterThan7d,
+            estimated: false,
         }),
+        ..Default::default()
     };
 
     // Run all 6 checks
@@ -69,27 +76,34 @@ fn test_mint_authority_exists_critical_override() {
             symbol: Some("UNFAIR".to_string()),
             decimals: Some(9),
             standard: TokenStandard::SplToken,
+            ..Default::default()
         }),
         supply: Some(SupplyInfo {
             total_supply_raw: Some("1000000000000000".to_string()),
             total_supply: Some(1000000.0),
+            circulating_supply: None,
         }),
         authorities: Some(AuthorityInfo {
             mint_authority: Some("SomeAuthorityKey123".to_string()),
             freeze_authority: None,
             owner: None,
             mint_mutable: Some(true),
+            ..Default::default()
         }),
         holders: Some(HolderInfo {
             top1_pct: Some(5.0),
             top5_pct: Some(20.0),
+            holder_count: None,
+            unlisted_balance_raw: None,
             top_holders: vec![],
         }),
         creation: Some(CreationInfo {
             created_at: Some("2026-01-20T00:00:00Z".to_string()),
             age_seconds: Some(864000),
             age_band: AgeBand::GreaterThan7d,
+            estimated: false,
         }),
+        ..Default::default()
     };
 
     // Run all checks
@@ -120,27 +134,34 @@ fn test_evm_fair_launch() {
             symbol: Some("FERC".to_string()),
             decimals: Some(18),
             standard: TokenStandard::Erc20,
+            ..Default::default()
         }),
         supply: Some(SupplyInfo {
             total_supply_raw: Some("1000000000000000000000000".to_string()),
             total_supply: Some(1000000.0),
+            circulating_supply: None,
         }),
         authorities: Some(AuthorityInfo {
             mint_authority: None,
             freeze_authority: None,
             owner: Some("0x0000000000000000000000000000000000000000".to_string()),
             mint_mutable: Some(false),
+            ..Default::default()
         }),
         holders: Some(HolderInfo {
             top1_pct: Some(9.0),
             top5_pct: Some(33.0),
+            holder_count: None,
+            unlisted_balance_raw: None,
             top_holders: vec![],
         }),
         creation: Some(CreationInfo {
             created_at: Some("2026-01-20T00:00:00Z".to_string()),
             age_seconds: Some(864000),
             age_band: AgeBand::GreaterThan7d,
+            estimated: false,
         }),
+        ..Default::default()
     };
 
     let checks = vec![
@@ -165,23 +186,28 @@ fn test_partial_data_realistic_scenario() {
             symbol: Some("PART".to_string()),
             decimals: Some(9),
             standard: TokenStandard::SplToken,
+            ..Default::default()
         }),
         supply: Some(SupplyInfo {
             total_supply: Some(1000000.0),
             total_supply_raw: Some("1000000000000000".to_string()),
+            circulating_supply: None,
         }),
         authorities: Some(AuthorityInfo {
             mint_authority: None,
             freeze_authority: None,
             owner: None,
             mint_mutable: Some(false),
+            ..Default::default()
         }),
         holders: None, // Provider timeout
         creation: Some(CreationInfo {
             age_seconds: Some(259200),
             created_at: Some("2026-01-27T00:00:00Z".to_string()),
             age_band: AgeBand::Day1To7,
+            estimated: false,
         }),
+        ..Default::default()
     };
 
     let checks = vec![